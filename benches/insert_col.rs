@@ -0,0 +1,43 @@
+//! Times inserting a column into wide, square-ish, and tall grids,
+//! with and without room reserved by `reserve_cols`.
+//!
+//! Run with `cargo bench --bench insert_col`.
+
+use flatgrid::Grid;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const RUNS: u32 = 20;
+
+/// Inserts a column in the middle of a fresh grid `RUNS` times, and prints the mean time of the insertion alone.
+
+fn bench(
+    row_size: usize,
+    col_size: usize,
+    reserve: bool,
+)
+{
+    let column: Vec<String> = (0..row_size).map(|row| format!("new {row}")).collect();
+    let mut total = Duration::ZERO;
+    for _ in 0..RUNS {
+        let mut grid = Grid::new(row_size, col_size);
+        if reserve {
+            grid.reserve_cols(1);
+        }
+        let column = column.clone();
+        let start = Instant::now();
+        grid.insert_col(col_size / 2, column);
+        total += start.elapsed();
+        black_box(&grid);
+    }
+    let name = format!("{row_size}x{col_size}{}", if reserve { " reserved" } else { "" });
+    println!("{name:<24} {:>10.2?}", total / RUNS);
+}
+
+fn main() {
+    for (row_size, col_size) in [(100, 2000), (2000, 100), (20_000, 10)] {
+        bench(row_size, col_size, false);
+        bench(row_size, col_size, true);
+    }
+}
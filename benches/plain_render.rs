@@ -0,0 +1,52 @@
+//! Times rendering a 100k x 5 table of plain cells, which takes the fast path for plain rows,
+//! and the same table with one styled cell, which does not.
+//!
+//! Run with `cargo bench --bench plain_render`.
+
+use flatgrid::{BorderStyle, FontStyle, Grid, RenderOptions};
+
+use std::hint::black_box;
+use std::time::Instant;
+
+const RUNS: u32 = 5;
+
+/// Runs a render `RUNS` times after a warm-up run, and prints the mean time per run.
+
+fn bench(
+    name: &str,
+    mut render: impl FnMut(),
+)
+{
+    render();
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        render();
+    }
+    println!("{name:<32} {:>10.2?}", start.elapsed() / RUNS);
+}
+
+fn main() {
+    let mut grid = Grid::with_capacity(100_000, 5);
+    for row in 0..100_000 {
+        grid.push_row((0..5).map(|col| format!("{row}:{col}")));
+    }
+    let options = RenderOptions::without_env();
+    let mut borderless = RenderOptions::without_env();
+    borderless.set_border_style(BorderStyle::None);
+
+    bench("render_with", || {
+        black_box(grid.render_with(&options));
+    });
+    bench("Display", || {
+        black_box(grid.to_string());
+    });
+    bench("borderless render", || {
+        black_box(grid.render_with(&borderless));
+    });
+
+    // A single styled cell makes every row go through the general path
+    grid.get_cell_mut(0, 0).unwrap().set_style(FontStyle::Bold);
+    bench("render_with (one styled cell)", || {
+        black_box(grid.render_with(&options));
+    });
+}
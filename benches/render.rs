@@ -0,0 +1,97 @@
+//! Times rendering a 10k-row grid and counts the allocations it makes.
+//!
+//! Run with `cargo bench --bench render`.
+
+use flatgrid::{Cell, FontStyle, Grid, RenderOptions};
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+/// Counts every allocation, to compare the allocations made by each way of rendering.
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+
+    unsafe fn alloc(
+        &self,
+        layout: Layout,
+    ) -> *mut u8
+    {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+    )
+    {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8
+    {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const RUNS: u32 = 5;
+
+/// Runs a render `RUNS` times after a warm-up run, and prints the mean time and allocations per run.
+
+fn bench(
+    name: &str,
+    mut render: impl FnMut(),
+)
+{
+    render();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        render();
+    }
+    let elapsed = start.elapsed() / RUNS;
+    let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / RUNS as usize;
+    println!("{name:<24} {elapsed:>10.2?} {allocations:>10} allocations");
+}
+
+fn main() {
+    let mut grid = Grid::with_capacity(10_000, 6);
+    for row in 0..10_000 {
+        grid.push_row((0..6).map(|col| {
+            let mut cell = Cell::from(format!("row {row} col {col}"));
+            if col == 0 {
+                cell.set_style(FontStyle::Bold);
+            }
+            cell
+        }));
+    }
+    let options = RenderOptions::without_env();
+
+    bench("render_with", || {
+        black_box(grid.render_with(&options));
+    });
+    bench("Display", || {
+        black_box(grid.to_string());
+    });
+    bench("render_to (io::sink)", || {
+        grid.render_to(&mut io::sink()).unwrap();
+    });
+}
@@ -0,0 +1,121 @@
+//! Derive macro for `flatgrid::ToGrid`.
+//!
+//! Use it through the `derive` feature of `flatgrid`, which re-exports it.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitInt, LitStr, parse_macro_input};
+
+/// Derives `flatgrid::ToGrid` for a struct with named fields.
+///
+/// See the documentation of `ToGrid` for the supported `#[grid(...)]` field attributes.
+
+#[proc_macro_derive(ToGrid, attributes(grid))]
+pub fn derive_to_grid(
+    input: TokenStream,
+) -> TokenStream
+{
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// A field of the struct that becomes a column, with its `#[grid(...)]` attributes.
+
+struct Column {
+    ident: syn::Ident,
+    header: String,
+    order: i64,
+    align: Option<TokenStream2>,
+    format: Option<LitStr>,
+}
+
+/// Generates the `ToGrid` implementation, or an error pointing at the offending tokens.
+
+fn expand(
+    input: DeriveInput,
+) -> syn::Result<TokenStream2>
+{
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new_spanned(&input.ident, "ToGrid can only be derived for structs with named fields")),
+        },
+        _ => return Err(syn::Error::new_spanned(&input.ident, "ToGrid can only be derived for structs")),
+    };
+
+    let mut columns = Vec::new();
+    for (position, field) in fields.iter().enumerate() {
+        let ident = field.ident.clone().expect("named fields have identifiers");
+        let mut column = Column {
+            header: ident.to_string(),
+            ident,
+            order: position as i64,
+            align: None,
+            format: None,
+        };
+        let mut skip = false;
+
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("grid")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                } else if meta.path.is_ident("rename") {
+                    column.header = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("order") {
+                    column.order = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+                } else if meta.path.is_ident("format") {
+                    column.format = Some(meta.value()?.parse::<LitStr>()?);
+                } else if meta.path.is_ident("align") {
+                    let align = meta.value()?.parse::<LitStr>()?;
+                    column.align = Some(match align.value().as_str() {
+                        "left"   => quote!(::flatgrid::Align::Left),
+                        "center" => quote!(::flatgrid::Align::Center),
+                        "right"  => quote!(::flatgrid::Align::Right),
+                        _ => return Err(syn::Error::new_spanned(align, "expected `left`, `center`, or `right`")),
+                    });
+                } else {
+                    return Err(meta.error("expected `rename`, `skip`, `order`, `align`, or `format`"));
+                }
+                Ok(())
+            })?;
+        }
+
+        if !skip {
+            columns.push(column);
+        }
+    }
+    columns.sort_by_key(|column| column.order);
+
+    let headers = columns.iter().map(|column| &column.header);
+    let cells = columns.iter().map(|column| {
+        let ident = &column.ident;
+        let data = match &column.format {
+            Some(format) => quote!(::std::format!(#format, self.#ident)),
+            None => quote!(::std::string::ToString::to_string(&self.#ident)),
+        };
+        let align = column.align.as_ref().map(|align| quote!(cell.set_align(#align);));
+        quote!({
+            #[allow(unused_mut)]
+            let mut cell = ::flatgrid::Cell::new(#data);
+            #align
+            cell
+        })
+    });
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::flatgrid::ToGrid for #name #type_generics #where_clause {
+            fn header() -> ::std::vec::Vec<::flatgrid::Cell> {
+                ::std::vec![#(::flatgrid::Cell::new(#headers)),*]
+            }
+
+            fn row(&self) -> ::std::vec::Vec<::flatgrid::Cell> {
+                ::std::vec![#(#cells),*]
+            }
+        }
+    })
+}
@@ -0,0 +1,7 @@
+//! Static and shared libraries exporting the C API of `flatgrid`.
+//!
+//! Building this crate produces `libflatgrid.a` and `libflatgrid.so` (`.dylib`, `.dll`),
+//! declared in `include/flatgrid.h`. The API itself lives in `flatgrid::ffi`; it is built
+//! as a separate crate so `flatgrid` stays a plain library, which also builds without `std`.
+
+pub use flatgrid::ffi::*;
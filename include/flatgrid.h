@@ -0,0 +1,93 @@
+#ifndef FLATGRID_H
+#define FLATGRID_H
+
+/* Generated with cbindgen from src/ffi.rs, do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ The call succeeded.
+ */
+#define FLATGRID_OK 0
+
+/*
+ A pointer argument was null.
+ */
+#define FLATGRID_ERROR_NULL -1
+
+/*
+ The row or column index was out of bounds.
+ */
+#define FLATGRID_ERROR_OUT_OF_BOUNDS -2
+
+/*
+ The text was not valid UTF-8.
+ */
+#define FLATGRID_ERROR_INVALID_UTF8 -3
+
+/*
+ Opaque handle to a grid, created with `flatgrid_new` and destroyed with `flatgrid_free`.
+ */
+typedef struct FlatGrid FlatGrid;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ Creates a grid of empty cells with the given number of rows and columns.
+
+ Returns null if the cells would not fit in memory. The grid must be destroyed with `flatgrid_free`.
+ */
+struct FlatGrid *flatgrid_new(size_t rows, size_t cols);
+
+/*
+ Destroys a grid created with `flatgrid_new`. Does nothing if `grid` is null.
+
+ # Safety
+
+ `grid` must be null or a pointer returned by `flatgrid_new` that was not destroyed yet.
+ */
+void flatgrid_free(struct FlatGrid *grid);
+
+/*
+ Sets the text of the cell at the given row and column. The text is copied.
+
+ Returns `FLATGRID_OK`, or an error code if a pointer is null, the indices are out of bounds,
+ or the text is not valid UTF-8.
+
+ # Safety
+
+ `grid` must be null or a valid grid, and `text` must be null or a NUL-terminated string.
+ */
+int flatgrid_set_cell(struct FlatGrid *grid, size_t row, size_t col, const char *text);
+
+/*
+ Renders the grid into a NUL-terminated string, exactly like the `Display` output.
+
+ Returns null if `grid` is null. The string must be freed with `flatgrid_string_free`.
+
+ # Safety
+
+ `grid` must be null or a valid grid.
+ */
+char *flatgrid_render(const struct FlatGrid *grid);
+
+/*
+ Frees a string returned by `flatgrid_render`. Does nothing if `text` is null.
+
+ # Safety
+
+ `text` must be null or a pointer returned by `flatgrid_render` that was not freed yet.
+ */
+void flatgrid_string_free(char *text);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* FLATGRID_H */
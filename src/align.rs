@@ -1,4 +1,4 @@
-use std::ops::{BitOr, BitOrAssign};
+use core::ops::{BitOr, BitOrAssign};
 
 /// Alignment options for cell content.
 /// 
@@ -23,6 +23,16 @@ impl Align {
     pub const H_MASK : u8 = Align::Left.0 | Align::Center.0 | Align::Right.0;
     pub const V_MASK : u8 = Align::Top.0 | Align::Middle.0 | Align::Bottom.0;
 
+    /// Checks whether all the alignments of `other` are set.
+
+    pub fn contains(
+        &self,
+        other: Align,
+    ) -> bool
+    {
+        self.0 & other.0 == other.0
+    }
+
     pub(crate) fn get_h(
         &self
     ) -> Option<AlignH>
@@ -45,6 +55,29 @@ impl Align {
         None
     }
 
+    /// Combines optional horizontal and vertical alignments into an `Align` value.
+    /// Missing alignments leave the matching axis unset.
+
+    pub(crate) fn from_parts(
+        h: Option<AlignH>,
+        v: Option<AlignV>,
+    ) -> Align
+    {
+        let h = match h {
+            Some(AlignH::Left)   => Align::Left.0,
+            Some(AlignH::Right)  => Align::Right.0,
+            Some(AlignH::Center) => Align::Center.0,
+            None                 => 0,
+        };
+        let v = match v {
+            Some(AlignV::Top)    => Align::Top.0,
+            Some(AlignV::Bottom) => Align::Bottom.0,
+            Some(AlignV::Middle) => Align::Middle.0,
+            None                 => 0,
+        };
+        Align(h | v)
+    }
+
 }
 
 impl BitOr for Align {
@@ -58,9 +91,7 @@ impl BitOr for Align {
     {
         // Combine horizontal and vertical alignments,
         // with rhs taking precedence in case of conflicts
-        let h = (self.0 & !Align::H_MASK) | (rhs.0 & Align::H_MASK);
-        let v = (self.0 & !Align::V_MASK) | (rhs.0 & Align::V_MASK);
-        Align(h | v)
+        Align::from_parts(rhs.get_h().or(self.get_h()), rhs.get_v().or(self.get_v()))
     }
 
 }
@@ -88,3 +119,26 @@ pub(crate) enum AlignH {
     Right,
     Center,
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last_alignment_wins_on_each_axis() {
+        let align = Align::Left | Align::Right;
+        assert_eq!(align.get_h(), Some(AlignH::Right));
+
+        let align = Align::Top | Align::Center | Align::Bottom;
+        assert_eq!(align.get_h(), Some(AlignH::Center));
+        assert_eq!(align.get_v(), Some(AlignV::Bottom));
+    }
+
+    #[test]
+    fn keeps_the_other_axis() {
+        let mut align = Align::Right | Align::Middle;
+        align |= Align::Left;
+        assert_eq!(align, Align::Left | Align::Middle);
+    }
+}
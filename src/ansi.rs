@@ -1,6 +1,8 @@
 
 
 pub const RESET_ANSI_CODE             : &str = "\x1b[0m";
+#[cfg(feature = "std")]
+pub const CLEAR_LINE_ANSI_CODE        : &str = "\x1b[2K";
 
 pub const BOLD_ANSI_CODE              : &str = "\x1b[1m";
 pub const DIM_ANSI_CODE               : &str = "\x1b[2m";
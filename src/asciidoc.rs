@@ -0,0 +1,142 @@
+use crate::align::{AlignH, AlignV};
+use crate::cell::Cell;
+use crate::export::Exporter;
+use crate::grid::Grid;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::format;
+
+impl Grid {
+
+    /// Export the grid as an AsciiDoc table block.
+    ///
+    /// The table is delimited by `|===` lines and every cell is written on its own line,
+    /// with a blank line separating rows.
+    ///
+    /// Cell alignment is carried over using AsciiDoc cell specifiers
+    /// (`<`, `^`, `>` for horizontal and `.<`, `.^`, `.>` for vertical alignment).
+    /// Cells without an explicit alignment get no specifier.
+    ///
+    /// Multiline cells are joined using hard line breaks (` +`) and
+    /// any `|` characters in the cell data are escaped.
+    ///
+    /// ANSI formatting (colors and font styles) is not exported.
+    ///
+    /// A grid without columns is exported as an empty table block, without the `cols` attribute,
+    /// since AsciiDoc requires at least one column.
+
+    pub fn to_asciidoc(
+        &self
+    ) -> String
+    {
+        self.export(&AsciiDoc)
+    }
+
+}
+
+/// Exporter for AsciiDoc table blocks, as produced by `Grid::to_asciidoc`.
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsciiDoc;
+
+impl Exporter for AsciiDoc {
+
+    type Output = String;
+
+    fn export(
+        &self,
+        grid: &Grid,
+    ) -> String
+    {
+        if grid.col_size() == 0 {
+            return String::from("|===\n|===\n");
+        }
+
+        let mut output = String::new();
+
+        output.push_str(&format!("[cols=\"{}*\"]\n", grid.col_size()));
+        output.push_str("|===\n");
+        for row_index in 0..grid.row_size() {
+            if row_index > 0 {
+                output.push('\n');
+            }
+            for cell in grid.row_iter(row_index) {
+                output.push_str(&AsciiDoc::render_cell(cell));
+                output.push('\n');
+            }
+        }
+        output.push_str("|===\n");
+
+        output
+    }
+
+}
+
+impl AsciiDoc {
+
+    /// Creates the cell specifier for the cell's alignment.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The cell to create the specifier for
+    ///
+    /// # Returns
+    ///
+    /// The alignment specifier, or an empty string if the cell has no alignment set
+
+    fn render_specifier(
+        cell: &Cell,
+    ) -> String
+    {
+        let h = match cell.h_align() {
+            Some(AlignH::Left)   => "<",
+            Some(AlignH::Center) => "^",
+            Some(AlignH::Right)  => ">",
+            None                 => "",
+        };
+        let v = match cell.v_align() {
+            Some(AlignV::Top)    => ".<",
+            Some(AlignV::Middle) => ".^",
+            Some(AlignV::Bottom) => ".>",
+            None                 => "",
+        };
+        format!("{}{}", h, v)
+    }
+
+    /// Creates a single AsciiDoc table cell, including its specifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The cell to render
+    ///
+    /// # Returns
+    ///
+    /// A formatted AsciiDoc cell string
+
+    fn render_cell(
+        cell: &Cell,
+    ) -> String
+    {
+        let text = cell.get_data()
+            .lines()
+            .map(|line| line.replace('|', "\\|"))
+            .collect::<Vec<String>>()
+            .join(" +\n");
+        format!("{}| {}", AsciiDoc::render_specifier(cell), text)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::Grid;
+
+    #[test]
+    fn exports_a_grid_without_columns_as_an_empty_table() {
+        assert_eq!(Grid::new(0, 0).to_asciidoc(), "|===\n|===\n");
+        assert_eq!(Grid::new(2, 0).to_asciidoc(), "|===\n|===\n");
+    }
+
+}
@@ -0,0 +1,148 @@
+use crate::align::AlignH;
+use crate::cell::Cell;
+use crate::export::Exporter;
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+
+use alloc::string::{String, ToString};
+use alloc::format;
+
+impl Grid {
+
+    /// Export the grid as a BBCode table, for posting on forums.
+    ///
+    /// Every row is written on its own line as `[tr]` with one `[td]` per cell,
+    /// wrapped in a `[table]` block.
+    ///
+    /// Centered and right-aligned cells are wrapped in `[center]` and `[right]` tags,
+    /// foreground colors become `[color]` tags and bold, italic, underline, and strike
+    /// font styles become `[b]`, `[i]`, `[u]`, and `[s]` tags.
+    /// Background colors and the remaining font styles have no BBCode equivalent and are dropped.
+    ///
+    /// BBCode has no portable escape sequence, so brackets in the cell data are kept as is.
+    /// Forums without table support can use `to_fenced_markdown` instead.
+
+    pub fn to_bbcode(
+        &self
+    ) -> String
+    {
+        self.export(&BBCode)
+    }
+
+    /// Export the rendered table as a fenced Markdown code block.
+    ///
+    /// This is the plain rendering of the table, without ANSI escape sequences,
+    /// inside a `text` code fence, so it keeps its alignment on forums that only support Markdown.
+    /// The fence is made longer than any run of backticks in the table.
+
+    pub fn to_fenced_markdown(
+        &self
+    ) -> String
+    {
+        self.export(&FencedMarkdown)
+    }
+
+}
+
+/// Exporter for BBCode tables, as produced by `Grid::to_bbcode`.
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BBCode;
+
+impl Exporter for BBCode {
+
+    type Output = String;
+
+    fn export(
+        &self,
+        grid: &Grid,
+    ) -> String
+    {
+        let mut output = String::new();
+
+        output.push_str("[table]\n");
+        for row_index in 0..grid.row_size() {
+            output.push_str("[tr]");
+            for cell in grid.row_iter(row_index) {
+                output.push_str(&BBCode::render_cell(cell));
+            }
+            output.push_str("[/tr]\n");
+        }
+        output.push_str("[/table]\n");
+
+        output
+    }
+
+}
+
+impl BBCode {
+
+    /// Creates a single BBCode table cell, including its formatting tags.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The cell to render
+    ///
+    /// # Returns
+    ///
+    /// A formatted BBCode cell string
+
+    fn render_cell(
+        cell: &Cell,
+    ) -> String
+    {
+        let mut text = cell.get_data().to_string();
+
+        for style in cell.font_style() {
+            let tag = match style {
+                FontStyle::Bold      => "b",
+                FontStyle::Italic    => "i",
+                FontStyle::Underline => "u",
+                FontStyle::Strike    => "s",
+                _ => continue,
+            };
+            text = format!("[{tag}]{text}[/{tag}]");
+        }
+        if let Some(color) = cell.fg_color() {
+            let (r, g, b) = color.as_rgb();
+            text = format!("[color=#{:02x}{:02x}{:02x}]{}[/color]", r, g, b, text);
+        }
+        match cell.h_align() {
+            Some(AlignH::Center) => text = format!("[center]{}[/center]", text),
+            Some(AlignH::Right)  => text = format!("[right]{}[/right]", text),
+            _ => {},
+        }
+
+        format!("[td]{}[/td]", text)
+    }
+
+}
+
+/// Exporter for fenced Markdown code blocks, as produced by `Grid::to_fenced_markdown`.
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FencedMarkdown;
+
+impl Exporter for FencedMarkdown {
+
+    type Output = String;
+
+    fn export(
+        &self,
+        grid: &Grid,
+    ) -> String
+    {
+        let table = grid.render_plain();
+
+        let mut longest_run = 0;
+        let mut run = 0;
+        for c in table.chars() {
+            run = if c == '`' { run + 1 } else { 0 };
+            longest_run = longest_run.max(run);
+        }
+        let fence = "`".repeat((longest_run + 1).max(3));
+
+        format!("{}text\n{}{}\n", fence, table, fence)
+    }
+
+}
@@ -0,0 +1,235 @@
+use flatgrid::{Align, BorderStyle, ColorMode, CsvHeader, CsvOptions, Grid, RenderOptions};
+
+use clap::{Parser, ValueEnum};
+use std::io::{self, IsTerminal, Read, Write};
+use std::process::ExitCode;
+
+/// Formats CSV, TSV, or JSON read from stdin as a styled table.
+
+#[derive(Debug, Parser)]
+#[command(name = "flatgrid", version)]
+struct Args {
+    /// Format of the input; detected from the data if not given
+    #[arg(short, long, value_enum)]
+    input: Option<InputFormat>,
+
+    /// Format of the output
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    output: OutputFormat,
+
+    /// Preset of render options, applied before `--border`
+    #[arg(short, long, value_enum, default_value_t = StylePreset::Default)]
+    style: StylePreset,
+
+    /// Border style of the table
+    #[arg(short, long, value_enum)]
+    border: Option<BorderPreset>,
+
+    /// When to emit ANSI escape sequences
+    #[arg(long, value_enum, default_value_t = ColorWhen::Auto)]
+    color: ColorWhen,
+
+    /// Column alignments, one letter per column: `l`, `c`, or `r` (e.g. `lrr`)
+    #[arg(short, long)]
+    align: Option<String>,
+
+    /// Maximum width of every column; longer cell lines are truncated
+    #[arg(long)]
+    max_col_width: Option<usize>,
+
+    /// Maximum width of the whole table; the widest columns are truncated to fit.
+    /// Defaults to the terminal width when writing to a terminal
+    #[arg(short = 'w', long)]
+    max_width: Option<usize>,
+
+    /// Keep the first CSV or TSV record as a regular row instead of a bold header
+    #[arg(long)]
+    no_header: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum InputFormat {
+    Csv,
+    Tsv,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Table,
+    Markdown,
+    Html,
+    Asciidoc,
+    Bbcode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum StylePreset {
+    Default,
+    Compact,
+    Plain,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum BorderPreset {
+    Unicode,
+    Ascii,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ColorWhen {
+    Auto,
+    Always,
+    Never,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("flatgrid: {}", message);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+fn run(
+    args: &Args,
+) -> Result<(), String>
+{
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)
+        .map_err(|e| format!("failed to read stdin: {}", e))?;
+
+    let mut grid = read_grid(&input, args)?;
+    if let Some(spec) = &args.align {
+        // Keep the header centered
+        let skip = usize::from(has_header(&input, args));
+        for (col_index, align) in parse_aligns(spec)?.into_iter().enumerate().take(grid.col_size()) {
+            for cell in grid.col_iter_mut(col_index).skip(skip) {
+                cell.set_align(align);
+            }
+        }
+    }
+
+    let output = match args.output {
+        OutputFormat::Table => grid.render_with(&render_options(args)),
+        OutputFormat::Markdown => grid.to_fenced_markdown(),
+        OutputFormat::Html => grid.render_html_fragment_with(&render_options(args)),
+        OutputFormat::Asciidoc => grid.to_asciidoc(),
+        OutputFormat::Bbcode => grid.to_bbcode(),
+    };
+
+    let mut stdout = io::stdout().lock();
+    match stdout.write_all(output.as_bytes()).and_then(|_| stdout.flush()) {
+        // A closed pipe, e.g. `flatgrid < data.csv | head`, is not an error
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => Err(format!("failed to write stdout: {}", e)),
+        _ => Ok(()),
+    }
+}
+
+/// Parses the input into a grid, detecting its format if not given.
+
+fn read_grid(
+    input: &str,
+    args: &Args,
+) -> Result<Grid, String>
+{
+    let delimiter = match args.input.unwrap_or_else(|| detect_format(input)) {
+        InputFormat::Json => {
+            return Grid::from_json(input).map_err(|e| format!("invalid JSON: {}", e));
+        },
+        InputFormat::Csv => b',',
+        InputFormat::Tsv => b'\t',
+    };
+    let mut options = CsvOptions::new();
+    options.set_delimiter(delimiter);
+    options.set_header(if args.no_header { CsvHeader::Keep } else { CsvHeader::Bold });
+    Grid::from_csv_reader(input.as_bytes(), &options).map_err(|e| format!("invalid CSV: {}", e))
+}
+
+/// Detects JSON from a leading `[` or `{`, and TSV from a tab in the first line.
+
+fn detect_format(
+    input: &str,
+) -> InputFormat
+{
+    let input = input.trim_start();
+    if input.starts_with('[') || input.starts_with('{') {
+        InputFormat::Json
+    } else if input.lines().next().is_some_and(|line| line.contains('\t')) {
+        InputFormat::Tsv
+    } else {
+        InputFormat::Csv
+    }
+}
+
+/// Checks whether the first row of the grid is a header.
+
+fn has_header(
+    input: &str,
+    args: &Args,
+) -> bool
+{
+    args.input.unwrap_or_else(|| detect_format(input)) == InputFormat::Json || !args.no_header
+}
+
+/// Builds the render options from the arguments.
+
+fn render_options(
+    args: &Args,
+) -> RenderOptions
+{
+    let mut options = RenderOptions::without_env();
+    match args.style {
+        StylePreset::Default => {},
+        StylePreset::Compact => options.set_row_separators(false),
+        StylePreset::Plain => {
+            options.set_border_style(BorderStyle::None);
+            options.set_color_mode(ColorMode::Never);
+        },
+    }
+    match args.border {
+        Some(BorderPreset::Unicode) => options.set_border_style(BorderStyle::Unicode),
+        Some(BorderPreset::Ascii) => options.set_border_style(BorderStyle::Ascii),
+        Some(BorderPreset::None) => options.set_border_style(BorderStyle::None),
+        None => {},
+    }
+    match args.color {
+        ColorWhen::Auto if !io::stdout().is_terminal() => options.set_color_mode(ColorMode::Never),
+        ColorWhen::Auto => {},
+        ColorWhen::Always => options.set_color_mode(ColorMode::Always),
+        ColorWhen::Never => options.set_color_mode(ColorMode::Never),
+    }
+    if let Some(max_col_width) = args.max_col_width {
+        options.set_max_col_width(max_col_width);
+    }
+    match args.max_width {
+        Some(max_width) => options.set_max_width(max_width),
+        // Output piped into other programs is never truncated
+        None if io::stdout().is_terminal() => {
+            options.fit_terminal();
+        },
+        None => {},
+    }
+    options
+}
+
+/// Parses column alignments such as `lcr` into one `Align` per column.
+
+fn parse_aligns(
+    spec: &str,
+) -> Result<Vec<Align>, String>
+{
+    spec.chars()
+        .filter(|c| !matches!(c, ',' | ' '))
+        .map(|c| match c.to_ascii_lowercase() {
+            'l' => Ok(Align::Left),
+            'c' => Ok(Align::Center),
+            'r' => Ok(Align::Right),
+            _ => Err(format!("unknown alignment '{}', expected l, c, or r", c)),
+        })
+        .collect()
+}
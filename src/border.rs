@@ -1,145 +1,408 @@
-/// Internal utility struct for generating table borders using Unicode box-drawing characters.
-/// 
-/// This struct provides constants and methods for creating the various border elements
-/// needed to draw table frames, including corners, edges, and intersections.
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::format;
+
+/// The style of the borders drawn around and between cells.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "lowercase"))]
+pub enum BorderStyle {
+    /// Unicode box-drawing characters (`┌─┬─┐`).
+    #[default]
+    Unicode,
+    /// Plain ASCII characters (`+-+-+`), for fonts and terminals without box-drawing support.
+    Ascii,
+    /// No borders at all; columns are separated by spaces only.
+    None,
+}
+
+/// Internal utility struct for generating table borders.
+///
+/// This struct holds the symbols of a border style and provides methods for creating
+/// the various border elements needed to draw table frames,
+/// including corners, edges, and intersections.
 
 #[derive(Debug, Clone)]
-pub struct Border {}
+pub(crate) struct Border {
+    top_left      : &'static str,
+    top_middle    : &'static str,
+    top_right     : &'static str,
+    middle_left   : &'static str,
+    middle_middle : &'static str,
+    middle_right  : &'static str,
+    bottom_left   : &'static str,
+    bottom_middle : &'static str,
+    bottom_right  : &'static str,
+    footer_left   : &'static str,
+    footer_middle : &'static str,
+    footer_right  : &'static str,
+    vertical      : &'static str,
+    horizontal    : &'static str,
+    footer_line   : &'static str,
+    padding       : usize,
+}
 
 impl Border {
 
-    const TOP_LEFT      : &'static str = " ┌─";
-    const TOP_MIDDLE    : &'static str = "─┬─";
-    const TOP_RIGHT     : &'static str = "─┐ ";
-    const MIDDLE_LEFT   : &'static str = " ├─";
-    const MIDDLE_MIDDLE : &'static str = "─┼─";
-    const MIDDLE_RIGHT  : &'static str = "─┤ ";
-    const BOTTOM_LEFT   : &'static str = " └─";
-    const BOTTOM_MIDDLE : &'static str = "─┴─";
-    const BOTTOM_RIGHT  : &'static str = "─┘ ";
-    const VERTICAL      : &'static str = " │ ";
-    const HORIZONTAL    : &'static str = "─";
+    const UNICODE: Border = Border {
+        top_left      : "┌",
+        top_middle    : "┬",
+        top_right     : "┐",
+        middle_left   : "├",
+        middle_middle : "┼",
+        middle_right  : "┤",
+        bottom_left   : "└",
+        bottom_middle : "┴",
+        bottom_right  : "┘",
+        footer_left   : "╞",
+        footer_middle : "╪",
+        footer_right  : "╡",
+        vertical      : "│",
+        horizontal    : "─",
+        footer_line   : "═",
+        padding       : 1,
+    };
+
+    const ASCII: Border = Border {
+        top_left      : "+",
+        top_middle    : "+",
+        top_right     : "+",
+        middle_left   : "+",
+        middle_middle : "+",
+        middle_right  : "+",
+        bottom_left   : "+",
+        bottom_middle : "+",
+        bottom_right  : "+",
+        footer_left   : "+",
+        footer_middle : "+",
+        footer_right  : "+",
+        vertical      : "|",
+        horizontal    : "-",
+        footer_line   : "=",
+        padding       : 1,
+    };
+
+    /// Gets the border symbols for the given style.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The border style
+    /// * `padding` - Number of spaces between the vertical borders and the cell content
+    ///
+    /// # Returns
+    ///
+    /// The border, or `None` if the style has no borders
+
+    pub fn from_style(
+        style: BorderStyle,
+        padding: usize,
+    ) -> Option<Border>
+    {
+        let border = match style {
+            BorderStyle::Unicode => Border::UNICODE,
+            BorderStyle::Ascii   => Border::ASCII,
+            BorderStyle::None    => return None,
+        };
+        Some(Border { padding, ..border })
+    }
+
+    /// Gets the symbol of the vertical borders between and around columns.
+
+    pub fn vertical(
+        &self
+    ) -> &'static str
+    {
+        self.vertical
+    }
+
+    /// Gets the number of spaces between the vertical borders and the cell content.
+
+    pub fn padding(
+        &self
+    ) -> usize
+    {
+        self.padding
+    }
 
     /// Creates a border line with the specified corner and intersection characters.
-    /// 
+    ///
     /// # Arguments
-    /// 
-    /// * `column_widths` - Vector of column widths
-    /// * `horizontal_fill` - Horizontal fill character
-    /// * `rightmost` - Right corner/intersection character
-    /// * `middle` - Middle intersection character
+    ///
+    /// * `column_widths` - Slice of column widths
+    /// * `horizontal` - Horizontal line character
     /// * `leftmost` - Left corner/intersection character
-    /// 
+    /// * `middle` - Middle intersection character
+    /// * `rightmost` - Right corner/intersection character
+    ///
     /// # Returns
     ///
     /// A formatted border string
 
     fn render_border(
-        column_widths: &Vec<usize>,
-        horizontal_fill: &str,
-        rightmost: &str,
-        middle: &str,
+        &self,
+        column_widths: &[usize],
+        horizontal: &str,
         leftmost: &str,
+        middle: &str,
+        rightmost: &str,
     ) -> String
     {
         let middle = column_widths.iter()
-            .map(|width| horizontal_fill.repeat(*width))
+            .map(|width| horizontal.repeat(*width + 2 * self.padding))
             .collect::<Vec<String>>()
-            .join(middle)
-            .to_string();
-        format!("{}{}{}", leftmost, middle, rightmost)
+            .join(middle);
+        format!(" {}{}{} ", leftmost, middle, rightmost)
     }
 
     /// Creates the top border of the table.
-    /// 
+    ///
     /// # Arguments
-    /// 
-    /// * `column_widths` - Vector of column widths
-    /// 
+    ///
+    /// * `column_widths` - Slice of column widths
+    ///
     /// # Returns
-    /// 
+    ///
     /// A formatted top border string
 
     pub fn render_top_border(
-        column_widths: &Vec<usize>,
+        &self,
+        column_widths: &[usize],
     ) -> String
     {
-        Border::render_border(
+        self.render_border(
             column_widths,
-            Border::HORIZONTAL,
-            Border::TOP_RIGHT,
-            Border::TOP_MIDDLE,
-            Border::TOP_LEFT,
+            self.horizontal,
+            self.top_left,
+            self.top_middle,
+            self.top_right,
         )
     }
 
     /// Creates a middle border (separator between rows).
-    /// 
+    ///
     /// # Arguments
-    /// 
-    /// * `column_widths` - Vector of column widths
-    /// 
+    ///
+    /// * `column_widths` - Slice of column widths
+    ///
     /// # Returns
-    /// 
+    ///
     /// A formatted middle border string
 
     pub fn render_mid_border(
-        column_widths: &Vec<usize>,
+        &self,
+        column_widths: &[usize],
     ) -> String
     {
-        Border::render_border(
+        self.render_border(
             column_widths,
-            Border::HORIZONTAL,
-            Border::MIDDLE_RIGHT,
-            Border::MIDDLE_MIDDLE,
-            Border::MIDDLE_LEFT,
+            self.horizontal,
+            self.middle_left,
+            self.middle_middle,
+            self.middle_right,
         )
     }
 
     /// Creates the bottom border of the table.
-    /// 
+    ///
     /// # Arguments
-    /// 
-    /// * `column_widths` - Vector of column widths
-    /// 
+    ///
+    /// * `column_widths` - Slice of column widths
+    ///
     /// # Returns
-    /// 
+    ///
     /// A formatted bottom border string
 
     pub fn render_bot_border(
-        column_widths: &Vec<usize>,
+        &self,
+        column_widths: &[usize],
+    ) -> String
+    {
+        self.render_border(
+            column_widths,
+            self.horizontal,
+            self.bottom_left,
+            self.bottom_middle,
+            self.bottom_right,
+        )
+    }
+
+    /// Creates the heavier border separating the footer from the rows above it (`╞═╪═╡`).
+    ///
+    /// # Arguments
+    ///
+    /// * `column_widths` - Slice of column widths
+    ///
+    /// # Returns
+    ///
+    /// A formatted footer border string
+
+    pub fn render_footer_border(
+        &self,
+        column_widths: &[usize],
+    ) -> String
+    {
+        self.render_border(
+            column_widths,
+            self.footer_line,
+            self.footer_left,
+            self.footer_middle,
+            self.footer_right,
+        )
+    }
+
+    /// Creates a separator that splits a full-width row into columns (`├─┬─┤`).
+    ///
+    /// # Arguments
+    ///
+    /// * `column_widths` - Slice of column widths
+    ///
+    /// # Returns
+    ///
+    /// A formatted split border string
+
+    pub fn render_split_border(
+        &self,
+        column_widths: &[usize],
+    ) -> String
+    {
+        self.render_border(
+            column_widths,
+            self.horizontal,
+            self.middle_left,
+            self.top_middle,
+            self.middle_right,
+        )
+    }
+
+    /// Creates a separator that merges columns into a full-width row (`├─┴─┤`).
+    ///
+    /// # Arguments
+    ///
+    /// * `column_widths` - Slice of column widths
+    ///
+    /// # Returns
+    ///
+    /// A formatted merge border string
+
+    pub fn render_merge_border(
+        &self,
+        column_widths: &[usize],
     ) -> String
     {
-        Border::render_border(
+        self.render_border(
             column_widths,
-            Border::HORIZONTAL,
-            Border::BOTTOM_RIGHT,
-            Border::BOTTOM_MIDDLE,
-            Border::BOTTOM_LEFT,
+            self.horizontal,
+            self.middle_left,
+            self.bottom_middle,
+            self.middle_right,
         )
     }
 
+    /// Creates a separator between rows that leaves a gap below merged cells (`├─┤ ├─┤`).
+    ///
+    /// # Arguments
+    ///
+    /// * `column_widths` - Slice of column widths
+    /// * `continued` - Whether each column continues the cell above the separator
+    ///
+    /// # Returns
+    ///
+    /// A formatted mid border string, with spaces instead of lines in the continued columns
+
+    pub fn render_continued_border(
+        &self,
+        column_widths: &[usize],
+        continued: &[bool],
+    ) -> String
+    {
+        let lined = |col_index: usize| !continued.get(col_index).copied().unwrap_or(false);
+        let junction = |left: bool, right: bool| match (left, right) {
+            (true, true)   => self.middle_middle,
+            (false, true)  => self.middle_left,
+            (true, false)  => self.middle_right,
+            (false, false) => self.vertical,
+        };
+
+        let mut output = String::from(" ");
+        for (col_index, width) in column_widths.iter().enumerate() {
+            let left = col_index > 0 && lined(col_index - 1);
+            output.push_str(match col_index {
+                0 => if lined(0) { self.middle_left } else { self.vertical },
+                _ => junction(left, lined(col_index)),
+            });
+            let line = if lined(col_index) { self.horizontal } else { " " };
+            output.push_str(&line.repeat(width + 2 * self.padding));
+        }
+        let last = column_widths.len().checked_sub(1).is_some_and(lined);
+        output.push_str(if last { self.middle_right } else { self.vertical });
+        output.push(' ');
+        output
+    }
+
+    /// Gets the content width of a single row spanning all the given columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `column_widths` - Slice of column widths
+    ///
+    /// # Returns
+    ///
+    /// The combined width of the columns and the separators between them
+
+    pub fn spanned_width(
+        &self,
+        column_widths: &[usize],
+    ) -> usize
+    {
+        let separators = column_widths.len().saturating_sub(1);
+        column_widths.iter().sum::<usize>() + separators * (2 * self.padding + 1)
+    }
+
+    /// Creates the left edge of a text row, up to the content of the first column.
+
+    pub fn render_row_left(
+        &self
+    ) -> String
+    {
+        format!(" {}{}", self.vertical, " ".repeat(self.padding))
+    }
+
+    /// Creates the separator between the content of two columns in a text row.
+
+    pub fn render_row_separator(
+        &self
+    ) -> String
+    {
+        let padding = " ".repeat(self.padding);
+        format!("{}{}{}", padding, self.vertical, padding)
+    }
+
+    /// Creates the right edge of a text row, after the content of the last column.
+
+    pub fn render_row_right(
+        &self
+    ) -> String
+    {
+        format!("{}{} ", " ".repeat(self.padding), self.vertical)
+    }
+
     /// Renders every cell in a row, line by line, with vertical separators between columns.
-    /// 
+    ///
     /// # Arguments
-    /// 
+    ///
     /// * `lines` - Vector of formatted line strings
-    /// 
+    ///
     /// # Returns
-    /// 
+    ///
     /// A formatted text row string
 
     pub fn render_row_lines(
+        &self,
         lines: Vec<String>
     ) -> String
     {
-        let vertical = Border::VERTICAL.to_string();
-        let text = lines
-            .iter()
-            .cloned()
-            .collect::<Vec<String>>()
-            .join(&vertical);
-        format!("{}{}{}", vertical, text, vertical)
+        let text = lines.join(&self.render_row_separator());
+        format!("{}{}{}", self.render_row_left(), text, self.render_row_right())
     }
 
 }
@@ -0,0 +1,291 @@
+use crate::align::Align;
+use crate::border::BorderStyle;
+use crate::cell::Cell;
+use crate::error::ErrorMessage;
+use crate::grid::Grid;
+
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+
+/// A formatter turning the data of a cell into the text displayed in its column.
+
+type CellFormatter = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+/// The definition of a column of a `GridBuilder`: its header and the defaults applied to its cells.
+
+#[derive(Clone)]
+pub struct ColumnDef {
+    name: String,
+    align: Option<Align>,
+    width: Option<usize>,
+    formatter: Option<CellFormatter>,
+}
+
+impl ColumnDef {
+
+    /// Creates a column with the given header, without alignment, width, or formatter.
+
+    pub fn new(
+        name: impl Into<String>,
+    ) -> Self
+    {
+        ColumnDef {
+            name: name.into(),
+            align: None,
+            width: None,
+            formatter: None,
+        }
+    }
+
+    /// Gets the header of the column.
+
+    pub fn name(
+        &self
+    ) -> &str
+    {
+        &self.name
+    }
+
+    /// Gets the alignment of the data cells, if any.
+
+    pub fn align(
+        &self
+    ) -> Option<Align>
+    {
+        self.align
+    }
+
+    /// Sets the alignment of the data cells, e.g. `Align::Right` for numbers.
+    ///
+    /// The header keeps its own alignment, centered like other header rows.
+
+    pub fn set_align(
+        &mut self,
+        new_align: Align,
+    )
+    {
+        self.align = Some(new_align);
+    }
+
+    /// Gets the fixed width of the column, if any.
+
+    pub fn width(
+        &self
+    ) -> Option<usize>
+    {
+        self.width
+    }
+
+    /// Sets a fixed width for the column, applied to every cell with `Cell::set_width`,
+    /// so longer lines are truncated and shorter ones padded.
+
+    pub fn set_width(
+        &mut self,
+        new_width: usize,
+    )
+    {
+        self.width = Some(new_width);
+    }
+
+    /// Sets a formatter applied to the data of every data cell of the column,
+    /// e.g. `|value| format!("{} ms", value)`.
+
+    pub fn set_formatter(
+        &mut self,
+        formatter: impl Fn(&str) -> String + Send + Sync + 'static,
+    )
+    {
+        self.formatter = Some(Arc::new(formatter));
+    }
+
+    /// Creates a data cell of the column from a value, or an empty cell if the value is missing.
+    ///
+    /// Missing values are not passed to the formatter.
+
+    fn data_cell(
+        &self,
+        value: Option<Cell>,
+    ) -> Cell
+    {
+        let mut cell = match (value, &self.formatter) {
+            (Some(mut cell), Some(formatter)) => {
+                let data = formatter(cell.get_data());
+                cell.set_data(data);
+                cell
+            },
+            (value, _) => value.unwrap_or_default(),
+        };
+        if let Some(align) = self.align {
+            cell.set_align(align);
+        }
+        if let Some(width) = self.width {
+            cell.set_width(width);
+        }
+        cell
+    }
+
+}
+
+impl Debug for ColumnDef {
+
+    fn fmt(
+        &self,
+        f: &mut Formatter,
+    ) -> fmt::Result
+    {
+        f.debug_struct("ColumnDef")
+            .field("name", &self.name)
+            .field("align", &self.align)
+            .field("width", &self.width)
+            .field("formatter", &self.formatter.is_some())
+            .finish()
+    }
+
+}
+
+/// Builds a grid with a header row from column definitions declared up front,
+/// so the configuration of each column lives in one place instead of in the setters of every cell.
+///
+/// Rows are added with `add_row`, and `build` applies the column definitions to them:
+/// the header row is formatted in bold and centered, and every data cell is formatted,
+/// aligned, and sized as its column defines.
+
+#[derive(Debug, Clone, Default)]
+pub struct GridBuilder {
+    columns: Vec<ColumnDef>,
+    rows: Vec<Vec<Cell>>,
+}
+
+impl GridBuilder {
+
+    /// Creates a builder without columns or rows.
+
+    pub fn new() -> Self {
+        GridBuilder::default()
+    }
+
+    /// Appends a column definition to the right of the previous ones.
+
+    pub fn add_column(
+        &mut self,
+        column: ColumnDef,
+    )
+    {
+        self.columns.push(column);
+    }
+
+    /// Gets the column definitions, from left to right.
+
+    pub fn columns(
+        &self
+    ) -> &[ColumnDef]
+    {
+        &self.columns
+    }
+
+    /// Appends a row of values, one per column.
+    ///
+    /// Like `Grid::push_row`, missing values are filled with empty cells, which are not formatted,
+    /// and values beyond the last column are dropped when building the grid.
+
+    pub fn add_row(
+        &mut self,
+        values: impl IntoIterator<Item = impl Into<Cell>>,
+    )
+    {
+        self.rows.push(values.into_iter().map(Into::into).collect());
+    }
+
+    /// Gets the number of rows added so far, without the header.
+
+    pub fn row_count(
+        &self
+    ) -> usize
+    {
+        self.rows.len()
+    }
+
+    /// Builds the grid, with the header row followed by the data rows.
+
+    pub fn build(
+        self
+    ) -> Grid
+    {
+        let mut grid = Grid::with_capacity(self.rows.len() + 1, self.columns.len());
+        grid.push_row(self.columns.iter().map(|column| Cell::new(column.name.as_str())));
+        grid.style_header_row();
+        for (column, cell) in self.columns.iter().zip(grid.row_iter_mut(0)) {
+            if let Some(width) = column.width {
+                cell.set_width(width);
+            }
+        }
+
+        for row in self.rows {
+            let mut values = row.into_iter();
+            grid.push_row(self.columns.iter().map(|column| column.data_cell(values.next())));
+        }
+        grid
+    }
+
+}
+
+impl Grid {
+
+    /// Inserts a header row above the first row, in bold and centered as in `GridBuilder::build`,
+    /// so a configured grid can be built in one expression, e.g.
+    /// `Grid::from(rows).with_header(["id", "name"]).with_border(BorderStyle::Ascii)`.
+    ///
+    /// A grid without columns takes one column per header cell. Otherwise, like `insert_row`,
+    /// missing cells are filled with empty cells and extra cells are dropped.
+
+    pub fn with_header(
+        mut self,
+        header: impl IntoIterator<Item = impl Into<Cell>>,
+    ) -> Grid
+    {
+        let header: Vec<Cell> = header.into_iter().map(Into::into).collect();
+        if self.col_size() == 0 {
+            self.resize(self.row_size(), header.len());
+        }
+        self.insert_row(0, header);
+        self.style_header_row();
+        self
+    }
+
+    /// Sets the border style of the grid, as `set_border_style` does, and returns the grid.
+
+    pub fn with_border(
+        mut self,
+        border_style: BorderStyle,
+    ) -> Grid
+    {
+        self.set_border_style(border_style);
+        self
+    }
+
+    /// Aligns every cell of a column, such as `Align::Right` for numbers, and returns the grid.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// The alignment is combined with the one of each cell, so aligning a column horizontally
+    /// keeps the vertical alignment of its cells, and the other way around.
+
+    pub fn with_col_align(
+        mut self,
+        col_index: usize,
+        align: Align,
+    ) -> Grid
+    {
+        if col_index >= self.col_size() {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        for cell in self.col_iter_mut(col_index) {
+            cell.set_align(cell.get_align() | align);
+        }
+        self
+    }
+
+}
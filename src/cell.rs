@@ -1,9 +1,15 @@
 use crate::align::{AlignH, AlignV, Align};
+use crate::cell_data::CellData;
+use crate::cell_style::CellStyle;
 use crate::color::{Foreground, Background};
 use crate::fontstyle::FontStyle;
-use crate::format::apply_ansi_formatting;
+use crate::format::{AnsiPrefixes, write_ansi_formatted};
 
-use std::fmt::Display;
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::format;
+use core::fmt::{self, Display, Formatter};
 
 /// A single cell in the grid.
 /// 
@@ -12,7 +18,7 @@ use std::fmt::Display;
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Cell {
-    data: String,
+    data: CellData,
     h_align: Option<AlignH>,
     v_align: Option<AlignV>,
     fg_color: Option<Foreground>,
@@ -20,8 +26,19 @@ pub struct Cell {
     font_style: FontStyle,
     width: Option<usize>,
     height: Option<usize>,
+    null: bool,
+    progress: Option<u16>,
+    flag: Option<bool>,
 }
 
+/// The width of the bar of a progress cell when nothing else sets the width of its column.
+
+const PROGRESS_BAR_WIDTH: usize = 10;
+
+/// The width taken by the percentage of a progress cell, right-aligned so the bars of a column line up.
+
+const PROGRESS_LABEL_WIDTH: usize = 4;
+
 
 impl Cell {
 
@@ -34,7 +51,24 @@ impl Cell {
     ) -> Self
     {
         Cell {
-            data: data.into(),
+            data: CellData::from_string(data.into()),
+            ..Cell::from_static("")
+        }
+    }
+
+    /// Creates a new cell borrowing a string literal, without copying it.
+    ///
+    /// Cells created with `new` or converted with `From` always own a copy of their data,
+    /// so tables full of labels and other literals can use this instead to avoid allocating.
+    /// The data is only copied when it is modified through `get_data_mut`.
+    /// This is a `const fn`, so it can also be used to define constant cells.
+
+    pub const fn from_static(
+        data: &'static str,
+    ) -> Self
+    {
+        Cell {
+            data: CellData::from_static(data),
             h_align: None,
             v_align: None,
             fg_color: None,
@@ -42,7 +76,155 @@ impl Cell {
             font_style: FontStyle::new(),
             width: None,
             height: None,
+            null: false,
+            progress: None,
+            flag: None,
+        }
+    }
+
+    /// Creates a cell standing for a null value or a missing field, dimmed to tell it apart from text.
+
+    pub(crate) fn null() -> Self {
+        let mut cell = Cell::from_static("null");
+        cell.set_style(FontStyle::Dim);
+        cell.null = true;
+        cell
+    }
+
+    /// Checks whether the cell stands for a null value or a missing field,
+    /// as created when importing JSON, maps, database rows, or DataFrames.
+    ///
+    /// Setting the data of the cell makes it a regular cell.
+
+    pub fn is_null(
+        &self
+    ) -> bool
+    {
+        self.null
+    }
+
+    /// Checks whether two cells hold the same content, ignoring all formatting:
+    /// their data, and whether they stand for null values, are compared,
+    /// but not their alignment, colors, font styles, or sizes.
+    ///
+    /// `==` compares the formatting too, which tests and diffs usually do not care about.
+
+    pub fn content_eq(
+        &self,
+        other: &Cell,
+    ) -> bool
+    {
+        self.data == other.data && self.null == other.null
+    }
+
+    /// Replaces the text shown for a null cell, keeping it a null cell.
+
+    pub(crate) fn set_null_text(
+        &mut self,
+        text: &str,
+    )
+    {
+        self.data = CellData::from_string(text.to_string());
+    }
+
+    /// Creates a cell standing for a binary value, such as a database BLOB.
+    ///
+    /// Values of up to 16 bytes are shown as hexadecimal, like `0x1f8b`,
+    /// and longer ones as a dimmed length placeholder, like `<2048 bytes>`.
+
+    #[cfg(any(feature = "sqlx", feature = "rusqlite"))]
+    pub(crate) fn blob(
+        bytes: &[u8],
+    ) -> Self
+    {
+        if bytes.len() > 16 {
+            let mut cell = Cell::new(format!("<{} bytes>", bytes.len()));
+            cell.set_style(FontStyle::Dim);
+            return cell;
         }
+        Cell::new(bytes.iter().fold(String::from("0x"), |hex, byte| hex + &format!("{:02x}", byte)))
+    }
+
+    /// Creates a cell showing a progress bar for a fraction between 0 and 1, such as `█████░░░  62%`,
+    /// e.g. for the tasks of a status dashboard.
+    ///
+    /// The fraction is clamped to that range, and NaN counts as 0. The data of the cell is
+    /// the percentage, such as `62%`, which is what exports and comparisons see. When rendering,
+    /// the bar stretches to fill the width of its column, which is at least wide enough for a bar
+    /// of 10 characters; a column too narrow for any bar shows the percentage alone.
+    /// Setting the data of the cell makes it a regular cell.
+
+    pub fn progress(
+        fraction: f64,
+    ) -> Self
+    {
+        let fraction = if fraction.is_nan() { 0.0 } else { fraction.clamp(0.0, 1.0) };
+        // Rounded to the nearest ten-thousandth, the fraction being positive
+        let progress = (fraction * 10000.0 + 0.5) as u16;
+        let mut cell = Cell::new(format!("{}%", (progress + 50) / 100));
+        cell.progress = Some(progress);
+        cell
+    }
+
+    /// Gets the fraction shown by a progress cell, or None if it is a regular cell.
+
+    pub fn get_progress(
+        &self
+    ) -> Option<f64>
+    {
+        self.progress.map(|progress| f64::from(progress) / 10000.0)
+    }
+
+    /// Gets the value shown by a boolean cell, as created by `Cell::from_bool`,
+    /// or None if it is a regular cell.
+
+    pub fn get_bool(
+        &self
+    ) -> Option<bool>
+    {
+        self.flag
+    }
+
+    /// Marks the cell as showing a boolean value with its data as the glyph.
+
+    pub(crate) fn set_flag(
+        &mut self,
+        value: bool,
+    )
+    {
+        self.flag = Some(value);
+    }
+
+    /// Draws a cell whose line is only known at render time, filling the given width:
+    /// the bar of a progress cell, or the glyph of a boolean cell aligned by its characters,
+    /// since glyphs such as `✓` take fewer columns than bytes.
+    ///
+    /// Returns None for regular cells, and for progress cells too narrow for a bar.
+
+    pub(crate) fn drawn_line(
+        &self,
+        width: usize,
+    ) -> Option<String>
+    {
+        if self.flag.is_some() {
+            let glyph: String = self.data.chars().take(width).collect();
+            let space = width - glyph.chars().count();
+            let left = match self.h_align.unwrap_or_default() {
+                AlignH::Left   => 0,
+                AlignH::Right  => space,
+                AlignH::Center => space / 2,
+            };
+            return Some(format!("{:left$}{}{:right$}", "", glyph, "", right = space - left));
+        }
+
+        let progress = usize::from(self.progress?);
+        let label_width = self.data.len().max(PROGRESS_LABEL_WIDTH);
+        let bar_width = width.checked_sub(label_width + 1).filter(|&bar_width| bar_width > 0)?;
+        let filled = (progress * bar_width + 5000) / 10000;
+        let mut bar = "█".repeat(filled);
+        bar.push_str(&"░".repeat(bar_width - filled));
+        bar.push_str(&format!(" {:>label_width$}", &*self.data));
+        Some(bar)
     }
 
     /// Sets the data of the cell.
@@ -54,7 +236,32 @@ impl Cell {
         new_data: impl Into<String>
     )
     {
-        self.data = new_data.into();
+        self.data = CellData::from_string(new_data.into());
+        self.null = false;
+        self.progress = None;
+        self.flag = None;
+    }
+
+    /// Replaces the cell's data with text shared with other cells, keeping it a null cell if it is one.
+
+    pub(crate) fn set_shared_data(
+        &mut self,
+        text: Arc<str>,
+    )
+    {
+        self.data = CellData::Shared(text);
+    }
+
+    /// Gets the number of heap bytes used by the cell's data, and the unused capacity after it.
+    ///
+    /// See `CellData::heap_usage` for how shared data is counted.
+
+    pub(crate) fn heap_usage(
+        &self,
+        shared: &mut BTreeSet<usize>,
+    ) -> (usize, usize)
+    {
+        self.data.heap_usage(shared)
     }
 
     /// Gets an immutable reference to the cell's data.
@@ -72,7 +279,16 @@ impl Cell {
         &mut self
     ) -> &mut str
     {
-        &mut self.data
+        self.data.as_mut_str()
+    }
+
+    /// Consumes the cell and returns its data.
+
+    pub(crate) fn into_data(
+        self
+    ) -> String
+    {
+        self.data.into_string()
     }
 
     pub(crate) fn height(
@@ -114,6 +330,12 @@ impl Cell {
         if let Some(width) = self.width {
             return width;
         }
+        if self.progress.is_some() {
+            return PROGRESS_BAR_WIDTH + 1 + self.data.len().max(PROGRESS_LABEL_WIDTH);
+        }
+        if self.flag.is_some() {
+            return self.data.chars().count();
+        }
         self.data.lines()
             .map(|line| line.len())
             .max()
@@ -159,6 +381,31 @@ impl Cell {
         self.v_align = new_align.get_v();
     }
 
+    /// Gets the alignment of the cell's content.
+    ///
+    /// Axes without an explicit alignment are left unset in the returned value.
+
+    pub fn get_align(
+        &self
+    ) -> Align
+    {
+        Align::from_parts(self.h_align, self.v_align)
+    }
+
+    pub(crate) fn h_align(
+        &self
+    ) -> Option<AlignH>
+    {
+        self.h_align
+    }
+
+    pub(crate) fn v_align(
+        &self
+    ) -> Option<AlignV>
+    {
+        self.v_align
+    }
+
     /// Sets the foreground color of the cell's text.
     /// 
     /// The `new_color` parameter should be a valid color string
@@ -219,72 +466,274 @@ impl Cell {
         self.height = None;
     }
 
-    pub(crate) fn render_lines(
+    /// Gets the foreground color of the cell's text, as one of the `Color` constants.
+
+    pub fn get_color(
+        &self
+    ) -> Option<&'static str>
+    {
+        self.fg_color.map(|color| color.as_str())
+    }
+
+    /// Gets the background color (highlight) of the cell's text, as one of the `Color` constants.
+
+    pub fn get_highlight(
+        &self
+    ) -> Option<&'static str>
+    {
+        self.bg_color.map(|color| color.as_str())
+    }
+
+    /// Gets the font style(s) of the cell's text.
+
+    pub fn get_style(
+        &self
+    ) -> FontStyle
+    {
+        self.font_style
+    }
+
+    pub(crate) fn fg_color(
+        &self
+    ) -> Option<Foreground>
+    {
+        self.fg_color
+    }
+
+    pub(crate) fn bg_color(
+        &self
+    ) -> Option<Background>
+    {
+        self.bg_color
+    }
+
+    pub(crate) fn font_style(
+        &self
+    ) -> FontStyle
+    {
+        self.font_style
+    }
+
+    /// Checks whether the cell has no colors, font styles, or alignment other than the default.
+    ///
+    /// A plain cell renders as its bare text followed by padding.
+
+    pub(crate) fn is_plain(
+        &self
+    ) -> bool
+    {
+        self.fg_color.is_none()
+            && self.bg_color.is_none()
+            && self.font_style == FontStyle::new()
+            && self.h_align.unwrap_or_default() == AlignH::Left
+            && self.v_align.unwrap_or_default() == AlignV::Top
+            && self.progress.is_none()
+            && self.flag.is_none()
+    }
+
+    /// Creates an empty cell with the colors of this cell and its font styles combined with another,
+    /// for styling a part of its text differently.
+
+    pub(crate) fn with_added_style(
+        &self,
+        style: FontStyle,
+    ) -> Cell
+    {
+        Cell {
+            fg_color: self.fg_color,
+            bg_color: self.bg_color,
+            font_style: self.font_style | style,
+            ..Cell::default()
+        }
+    }
+
+    /// Creates an empty cell with the colors and font styles of this cell,
+    /// taking the ones it does not set from a default style.
+
+    pub(crate) fn with_default_style(
+        &self,
+        default_style: &CellStyle,
+    ) -> Cell
+    {
+        Cell {
+            fg_color: self.fg_color.or(default_style.fg_color),
+            bg_color: self.bg_color.or(default_style.bg_color),
+            font_style: if self.font_style == FontStyle::new() { default_style.font_style } else { self.font_style },
+            ..Cell::default()
+        }
+    }
+
+    /// Positions the cell's content inside a cell of the target size.
+    ///
+    /// Lines are aligned and truncated to the target width, and blank lines are
+    /// added according to the vertical alignment to reach the target height,
+    /// using the alignment of the default style on the axes the cell does not align.
+    /// The lines are produced one at a time, without collecting them first.
+
+    pub(crate) fn layout_lines(
         &self,
         target_cell_height: usize,
         target_cell_width: usize,
-    ) -> Vec<String>
+        default_style: &CellStyle,
+    ) -> CellLines<'_>
     {
-        let mut visible_lens = self.data.lines()
-            .map(|line| line.len());
-        let data_lines = self.data.lines()
-            .map(|line| apply_ansi_formatting(line, self.fg_color, self.bg_color, self.font_style));
+        // The glyph of a boolean cell is drawn when rendering, in place of a blank line,
+        // as its bytes would not fit a column as wide as its characters
+        let data = if self.flag.is_some() { " " } else { &*self.data };
+        let height = data.lines().count();
+        let pad_count = target_cell_height.saturating_sub(height);
 
-        let height = self.data.lines().count();
-    
-        let v_align = self.v_align.unwrap_or_default();
-        let h_align = self.h_align.unwrap_or_default();
+        let top_pad_count = match self.v_align.or(default_style.v_align).unwrap_or_default() {
+            AlignV::Top    => 0,
+            AlignV::Bottom => pad_count,
+            AlignV::Middle => pad_count / 2,
+        };
+
+        CellLines {
+            lines: data.lines(),
+            h_align: self.h_align.or(default_style.h_align).unwrap_or_default(),
+            width: target_cell_width,
+            top_pad_count,
+            bottom_pad_count: pad_count - top_pad_count,
+        }
+    }
 
-        let pad_count = target_cell_height.saturating_sub(height);
-        let pad_string = " ".repeat(target_cell_width);
-
-        // Pre-allocate first since we know the size
-        let mut lines = Vec::with_capacity(target_cell_height);
-
-        // Add top padding
-        match v_align {
-            AlignV::Top => {},
-            AlignV::Bottom => {
-                lines.extend(std::iter::repeat(pad_string.clone()).take(pad_count));
-            },
-            AlignV::Middle => {
-                lines.extend(std::iter::repeat(pad_string.clone()).take(pad_count / 2));
-            },
+    /// Wraps text so that displaying it applies the cell's colors and font styles using ANSI escape codes.
+    ///
+    /// The escape sequences are taken from `prefixes`, so they are only built once per style.
+
+    pub(crate) fn styled<'a>(
+        &self,
+        text: &'a str,
+        prefixes: &'a mut AnsiPrefixes,
+    ) -> StyledText<'a>
+    {
+        let prefix = prefixes.prefix(self.fg_color, self.bg_color, self.font_style);
+        StyledText { prefix, text }
+    }
+
+}
+
+
+/// One line of a cell's content, positioned inside the cell.
+///
+/// The line occupies `left + text.len() + right` columns,
+/// where `left` and `right` are the number of padding spaces around the text.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CellLine<'a> {
+    pub(crate) left: usize,
+    pub(crate) text: &'a str,
+    pub(crate) right: usize,
+}
+
+/// Iterator over the positioned lines of a cell, as returned by `Cell::layout_lines`.
+///
+/// Yields the blank lines above the content, the aligned content lines, and the blank lines below it.
+
+#[derive(Debug, Clone)]
+pub(crate) struct CellLines<'a> {
+    lines: core::str::Lines<'a>,
+    h_align: AlignH,
+    width: usize,
+    top_pad_count: usize,
+    bottom_pad_count: usize,
+}
+
+/// Cuts a line to at most the specified width, at the last character boundary that fits,
+/// so multibyte characters are never split.
+
+pub(crate) fn truncate_line(
+    line: &str,
+    width: usize,
+) -> &str
+{
+    if line.len() <= width {
+        return line;
+    }
+    let mut end = width;
+    while !line.is_char_boundary(end) {
+        end -= 1;
+    }
+    &line[..end]
+}
+
+impl CellLines<'_> {
+
+    /// Creates the lines of a cell shown without any content, such as a merged cell below the first row of its run.
+
+    pub(crate) fn blank(
+        target_cell_height: usize,
+        target_cell_width: usize,
+    ) -> Self
+    {
+        CellLines {
+            lines: "".lines(),
+            h_align: AlignH::default(),
+            width: target_cell_width,
+            top_pad_count: target_cell_height,
+            bottom_pad_count: 0,
         }
+    }
+
+}
 
-        // Add content lines
-        for line in data_lines {
-            let visible_len = visible_lens.next().unwrap_or(0);
-            lines.push(
-                if visible_len < target_cell_width {
-                    // Apply horizontal alignment
-                    let width = target_cell_width + line.len() - visible_len;
-                    match h_align {
-                        AlignH::Left   => format!("{:<width$}", line, width = width),
-                        AlignH::Right  => format!("{:>width$}", line, width = width),
-                        AlignH::Center => format!("{:^width$}", line, width = width),
-                    }
-                } else if visible_len == target_cell_width {
-                    line
-                } else {
-                    // Truncate the line to fit the target width
-                    line[..target_cell_width].to_string()
-                }
-            );
+impl<'a> Iterator for CellLines<'a> {
+
+    type Item = CellLine<'a>;
+
+    fn next(
+        &mut self
+    ) -> Option<CellLine<'a>>
+    {
+        let pad_line = CellLine { left: self.width, text: "", right: 0 };
+
+        if self.top_pad_count > 0 {
+            self.top_pad_count -= 1;
+            return Some(pad_line);
         }
 
-        // Add bottom padding
-        match v_align {
-            AlignV::Top => {
-                lines.extend(std::iter::repeat(pad_string).take(pad_count));
-            },
-            AlignV::Bottom => {},
-            AlignV::Middle => {
-                lines.extend(std::iter::repeat(pad_string).take(pad_count - pad_count / 2));
-            },
+        if let Some(line) = self.lines.next() {
+            // Truncate the line to fit the target width
+            let text = truncate_line(line, self.width);
+            // Apply horizontal alignment
+            let space = self.width - text.len();
+            let left = match self.h_align {
+                AlignH::Left   => 0,
+                AlignH::Right  => space,
+                AlignH::Center => space / 2,
+            };
+            return Some(CellLine { left, text, right: space - left });
         }
 
-        lines
+        if self.bottom_pad_count > 0 {
+            self.bottom_pad_count -= 1;
+            return Some(pad_line);
+        }
+
+        None
+    }
+
+}
+
+/// Text displayed with the colors and font styles of a cell, as returned by `Cell::styled`.
+///
+/// Empty text is displayed as nothing at all, without any escape sequences.
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StyledText<'a> {
+    prefix: &'a str,
+    text: &'a str,
+}
+
+impl Display for StyledText<'_> {
+
+    fn fmt(
+        &self,
+        f: &mut Formatter,
+    ) -> fmt::Result
+    {
+        write_ansi_formatted(f, self.prefix, self.text)
     }
 
 }
@@ -296,7 +745,7 @@ where
 {
 
     fn from(value: T) -> Self {
-        Cell::new(value.to_string())
+        Cell { data: CellData::from_display(&value), ..Cell::default() }
     }
 
 }
@@ -0,0 +1,180 @@
+#[cfg(not(feature = "compact_str"))]
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::mem::size_of;
+use core::ops::Deref;
+
+/// The storage of data owned by a single cell.
+///
+/// With the `compact_str` feature, data of up to 24 bytes (on 64-bit targets) is stored inline,
+/// saving a heap allocation for most cells.
+
+#[cfg(not(feature = "compact_str"))]
+type UniqueData = Cow<'static, str>;
+#[cfg(feature = "compact_str")]
+type UniqueData = compact_str::CompactString;
+
+/// The storage of a cell's data.
+///
+/// Data is either owned by the cell or borrowed from a string literal, as created by
+/// `Cell::from_static`, or shared with other cells holding the same text, as done by `Interner`.
+/// Either way it behaves as a plain string: equality and `Debug` only look at the text.
+
+#[derive(Clone)]
+pub(crate) enum CellData {
+    Unique(UniqueData),
+    Shared(Arc<str>),
+}
+
+impl CellData {
+
+    /// Borrows a string literal, without copying it.
+
+    pub(crate) const fn from_static(
+        text: &'static str,
+    ) -> Self
+    {
+        #[cfg(not(feature = "compact_str"))]
+        return CellData::Unique(Cow::Borrowed(text));
+        #[cfg(feature = "compact_str")]
+        return CellData::Unique(UniqueData::const_new(text));
+    }
+
+    /// Takes ownership of a string.
+
+    pub(crate) fn from_string(
+        text: String,
+    ) -> Self
+    {
+        CellData::Unique(UniqueData::from(text))
+    }
+
+    /// Formats a value, straight into the inline storage with the `compact_str` feature.
+
+    pub(crate) fn from_display(
+        value: &impl Display,
+    ) -> Self
+    {
+        #[cfg(not(feature = "compact_str"))]
+        return CellData::from_string(value.to_string());
+        #[cfg(feature = "compact_str")]
+        return CellData::Unique(compact_str::ToCompactString::to_compact_string(value));
+    }
+
+    /// Gets a mutable reference to the text, copying it first unless it is owned by this cell.
+
+    pub(crate) fn as_mut_str(
+        &mut self
+    ) -> &mut str
+    {
+        if let CellData::Shared(text) = self {
+            *self = CellData::from_string(text.to_string());
+        }
+        match self {
+            #[cfg(not(feature = "compact_str"))]
+            CellData::Unique(text) => text.to_mut(),
+            #[cfg(feature = "compact_str")]
+            CellData::Unique(text) => text.as_mut_str(),
+            CellData::Shared(_) => unreachable!("shared data was made unique above"),
+        }
+    }
+
+    /// Converts the data into an owned string.
+
+    pub(crate) fn into_string(
+        self
+    ) -> String
+    {
+        match self {
+            #[cfg(not(feature = "compact_str"))]
+            CellData::Unique(text) => text.into_owned(),
+            #[cfg(feature = "compact_str")]
+            CellData::Unique(text) => text.into_string(),
+            CellData::Shared(text) => text.to_string(),
+        }
+    }
+
+    /// Gets the number of heap bytes used by the text, and the unused capacity allocated after it.
+    ///
+    /// Data borrowed from a string literal or stored inline uses no heap memory.
+    /// Shared data, including its reference counts, is only counted the first time its address
+    /// is added to `shared`, so that each copy is counted once for all the cells sharing it.
+
+    pub(crate) fn heap_usage(
+        &self,
+        shared: &mut BTreeSet<usize>,
+    ) -> (usize, usize)
+    {
+        match self {
+            #[cfg(not(feature = "compact_str"))]
+            CellData::Unique(Cow::Borrowed(_)) => (0, 0),
+            #[cfg(not(feature = "compact_str"))]
+            CellData::Unique(Cow::Owned(text)) => (text.len(), text.capacity() - text.len()),
+            #[cfg(feature = "compact_str")]
+            CellData::Unique(text) if text.is_heap_allocated() => (text.len(), text.capacity() - text.len()),
+            #[cfg(feature = "compact_str")]
+            CellData::Unique(_) => (0, 0),
+            CellData::Shared(text) => {
+                if shared.insert(text.as_ptr() as usize) {
+                    (text.len() + 2 * size_of::<usize>(), 0)
+                } else {
+                    (0, 0)
+                }
+            },
+        }
+    }
+
+}
+
+impl Default for CellData {
+
+    fn default() -> Self {
+        CellData::from_static("")
+    }
+
+}
+
+impl Deref for CellData {
+
+    type Target = str;
+
+    fn deref(
+        &self
+    ) -> &str
+    {
+        match self {
+            CellData::Unique(text) => text,
+            CellData::Shared(text) => text,
+        }
+    }
+
+}
+
+impl PartialEq for CellData {
+
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool
+    {
+        **self == **other
+    }
+
+}
+
+impl Eq for CellData {}
+
+impl Debug for CellData {
+
+    fn fmt(
+        &self,
+        f: &mut Formatter,
+    ) -> fmt::Result
+    {
+        Debug::fmt(&**self, f)
+    }
+
+}
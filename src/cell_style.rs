@@ -0,0 +1,151 @@
+use crate::align::{Align, AlignH, AlignV};
+use crate::color::{Background, Foreground};
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+
+/// The formatting a grid gives to the cells that do not set their own, set with `Grid::set_default_style`,
+/// such as "everything dim, middle-aligned".
+///
+/// Every setting applies separately: a cell with its own color still takes the default alignment.
+/// A cell without font styles takes the default font styles, and the horizontal and vertical
+/// alignments are taken one axis at a time.
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CellStyle {
+    pub(crate) h_align: Option<AlignH>,
+    pub(crate) v_align: Option<AlignV>,
+    pub(crate) fg_color: Option<Foreground>,
+    pub(crate) bg_color: Option<Background>,
+    pub(crate) font_style: FontStyle,
+}
+
+impl CellStyle {
+
+    /// Creates a style without any setting, leaving every cell as it is.
+
+    pub fn new() -> Self {
+        CellStyle::default()
+    }
+
+    /// Gets the alignment, with the axes without a default alignment left unset.
+
+    pub fn align(
+        &self
+    ) -> Align
+    {
+        Align::from_parts(self.h_align, self.v_align)
+    }
+
+    /// Sets the alignment, such as `Align::Left | Align::Middle`.
+    ///
+    /// An axis without an alignment is left to the cells, which default to left and top.
+
+    pub fn set_align(
+        &mut self,
+        new_align: Align,
+    )
+    {
+        self.h_align = new_align.get_h();
+        self.v_align = new_align.get_v();
+    }
+
+    /// Gets the foreground color, as one of the `Color` constants, if set.
+
+    pub fn color(
+        &self
+    ) -> Option<&'static str>
+    {
+        self.fg_color.map(|color| color.as_str())
+    }
+
+    /// Sets the foreground color of the text.
+    ///
+    /// Unrecognized color strings result in no color being set.
+
+    pub fn set_color(
+        &mut self,
+        new_color: &str,
+    )
+    {
+        self.fg_color = Foreground::from_str(new_color);
+    }
+
+    /// Gets the background color (highlight), as one of the `Color` constants, if set.
+
+    pub fn highlight(
+        &self
+    ) -> Option<&'static str>
+    {
+        self.bg_color.map(|color| color.as_str())
+    }
+
+    /// Sets the background color (highlight) of the text.
+    ///
+    /// Unrecognized color strings result in no color being set.
+
+    pub fn set_highlight(
+        &mut self,
+        new_color: &str,
+    )
+    {
+        self.bg_color = Background::from_str(new_color);
+    }
+
+    /// Gets the font style(s).
+
+    pub fn style(
+        &self
+    ) -> FontStyle
+    {
+        self.font_style
+    }
+
+    /// Sets the font style(s), such as `FontStyle::Dim`.
+
+    pub fn set_style(
+        &mut self,
+        new_style: FontStyle,
+    )
+    {
+        self.font_style = new_style;
+    }
+
+    /// Returns true if the style has no setting, so cells render as they are.
+
+    pub(crate) fn is_empty(
+        &self
+    ) -> bool
+    {
+        *self == CellStyle::default()
+    }
+
+}
+
+impl Grid {
+
+    /// Sets the formatting of the cells that do not set their own, the bottom layer below
+    /// the formatting of each cell, so a look such as "everything dim and middle-aligned"
+    /// is configured once.
+    ///
+    /// The cells are not modified: the default style is applied when the grid is rendered,
+    /// in text and HTML, and exports reading the formatting of each cell, such as xlsx, ignore it.
+    /// Every row is marked as dirty, since the rows render differently.
+
+    pub fn set_default_style(
+        &mut self,
+        new_style: CellStyle,
+    )
+    {
+        *self.default_style_mut() = new_style;
+    }
+
+    /// Removes the default style, so cells render with only their own formatting.
+
+    pub fn clear_default_style(
+        &mut self
+    )
+    {
+        self.set_default_style(CellStyle::default());
+    }
+
+}
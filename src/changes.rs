@@ -0,0 +1,133 @@
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+
+use alloc::vec::Vec;
+
+impl Grid {
+
+    /// Adds a font style to every cell whose content differs from the cell at the same position
+    /// in a previous state of the grid, like `watch -d`.
+    ///
+    /// Only the data of the cells is compared, so formatting changes are not highlighted.
+    /// Cells outside of the previous grid, e.g. in newly added rows, count as changed.
+    /// The style is combined with the cell's own font styles.
+    ///
+    /// # Returns
+    ///
+    /// The number of changed cells
+
+    pub fn highlight_changes(
+        &mut self,
+        previous: &Grid,
+        style: FontStyle,
+    ) -> usize
+    {
+        let col_size = self.col_size();
+        let mut changed = 0;
+        for (index, cell) in self.flat_iter_mut().enumerate() {
+            let previous_cell = previous.get_cell(index / col_size, index % col_size);
+            if previous_cell.is_none_or(|previous_cell| previous_cell.get_data() != cell.get_data()) {
+                cell.set_style(cell.get_style() | style);
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+}
+
+/// Helper for highlighting the cells that changed between frames of a refreshing table.
+///
+/// Every frame is compared with the previous one, and each cell is styled according to
+/// the number of frames since its content last changed: a cell that changed in this frame
+/// gets the first style, one that changed in the previous frame the second style, and so on,
+/// so highlights can fade out over several frames. Cells that changed longer ago than
+/// there are styles, and all cells of the first frame, are left as they are.
+
+#[derive(Debug, Clone)]
+pub struct ChangeHighlighter {
+    styles: Vec<FontStyle>,
+    previous: Option<Grid>,
+    ages: Vec<Option<usize>>,
+}
+
+impl Default for ChangeHighlighter {
+
+    fn default() -> Self {
+        ChangeHighlighter::new()
+    }
+
+}
+
+impl ChangeHighlighter {
+
+    /// Creates a change highlighter that shows the cells changed in the last frame in reverse video,
+    /// like `watch -d`.
+
+    pub fn new() -> Self {
+        ChangeHighlighter::with_styles([FontStyle::Reverse])
+    }
+
+    /// Creates a change highlighter with one style per frame of age, such as
+    /// `[FontStyle::Reverse, FontStyle::Bold, FontStyle::Underline]` to fade out over three frames.
+
+    pub fn with_styles(
+        styles: impl IntoIterator<Item = FontStyle>,
+    ) -> Self
+    {
+        ChangeHighlighter {
+            styles: styles.into_iter().collect(),
+            previous: None,
+            ages: Vec::new(),
+        }
+    }
+
+    /// Compares the grid with the previous frame and returns a copy with the changed cells highlighted.
+    ///
+    /// The grid itself is kept unchanged as the new previous frame.
+    /// See `Grid::highlight_changes` for how cells are compared.
+
+    pub fn highlight(
+        &mut self,
+        grid: &Grid,
+    ) -> Grid
+    {
+        let col_size = grid.col_size();
+        let ages: Vec<Option<usize>> = grid.flat_iter()
+            .enumerate()
+            .map(|(index, cell)| {
+                let previous = self.previous.as_ref()?;
+                let (row_index, col_index) = (index / col_size, index % col_size);
+                let age = match previous.get_cell(row_index, col_index) {
+                    Some(previous_cell) if previous_cell.get_data() == cell.get_data() => {
+                        self.ages[row_index * previous.col_size() + col_index]? + 1
+                    },
+                    _ => 0,
+                };
+                // Ages beyond the last style are forgotten
+                (age < self.styles.len()).then_some(age)
+            })
+            .collect();
+
+        let mut highlighted = grid.clone();
+        for (cell, age) in highlighted.flat_iter_mut().zip(&ages) {
+            if let Some(&style) = age.and_then(|age| self.styles.get(age)) {
+                cell.set_style(cell.get_style() | style);
+            }
+        }
+        self.previous = Some(grid.clone());
+        self.ages = ages;
+        highlighted
+    }
+
+    /// Forgets the previous frame, so nothing is highlighted in the next one.
+
+    pub fn reset(
+        &mut self
+    )
+    {
+        self.previous = None;
+        self.ages.clear();
+    }
+
+}
@@ -0,0 +1,212 @@
+use crate::align::{Align, AlignH};
+use crate::cell::Cell;
+use crate::error::ErrorMessage;
+use crate::grid::Grid;
+use crate::locale::Locale;
+use crate::sort::{SortType, parse_date, parse_number};
+
+use alloc::string::String;
+
+/// The type of the data in a column, set with `Grid::set_col_type` so typed data looks right
+/// without configuring every cell.
+///
+/// The type decides how the cells holding a value of the type are aligned and formatted,
+/// what null cells show (see `Cell::is_null`), and how `Grid::sort_by_col` compares the cells.
+/// Cells holding other text, such as a header, are left unchanged.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColType {
+    /// Any text, left as is and sorted lexicographically, like columns without a type.
+    #[default]
+    Text,
+    /// Decimal numbers, also in the locale of the grid (see `Grid::set_locale`),
+    /// right-aligned with surrounding whitespace removed, and sorted by value.
+    /// Null cells show `-`.
+    Numeric,
+    /// ISO 8601 dates with an optional time, written with `-` between date parts and a space
+    /// before the time (`2024-03-01 14:30`), and sorted chronologically. Null cells show `-`.
+    DateTime,
+    /// Booleans written as `true`/`false`, `yes`/`no`, `y`/`n`, `on`/`off`, or `1`/`0` in any case,
+    /// centered and written as `true` or `false`, with `false` sorted first. Null cells show `-`.
+    Bool,
+}
+
+impl ColType {
+
+    /// Gets the comparison used when sorting a column of this type.
+
+    pub fn sort_type(
+        self
+    ) -> SortType
+    {
+        match self {
+            ColType::Text     => SortType::Lexicographic,
+            ColType::Numeric  => SortType::Numeric,
+            ColType::DateTime => SortType::Date,
+            ColType::Bool     => SortType::Bool,
+        }
+    }
+
+    /// Gets the horizontal alignment of the values of this type.
+
+    fn align(
+        self
+    ) -> AlignH
+    {
+        match self {
+            ColType::Text | ColType::DateTime => AlignH::Left,
+            ColType::Numeric                  => AlignH::Right,
+            ColType::Bool                     => AlignH::Center,
+        }
+    }
+
+    /// Gets the text shown in null cells of this type, or None to leave them as is.
+
+    fn null_text(
+        self
+    ) -> Option<&'static str>
+    {
+        match self {
+            ColType::Text => None,
+            _             => Some("-"),
+        }
+    }
+
+    /// Formats the text of a cell as a value of this type, or returns None if it is not one
+    /// or needs no formatting.
+
+    fn format(
+        self,
+        text: &str,
+        locale: &Locale,
+    ) -> Option<String>
+    {
+        let trimmed = text.trim();
+        match self {
+            // Text needs no formatting, and aligning it would override a centered header
+            ColType::Text => None,
+            ColType::Numeric => parse_number(text, locale).map(|_| String::from(trimmed)),
+            ColType::DateTime => parse_date(text).map(|_| trimmed.replacen('T', " ", 1).replace('/', "-")),
+            ColType::Bool => parse_bool(trimmed).map(|value| String::from(if value { "true" } else { "false" })),
+        }
+    }
+
+    /// Applies the defaults of this type to a cell.
+
+    fn apply(
+        self,
+        cell: &mut Cell,
+        locale: &Locale,
+    )
+    {
+        if cell.is_null() {
+            let Some(null_text) = self.null_text() else {
+                return;
+            };
+            cell.set_null_text(null_text);
+        } else if let Some(formatted) = self.format(cell.get_data(), locale) {
+            if formatted != cell.get_data() {
+                cell.set_data(formatted);
+            }
+        } else {
+            return;
+        }
+        cell.set_align(Align::from_parts(Some(self.align()), cell.v_align()));
+    }
+
+}
+
+impl Grid {
+
+    /// Gets the type of a column, which is `ColType::Text` unless set with `set_col_type`.
+
+    pub fn col_type(
+        &self,
+        col_index: usize,
+    ) -> ColType
+    {
+        self.col_types().get(&col_index).copied().unwrap_or_default()
+    }
+
+    /// Sets the type of a column and applies its defaults to the cells of the column:
+    /// the cells holding a value of the type are formatted and aligned for it,
+    /// and null cells show the null text of the type, if it has one.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// Cells holding other text, such as a header, are left unchanged, and so are cells
+    /// added later, so set the type again after adding rows. The type is kept to sort with
+    /// `sort_by_col`, and moves with the column when columns are inserted or reordered.
+
+    pub fn set_col_type(
+        &mut self,
+        col_index: usize,
+        col_type: ColType,
+    )
+    {
+        if col_index >= self.col_size() {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        self.col_types_mut().insert(col_index, col_type);
+        let locale = *self.locale();
+        for cell in self.col_iter_mut(col_index) {
+            col_type.apply(cell, &locale);
+        }
+    }
+
+    /// Sorts the rows by the cells of a column, compared as the type of the column.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// This is a shortcut for `sort_by_col_typed` with the comparison of `col_type`.
+
+    pub fn sort_by_col(
+        &mut self,
+        col_index: usize,
+    )
+    {
+        self.sort_by_col_typed(col_index, self.col_type(col_index).sort_type());
+    }
+
+}
+
+/// Parses a cell as a boolean, ignoring case.
+
+pub(crate) fn parse_bool(
+    text: &str,
+) -> Option<bool>
+{
+    const TRUE: [&str; 5] = ["true", "yes", "y", "on", "1"];
+    const FALSE: [&str; 5] = ["false", "no", "n", "off", "0"];
+    if TRUE.iter().any(|word| text.eq_ignore_ascii_case(word)) {
+        Some(true)
+    } else if FALSE.iter().any(|word| text.eq_ignore_ascii_case(word)) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{ColType, Grid, SortType};
+
+    use alloc::vec::Vec;
+
+    #[test]
+    fn bool_columns_sort_false_first() {
+        let mut grid = Grid::from([["yes"], ["n/a"], ["0"], ["TRUE"], ["off"]]);
+        assert_eq!(ColType::Bool.sort_type(), SortType::Bool);
+        grid.sort_by_col_typed(0, SortType::Bool);
+        let col: Vec<&str> = grid.col_iter(0).map(|cell| cell.get_data()).collect();
+        assert_eq!(col, ["0", "off", "yes", "TRUE", "n/a"]);
+
+        grid.set_col_type(0, ColType::Bool);
+        grid.sort_by_col(0);
+        let col: Vec<&str> = grid.col_iter(0).map(|cell| cell.get_data()).collect();
+        assert_eq!(col, ["false", "false", "true", "true", "n/a"]);
+    }
+
+}
@@ -1,5 +1,8 @@
 use crate::ansi::*;
 
+#[cfg(feature = "serde")]
+use alloc::string::String;
+
 /// Represents standard colors for foreground and background styling.
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -27,6 +30,24 @@ impl Color {
 }
 
 
+// RGB values of the 16 terminal colors, used wherever colors are exported outside of a terminal.
+const BLACK_RGB          : (u8, u8, u8) = (0, 0, 0);
+const RED_RGB            : (u8, u8, u8) = (205, 49, 49);
+const GREEN_RGB          : (u8, u8, u8) = (13, 188, 121);
+const YELLOW_RGB         : (u8, u8, u8) = (229, 229, 16);
+const BLUE_RGB           : (u8, u8, u8) = (36, 114, 200);
+const MAGENTA_RGB        : (u8, u8, u8) = (188, 63, 188);
+const CYAN_RGB           : (u8, u8, u8) = (17, 168, 205);
+const WHITE_RGB          : (u8, u8, u8) = (229, 229, 229);
+const BRIGHT_BLACK_RGB   : (u8, u8, u8) = (102, 102, 102);
+const BRIGHT_RED_RGB     : (u8, u8, u8) = (241, 76, 76);
+const BRIGHT_GREEN_RGB   : (u8, u8, u8) = (35, 209, 139);
+const BRIGHT_YELLOW_RGB  : (u8, u8, u8) = (245, 245, 67);
+const BRIGHT_BLUE_RGB    : (u8, u8, u8) = (59, 142, 234);
+const BRIGHT_MAGENTA_RGB : (u8, u8, u8) = (214, 112, 214);
+const BRIGHT_CYAN_RGB    : (u8, u8, u8) = (41, 184, 219);
+const BRIGHT_WHITE_RGB   : (u8, u8, u8) = (255, 255, 255);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Foreground {
     Black,
@@ -74,6 +95,32 @@ impl Foreground {
         }
     }
 
+    /// Gets the color name, as found in the `Color` constants.
+
+    pub fn as_str(
+        &self
+    ) -> &'static str
+    {
+        match self {
+            Self::Black         => Color::BLACK,
+            Self::Red           => Color::RED,
+            Self::Green         => Color::GREEN,
+            Self::Yellow        => Color::YELLOW,
+            Self::Blue          => Color::BLUE,
+            Self::Magenta       => Color::MAGENTA,
+            Self::Cyan          => Color::CYAN,
+            Self::White         => Color::WHITE,
+            Self::BrightBlack   => Color::BRIGHT_BLACK,
+            Self::BrightRed     => Color::BRIGHT_RED,
+            Self::BrightGreen   => Color::BRIGHT_GREEN,
+            Self::BrightYellow  => Color::BRIGHT_YELLOW,
+            Self::BrightBlue    => Color::BRIGHT_BLUE,
+            Self::BrightMagenta => Color::BRIGHT_MAGENTA,
+            Self::BrightCyan    => Color::BRIGHT_CYAN,
+            Self::BrightWhite   => Color::BRIGHT_WHITE,
+        }
+    }
+
     pub fn as_ansi_code(
         &self
     ) -> &str
@@ -98,6 +145,32 @@ impl Foreground {
         }
     }
 
+    /// Gets the RGB value of the color.
+
+    pub fn as_rgb(
+        &self
+    ) -> (u8, u8, u8)
+    {
+        match self {
+            Self::Black         => BLACK_RGB,
+            Self::Red           => RED_RGB,
+            Self::Green         => GREEN_RGB,
+            Self::Yellow        => YELLOW_RGB,
+            Self::Blue          => BLUE_RGB,
+            Self::Magenta       => MAGENTA_RGB,
+            Self::Cyan          => CYAN_RGB,
+            Self::White         => WHITE_RGB,
+            Self::BrightBlack   => BRIGHT_BLACK_RGB,
+            Self::BrightRed     => BRIGHT_RED_RGB,
+            Self::BrightGreen   => BRIGHT_GREEN_RGB,
+            Self::BrightYellow  => BRIGHT_YELLOW_RGB,
+            Self::BrightBlue    => BRIGHT_BLUE_RGB,
+            Self::BrightMagenta => BRIGHT_MAGENTA_RGB,
+            Self::BrightCyan    => BRIGHT_CYAN_RGB,
+            Self::BrightWhite   => BRIGHT_WHITE_RGB,
+        }
+    }
+
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -148,6 +221,32 @@ impl Background {
         }
     }
 
+    /// Gets the color name, as found in the `Color` constants.
+
+    pub fn as_str(
+        &self
+    ) -> &'static str
+    {
+        match self {
+            Self::Black         => Color::BLACK,
+            Self::Red           => Color::RED,
+            Self::Green         => Color::GREEN,
+            Self::Yellow        => Color::YELLOW,
+            Self::Blue          => Color::BLUE,
+            Self::Magenta       => Color::MAGENTA,
+            Self::Cyan          => Color::CYAN,
+            Self::White         => Color::WHITE,
+            Self::BrightBlack   => Color::BRIGHT_BLACK,
+            Self::BrightRed     => Color::BRIGHT_RED,
+            Self::BrightGreen   => Color::BRIGHT_GREEN,
+            Self::BrightYellow  => Color::BRIGHT_YELLOW,
+            Self::BrightBlue    => Color::BRIGHT_BLUE,
+            Self::BrightMagenta => Color::BRIGHT_MAGENTA,
+            Self::BrightCyan    => Color::BRIGHT_CYAN,
+            Self::BrightWhite   => Color::BRIGHT_WHITE,
+        }
+    }
+
     pub fn as_ansi_code(
         &self
     ) -> &str
@@ -172,4 +271,95 @@ impl Background {
         }
     }
 
-}
\ No newline at end of file
+    /// Gets the RGB value of the color.
+
+    pub fn as_rgb(
+        &self
+    ) -> (u8, u8, u8)
+    {
+        match self {
+            Self::Black         => BLACK_RGB,
+            Self::Red           => RED_RGB,
+            Self::Green         => GREEN_RGB,
+            Self::Yellow        => YELLOW_RGB,
+            Self::Blue          => BLUE_RGB,
+            Self::Magenta       => MAGENTA_RGB,
+            Self::Cyan          => CYAN_RGB,
+            Self::White         => WHITE_RGB,
+            Self::BrightBlack   => BRIGHT_BLACK_RGB,
+            Self::BrightRed     => BRIGHT_RED_RGB,
+            Self::BrightGreen   => BRIGHT_GREEN_RGB,
+            Self::BrightYellow  => BRIGHT_YELLOW_RGB,
+            Self::BrightBlue    => BRIGHT_BLUE_RGB,
+            Self::BrightMagenta => BRIGHT_MAGENTA_RGB,
+            Self::BrightCyan    => BRIGHT_CYAN_RGB,
+            Self::BrightWhite   => BRIGHT_WHITE_RGB,
+        }
+    }
+
+}
+
+/// Colors are serialized by name, as found in the `Color` constants.
+
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_for_color {
+    ($color:ident) => {
+        impl serde::Serialize for $color {
+
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            {
+                serializer.serialize_str(self.as_str())
+            }
+
+        }
+
+        impl<'de> serde::Deserialize<'de> for $color {
+
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error>
+            {
+                let name: String = serde::Deserialize::deserialize(deserializer)?;
+                $color::from_str(&name)
+                    .ok_or_else(|| serde::de::Error::invalid_value(serde::de::Unexpected::Str(&name), &"a color name like \"red\" or \"bright blue\""))
+            }
+
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+impl_serde_for_color!(Foreground);
+#[cfg(feature = "serde")]
+impl_serde_for_color!(Background);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_colors_to_rgb_by_name() {
+        assert_eq!(Foreground::Black.as_rgb(), (0, 0, 0));
+        assert_eq!(Foreground::Red.as_rgb(), (205, 49, 49));
+        assert_eq!(Foreground::BrightWhite.as_rgb(), (255, 255, 255));
+        assert_eq!(Background::Blue.as_rgb(), (36, 114, 200));
+        assert_eq!(Background::BrightMagenta.as_rgb(), (214, 112, 214));
+    }
+
+    #[test]
+    fn foreground_and_background_share_the_palette() {
+        let pairs = [
+            (Foreground::Green, Background::Green),
+            (Foreground::Yellow, Background::Yellow),
+            (Foreground::BrightBlack, Background::BrightBlack),
+            (Foreground::BrightCyan, Background::BrightCyan),
+        ];
+        for (fg, bg) in pairs {
+            assert_eq!(fg.as_rgb(), bg.as_rgb());
+        }
+    }
+}
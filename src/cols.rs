@@ -0,0 +1,177 @@
+use crate::align::Align;
+use crate::cell::Cell;
+use crate::error::ErrorMessage;
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+
+use alloc::string::String;
+
+/// A column of a grid whose cells can be changed, as returned by `Grid::col_mut`,
+/// with methods formatting the whole column at once.
+///
+/// As with `Grid::col_iter_mut`, changes made through it are not recorded in the history.
+
+#[derive(Debug)]
+pub struct ColMut<'a> {
+    grid: &'a mut Grid,
+    col_index: usize,
+}
+
+impl ColMut<'_> {
+
+    /// Gets the index of the column in the grid.
+
+    pub fn index(
+        &self
+    ) -> usize
+    {
+        self.col_index
+    }
+
+    /// Gets an iterator over the cells of the column, from the first row to the last.
+
+    pub fn cells(
+        &self
+    ) -> impl DoubleEndedIterator<Item = &Cell> + ExactSizeIterator
+    {
+        self.grid.col_iter(self.col_index)
+    }
+
+    /// Gets an iterator over the cells of the column to change them.
+
+    pub fn cells_mut(
+        &mut self
+    ) -> impl DoubleEndedIterator<Item = &mut Cell> + ExactSizeIterator
+    {
+        self.grid.col_iter_mut(self.col_index)
+    }
+
+    /// Sets the alignment of every cell of the column, as `Cell::set_align` does,
+    /// e.g. `Align::Right` for numbers.
+
+    pub fn set_align(
+        &mut self,
+        new_align: Align,
+    )
+    {
+        self.cells_mut().for_each(|cell| cell.set_align(new_align));
+    }
+
+    /// Sets the foreground color of every cell of the column, as `Cell::set_color` does.
+
+    pub fn set_color(
+        &mut self,
+        new_color: &str,
+    )
+    {
+        self.cells_mut().for_each(|cell| cell.set_color(new_color));
+    }
+
+    /// Sets the background color of every cell of the column, as `Cell::set_highlight` does.
+
+    pub fn set_highlight(
+        &mut self,
+        new_color: &str,
+    )
+    {
+        self.cells_mut().for_each(|cell| cell.set_highlight(new_color));
+    }
+
+    /// Sets the font style(s) of every cell of the column, as `Cell::set_style` does.
+
+    pub fn set_style(
+        &mut self,
+        style: FontStyle,
+    )
+    {
+        self.cells_mut().for_each(|cell| cell.set_style(style));
+    }
+
+    /// Replaces every cell of the column with a copy of the given cell, data and formatting included.
+
+    pub fn fill(
+        &mut self,
+        cell: impl Into<Cell>,
+    )
+    {
+        let cell = cell.into();
+        self.cells_mut().for_each(|old| *old = cell.clone());
+    }
+
+    /// Sets the data of the cells of the column from the first row on, keeping their formatting.
+    ///
+    /// Cells beyond the given data are left unchanged, and extra data is ignored.
+
+    pub fn set_data(
+        &mut self,
+        new_data: impl IntoIterator<Item = impl Into<String>>,
+    )
+    {
+        self.cells_mut().zip(new_data).for_each(|(cell, data)| cell.set_data(data));
+    }
+
+}
+
+impl Grid {
+
+    /// Gets a column to change or format all of its cells at once,
+    /// e.g. `grid.col_mut(2).set_align(Align::Right)`.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// As with `col_iter_mut`, the changes are not recorded in the history.
+
+    pub fn col_mut(
+        &mut self,
+        col_index: usize,
+    ) -> ColMut<'_>
+    {
+        if col_index >= self.col_size() {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        ColMut { grid: self, col_index }
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Align, Color, FontStyle, Grid};
+
+    use alloc::vec::Vec;
+
+    #[test]
+    fn col_mut_formats_the_whole_column() {
+        let mut grid = Grid::from([["name", "qty"], ["apple", "3"], ["pear", "12"]]);
+        let mut qty = grid.col_mut(1);
+        assert_eq!(qty.index(), 1);
+        qty.set_align(Align::Right);
+        qty.set_color(Color::GREEN);
+        qty.set_highlight(Color::BLACK);
+        qty.set_style(FontStyle::Italic);
+        assert!(grid.col_iter(1).all(|cell| cell.get_align().contains(Align::Right)
+            && cell.get_color() == Some(Color::GREEN)
+            && cell.get_highlight() == Some(Color::BLACK)
+            && cell.get_style().contains(FontStyle::Italic)));
+        assert!(grid.col_iter(0).all(|cell| cell.get_color().is_none()));
+
+        grid.col_mut(1).set_data(["count", "4"]);
+        let qty: Vec<&str> = grid.col_iter(1).map(|cell| cell.get_data()).collect();
+        assert_eq!(qty, ["count", "4", "12"]);
+        assert!(grid.get_cell(0, 1).unwrap().get_align().contains(Align::Right));
+
+        grid.col_mut(0).fill("?");
+        assert!(grid.col_mut(0).cells().all(|cell| cell.get_data() == "?"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn col_mut_rejects_columns_out_of_bounds() {
+        let mut grid = Grid::from([["a"]]);
+        grid.col_mut(1);
+    }
+
+}
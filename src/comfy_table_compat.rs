@@ -0,0 +1,57 @@
+use crate::align::AlignH;
+use crate::cell::Cell;
+use crate::grid::Grid;
+
+use comfy_table::{CellAlignment, Table};
+
+impl From<&Grid> for Table {
+
+    /// Converts a grid into a comfy-table `Table`, with one table row per grid row.
+    ///
+    /// The data and horizontal alignment of every cell are carried over;
+    /// other formatting is left to the table's own styling.
+
+    fn from(
+        grid: &Grid,
+    ) -> Self
+    {
+        let mut table = Table::new();
+        for row_index in 0..grid.row_size() {
+            let row = grid.row_iter(row_index).map(|cell| {
+                let table_cell = comfy_table::Cell::new(cell.get_data());
+                match cell.h_align() {
+                    Some(AlignH::Left)   => table_cell.set_alignment(CellAlignment::Left),
+                    Some(AlignH::Center) => table_cell.set_alignment(CellAlignment::Center),
+                    Some(AlignH::Right)  => table_cell.set_alignment(CellAlignment::Right),
+                    None => table_cell,
+                }
+            });
+            table.add_row(row.collect::<Vec<comfy_table::Cell>>());
+        }
+        table
+    }
+
+}
+
+impl From<&Table> for Grid {
+
+    /// Converts a comfy-table `Table` into a grid, using `let grid: Grid = (&table).into();`.
+    ///
+    /// The header of the table, if any, becomes the first row, formatted in bold and centered,
+    /// followed by the rows of the table. Only the content of the cells is carried over.
+
+    fn from(
+        table: &Table,
+    ) -> Self
+    {
+        let header = table.header().map(|row| row.cell_iter().map(|cell| Cell::new(cell.content())).collect());
+        let rows = table.row_iter().map(|row| row.cell_iter().map(|cell| Cell::new(cell.content())).collect());
+
+        let mut grid = Grid::from(header.clone().into_iter().chain(rows).collect::<Vec<Vec<Cell>>>());
+        if header.is_some() {
+            grid.style_header_row();
+        }
+        grid
+    }
+
+}
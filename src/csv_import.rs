@@ -0,0 +1,165 @@
+use crate::grid::Grid;
+
+use std::io;
+use std::path::Path;
+
+/// How the first record of a CSV file is handled.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvHeader {
+    /// The first record is kept as the first row, like any other record.
+    #[default]
+    Keep,
+    /// The first record is dropped.
+    Skip,
+    /// The first record is kept as the first row, in bold and centered.
+    Bold,
+}
+
+/// Options used when reading a grid from CSV.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    header: CsvHeader,
+    delimiter: u8,
+    trim: bool,
+}
+
+impl Default for CsvOptions {
+
+    fn default() -> Self {
+        CsvOptions::new()
+    }
+
+}
+
+impl CsvOptions {
+
+    /// Creates the default CSV options.
+    ///
+    /// The first record is kept as a regular row, fields are separated by commas,
+    /// and whitespace around fields is kept.
+
+    pub fn new() -> Self {
+        CsvOptions {
+            header: CsvHeader::Keep,
+            delimiter: b',',
+            trim: false,
+        }
+    }
+
+    /// Gets how the first record is handled.
+
+    pub fn header(
+        &self
+    ) -> CsvHeader
+    {
+        self.header
+    }
+
+    /// Sets how the first record is handled.
+
+    pub fn set_header(
+        &mut self,
+        new_header: CsvHeader,
+    )
+    {
+        self.header = new_header;
+    }
+
+    /// Gets the field delimiter.
+
+    pub fn delimiter(
+        &self
+    ) -> u8
+    {
+        self.delimiter
+    }
+
+    /// Sets the field delimiter, e.g. `b';'` or `b'\t'`.
+
+    pub fn set_delimiter(
+        &mut self,
+        new_delimiter: u8,
+    )
+    {
+        self.delimiter = new_delimiter;
+    }
+
+    /// Gets whether whitespace around fields is removed.
+
+    pub fn trim(
+        &self
+    ) -> bool
+    {
+        self.trim
+    }
+
+    /// Sets whether whitespace around fields is removed.
+
+    pub fn set_trim(
+        &mut self,
+        new_trim: bool,
+    )
+    {
+        self.trim = new_trim;
+    }
+
+}
+
+impl Grid {
+
+    /// Reads a grid from CSV data.
+    ///
+    /// Every record becomes a row and every field a cell.
+    /// Records may have different lengths; missing cells are filled with empty cells.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data cannot be read or is not valid UTF-8 CSV
+
+    pub fn from_csv_reader(
+        reader: impl io::Read,
+        options: &CsvOptions,
+    ) -> csv::Result<Grid>
+    {
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .delimiter(options.delimiter())
+            .trim(if options.trim() { csv::Trim::All } else { csv::Trim::None })
+            .from_reader(reader);
+
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        for record in csv_reader.records() {
+            rows.push(record?.iter().map(String::from).collect());
+        }
+        if options.header() == CsvHeader::Skip && !rows.is_empty() {
+            rows.remove(0);
+        }
+
+        let mut grid = Grid::from(rows);
+        if options.header() == CsvHeader::Bold {
+            grid.style_header_row();
+        }
+        Ok(grid)
+    }
+
+    /// Reads a grid from a CSV file.
+    ///
+    /// See `Grid::from_csv_reader` for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or read, or is not valid UTF-8 CSV
+
+    pub fn from_csv_path(
+        path: impl AsRef<Path>,
+        options: &CsvOptions,
+    ) -> csv::Result<Grid>
+    {
+        let file = std::fs::File::open(path)?;
+        Grid::from_csv_reader(file, options)
+    }
+
+}
@@ -0,0 +1,82 @@
+use crate::grid::Grid;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+impl Grid {
+
+    /// Create a new grid from delimiter-separated text, such as TSV or `;`- and `|`-separated output.
+    ///
+    /// Every line becomes a row and every field a cell; a trailing empty line is ignored.
+    /// Lines may have different numbers of fields, and missing cells are filled with empty cells.
+    ///
+    /// Fields starting with `"` are quoted: they may contain the delimiter and line breaks,
+    /// and `""` inside them stands for a single quote.
+    /// Outside quotes, a backslash escapes the delimiter, a quote, or another backslash (`\|`, `\"`, `\\`);
+    /// any other backslash, and every backslash in a quoted field, is kept as is,
+    /// so paths like `C:\temp` are not altered.
+    /// Both `\n` and `\r\n` line endings are accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to parse
+    /// * `delimiter` - The character separating fields, e.g. `'\t'`, `';'`, or `'|'`
+
+    pub fn from_delimited(
+        text: &str,
+        delimiter: char,
+    ) -> Self
+    {
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        let mut row: Vec<String> = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = text.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' if !quoted && matches!(chars.peek(), Some(&next) if next == delimiter || next == '"' || next == '\\') => {
+                    field.extend(chars.next());
+                },
+                '"' if quoted => {
+                    if chars.peek() == Some(&'"') {
+                        chars.next();
+                        field.push('"');
+                    } else {
+                        quoted = false;
+                    }
+                },
+                '"' if field.is_empty() => quoted = true,
+                _ if quoted => field.push(c),
+                _ if c == delimiter => row.push(core::mem::take(&mut field)),
+                '\r' if chars.peek() == Some(&'\n') => {},
+                '\n' => {
+                    row.push(core::mem::take(&mut field));
+                    rows.push(core::mem::take(&mut row));
+                },
+                _ => field.push(c),
+            }
+        }
+        // The last line may not end with a line break
+        if !field.is_empty() || !row.is_empty() {
+            row.push(field);
+            rows.push(row);
+        }
+
+        Grid::from(rows)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::Grid;
+
+    #[test]
+    fn backslash_escapes_apply_outside_quotes_only() {
+        let grid = Grid::from_delimited("a\\|b|\"C:\\\\share\"|\"x\\\"\"\"\n", '|');
+        assert_eq!(grid.to_records(), [["a|b", "C:\\\\share", "x\\\""]]);
+    }
+
+}
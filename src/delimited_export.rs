@@ -0,0 +1,334 @@
+use crate::export::Exporter;
+use crate::grid::Grid;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// When fields are quoted in delimited exports.
+///
+/// Quoted fields are wrapped in `"` and every `"` inside them is doubled, as in RFC 4180.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuotePolicy {
+    /// Every field is quoted, including empty ones.
+    Always,
+    /// Only fields containing the delimiter, a quote, a backslash, or a line break are quoted.
+    #[default]
+    Necessary,
+    /// No field is quoted, for readers without quoting support.
+    /// Fields are written as is, so the delimiter and line breaks must not appear in the data
+    /// unless the newline policy removes them.
+    Never,
+}
+
+/// How line breaks inside cells are written in delimited exports.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NewlinePolicy {
+    /// Line breaks are kept inside the field, which is then quoted unless quoting is disabled.
+    #[default]
+    Keep,
+    /// Line breaks are replaced with a literal `\n` (and carriage returns with `\r`), keeping every
+    /// record on a single line. Backslashes are doubled, so the escapes can be told apart from the data.
+    /// `Delimited::parse` decodes the escapes again.
+    Escape,
+    /// Line breaks (`\n` or `\r\n`) are replaced with spaces, so words on separate lines stay apart.
+    Strip,
+}
+
+impl Grid {
+
+    /// Export the grid as CSV, with comma-separated fields quoted when necessary.
+    ///
+    /// Every row is written as one record ending with `\n`. Only the cell data is exported.
+    /// Use the `Delimited` exporter to choose the quoting and line break handling.
+
+    pub fn to_csv(
+        &self
+    ) -> String
+    {
+        self.export(&Delimited::csv())
+    }
+
+    /// Export the grid as TSV, with tab-separated fields quoted when necessary.
+    ///
+    /// This works like `to_csv`, with tabs as the delimiter.
+
+    pub fn to_tsv(
+        &self
+    ) -> String
+    {
+        self.export(&Delimited::tsv())
+    }
+
+}
+
+/// Exporter for delimiter-separated text, as produced by `Grid::to_csv` and `Grid::to_tsv`.
+///
+/// Use `Delimited::parse` with the same settings to read the output back, which restores the
+/// cell data unless quoting is disabled or line breaks are stripped.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Delimited {
+    delimiter: char,
+    quote_policy: QuotePolicy,
+    newline_policy: NewlinePolicy,
+}
+
+impl Default for Delimited {
+
+    fn default() -> Self {
+        Delimited::csv()
+    }
+
+}
+
+impl Delimited {
+
+    /// Creates an exporter separating fields with the delimiter, e.g. `';'` or `'|'`,
+    /// quoting fields when necessary and keeping line breaks.
+
+    pub fn new(
+        delimiter: char,
+    ) -> Self
+    {
+        Delimited {
+            delimiter,
+            quote_policy: QuotePolicy::Necessary,
+            newline_policy: NewlinePolicy::Keep,
+        }
+    }
+
+    /// Creates an exporter for CSV, as used by `Grid::to_csv`.
+
+    pub fn csv() -> Self {
+        Delimited::new(',')
+    }
+
+    /// Creates an exporter for TSV, as used by `Grid::to_tsv`.
+
+    pub fn tsv() -> Self {
+        Delimited::new('\t')
+    }
+
+    /// Gets the field delimiter.
+
+    pub fn delimiter(
+        &self
+    ) -> char
+    {
+        self.delimiter
+    }
+
+    /// Sets the field delimiter.
+
+    pub fn set_delimiter(
+        &mut self,
+        new_delimiter: char,
+    )
+    {
+        self.delimiter = new_delimiter;
+    }
+
+    /// Gets when fields are quoted.
+
+    pub fn quote_policy(
+        &self
+    ) -> QuotePolicy
+    {
+        self.quote_policy
+    }
+
+    /// Sets when fields are quoted.
+
+    pub fn set_quote_policy(
+        &mut self,
+        new_quote_policy: QuotePolicy,
+    )
+    {
+        self.quote_policy = new_quote_policy;
+    }
+
+    /// Gets how line breaks inside cells are written.
+
+    pub fn newline_policy(
+        &self
+    ) -> NewlinePolicy
+    {
+        self.newline_policy
+    }
+
+    /// Sets how line breaks inside cells are written.
+
+    pub fn set_newline_policy(
+        &mut self,
+        new_newline_policy: NewlinePolicy,
+    )
+    {
+        self.newline_policy = new_newline_policy;
+    }
+
+    /// Reads text written by this exporter back into a grid.
+    ///
+    /// The fields are parsed like `Grid::from_delimited` with the delimiter of the exporter,
+    /// and escaped line breaks are decoded when the newline policy is `Escape`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The exported text
+
+    pub fn parse(
+        &self,
+        text: &str,
+    ) -> Grid
+    {
+        let grid = Grid::from_delimited(text, self.delimiter);
+        if self.newline_policy != NewlinePolicy::Escape {
+            return grid;
+        }
+
+        let rows: Vec<Vec<String>> = grid.to_records()
+            .iter()
+            .map(|record| record.iter().map(|data| unescape_newlines(data)).collect())
+            .collect();
+        Grid::from(rows)
+    }
+
+    /// Writes the data of a cell as a field, applying the newline and quote policies.
+
+    fn write_field(
+        &self,
+        output: &mut String,
+        data: &str,
+    )
+    {
+        let data = match self.newline_policy {
+            NewlinePolicy::Keep => Cow::Borrowed(data),
+            NewlinePolicy::Escape => Cow::Owned(escape_newlines(data)),
+            NewlinePolicy::Strip => Cow::Owned(data.split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line)).collect::<Vec<_>>().join(" ")),
+        };
+
+        let quoted = match self.quote_policy {
+            QuotePolicy::Always => true,
+            QuotePolicy::Necessary => data.contains([self.delimiter, '"', '\\', '\n', '\r']),
+            QuotePolicy::Never => false,
+        };
+        if quoted {
+            output.push('"');
+            output.push_str(&data.replace('"', "\"\""));
+            output.push('"');
+        } else {
+            output.push_str(&data);
+        }
+    }
+
+}
+
+/// Escapes backslashes, then line breaks and carriage returns, as for `NewlinePolicy::Escape`.
+
+fn escape_newlines(
+    data: &str
+) -> String
+{
+    let mut escaped = String::with_capacity(data.len());
+    for c in data.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Decodes the escapes written by `escape_newlines`; any other backslash is kept as is.
+
+fn unescape_newlines(
+    data: &str
+) -> String
+{
+    let mut unescaped = String::with_capacity(data.len());
+    let mut chars = data.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('\\', Some('\\')) => { chars.next(); unescaped.push('\\'); },
+            ('\\', Some('n'))  => { chars.next(); unescaped.push('\n'); },
+            ('\\', Some('r'))  => { chars.next(); unescaped.push('\r'); },
+            _                  => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+impl Exporter for Delimited {
+
+    type Output = String;
+
+    fn export(
+        &self,
+        grid: &Grid,
+    ) -> String
+    {
+        let mut output = String::new();
+
+        for row_index in 0..grid.row_size() {
+            for (col_index, cell) in grid.row_iter(row_index).enumerate() {
+                if col_index > 0 {
+                    output.push(self.delimiter);
+                }
+                self.write_field(&mut output, cell.get_data());
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{Delimited, NewlinePolicy, QuotePolicy};
+    use crate::{Exporter, Grid};
+
+    use alloc::string::String;
+
+    fn export(
+        newline_policy: NewlinePolicy,
+        data: &str,
+    ) -> String
+    {
+        let mut delimited = Delimited::csv();
+        delimited.set_newline_policy(newline_policy);
+        delimited.export(&Grid::from([[data]]))
+    }
+
+    #[test]
+    fn escape_keeps_every_line_break_and_backslash() {
+        assert_eq!(export(NewlinePolicy::Escape, "a\\nb\n"), "\"a\\\\nb\\n\"\n");
+        assert_eq!(export(NewlinePolicy::Escape, "a\r\nb"), "\"a\\r\\nb\"\n");
+    }
+
+    #[test]
+    fn strip_treats_crlf_as_one_line_break() {
+        assert_eq!(export(NewlinePolicy::Strip, "a\r\nb\n"), "a b \n");
+    }
+
+    #[test]
+    fn parse_reads_back_backslashes_and_line_breaks() {
+        let grid = Grid::from([["C:\\share", "x\\,y", "a\\nb\r\nc", "\"q\""]]);
+        for quote_policy in [QuotePolicy::Always, QuotePolicy::Necessary] {
+            for newline_policy in [NewlinePolicy::Keep, NewlinePolicy::Escape] {
+                let mut delimited = Delimited::csv();
+                delimited.set_quote_policy(quote_policy);
+                delimited.set_newline_policy(newline_policy);
+                assert_eq!(delimited.parse(&delimited.export(&grid)).to_records(), grid.to_records());
+            }
+        }
+        assert_eq!(Grid::from_delimited(&grid.to_csv(), ',').to_records(), grid.to_records());
+    }
+
+}
@@ -0,0 +1,438 @@
+use crate::cell::Cell;
+use crate::color::Color;
+use crate::grid::Grid;
+use crate::options::RenderOptions;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Error, Formatter};
+use core::ops::{Index, IndexMut, Range};
+
+/// The differences between two grids, as computed by `Grid::diff`.
+///
+/// Rows are matched by the data of their cells, ignoring formatting, so that rows inserted
+/// or removed in the middle of a grid do not make every row below them differ.
+/// A removed row directly followed by an added row is reported as a single changed row,
+/// listing the columns whose data differs.
+
+#[derive(Debug, Clone)]
+pub struct GridDiff<'a> {
+    old: &'a Grid,
+    new: &'a Grid,
+    rows: Vec<RowDiff>,
+}
+
+/// How a row differs between two grids, with its index in either or both grids.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RowDiff {
+    /// The row holds the same data in both grids.
+    Unchanged { old: usize, new: usize },
+    /// The row holds different data in some columns, in ascending order.
+    Changed { old: usize, new: usize, cols: Vec<usize> },
+    /// The row is only in the new grid.
+    Added { new: usize },
+    /// The row is only in the old grid.
+    Removed { old: usize },
+}
+
+impl Grid {
+
+    /// Compares the grid, as the old state, with another grid, as the new state,
+    /// e.g. to compare two config dumps or two benchmark runs.
+    ///
+    /// Only the data of the cells is compared. Grids with different numbers of columns
+    /// are compared as if the narrower one had empty cells on the right.
+
+    pub fn diff<'a>(
+        &'a self,
+        new: &'a Grid,
+    ) -> GridDiff<'a>
+    {
+        let old = self;
+        let col_size = old.col_size().max(new.col_size());
+        let same = |old_row, new_row| (0..col_size).all(|col_index| cell_data(old, old_row, col_index) == cell_data(new, new_row, col_index));
+        let changed_cols = |old_row, new_row| -> Vec<usize> {
+            (0..col_size).filter(|&col_index| cell_data(old, old_row, col_index) != cell_data(new, new_row, col_index)).collect()
+        };
+
+        let (old_rows, new_rows) = (old.row_size(), new.row_size());
+        let mut matches = Vec::new();
+        let mut diagonals = (Diagonals::new(old_rows + new_rows), Diagonals::new(old_rows + new_rows));
+        align_rows(0..old_rows, 0..new_rows, &same, &mut diagonals, &mut matches);
+
+        let mut rows = Vec::new();
+        let mut removed = Vec::new();
+        let mut added = Vec::new();
+        let (mut old_row, mut new_row) = (0, 0);
+        for (old_match, new_match) in matches.into_iter().chain([(old_rows, new_rows)]) {
+            removed.extend(old_row..old_match);
+            added.extend(new_row..new_match);
+            if old_match < old_rows {
+                push_hunk(&mut rows, &mut removed, &mut added, &changed_cols);
+                rows.push(RowDiff::Unchanged { old: old_match, new: new_match });
+            }
+            (old_row, new_row) = (old_match + 1, new_match + 1);
+        }
+        push_hunk(&mut rows, &mut removed, &mut added, &changed_cols);
+
+        GridDiff { old, new, rows }
+    }
+
+}
+
+/// The furthest row reached in the old grid on each diagonal of the edit graph, for Myers' algorithm,
+/// indexed by the diagonal `k = old - new` from `-max` to `max`.
+
+struct Diagonals {
+    offset: isize,
+    rows: Vec<usize>,
+}
+
+impl Diagonals {
+
+    /// Creates the diagonals for aligning up to the given total number of rows.
+
+    fn new(
+        row_count: usize,
+    ) -> Self
+    {
+        let max = row_count / 2 + 2;
+        Diagonals { offset: max as isize, rows: vec![0; 2 * max + 1] }
+    }
+
+}
+
+impl Index<isize> for Diagonals {
+
+    type Output = usize;
+
+    fn index(
+        &self,
+        k: isize,
+    ) -> &usize
+    {
+        &self.rows[(k + self.offset) as usize]
+    }
+
+}
+
+impl IndexMut<isize> for Diagonals {
+
+    fn index_mut(
+        &mut self,
+        k: isize,
+    ) -> &mut usize
+    {
+        &mut self.rows[(k + self.offset) as usize]
+    }
+
+}
+
+/// Appends the pairs of rows matched between two ranges of rows, in order, along a shortest edit script.
+///
+/// This is Myers' divide and conquer algorithm: the middle snake of the script splits the ranges in two,
+/// which are aligned in turn. It takes linear space, and time proportional to the number of rows
+/// times the number of differences, so large grids with few changes are compared quickly.
+
+fn align_rows(
+    mut old: Range<usize>,
+    mut new: Range<usize>,
+    same: &impl Fn(usize, usize) -> bool,
+    diagonals: &mut (Diagonals, Diagonals),
+    matches: &mut Vec<(usize, usize)>,
+)
+{
+    while !old.is_empty() && !new.is_empty() && same(old.start, new.start) {
+        matches.push((old.start, new.start));
+        (old.start, new.start) = (old.start + 1, new.start + 1);
+    }
+    let mut suffix = 0;
+    while suffix < old.len().min(new.len()) && same(old.end - 1 - suffix, new.end - 1 - suffix) {
+        suffix += 1;
+    }
+    (old.end, new.end) = (old.end - suffix, new.end - suffix);
+
+    if !old.is_empty() && !new.is_empty() {
+        let (old_split, new_split) = middle_snake(old.clone(), new.clone(), same, diagonals);
+        align_rows(old.start..old_split, new.start..new_split, same, diagonals, matches);
+        align_rows(old_split..old.end, new_split..new.end, same, diagonals, matches);
+    }
+    matches.extend((0..suffix).map(|offset| (old.end + offset, new.end + offset)));
+}
+
+/// Finds the start of the middle snake of a shortest edit script between two non-empty ranges of rows
+/// without a common first or last row, by searching forward from the start and backward from the end
+/// until the paths overlap.
+
+fn middle_snake(
+    old: Range<usize>,
+    new: Range<usize>,
+    same: &impl Fn(usize, usize) -> bool,
+    (forward, backward): &mut (Diagonals, Diagonals),
+) -> (usize, usize)
+{
+    let (n, m) = (old.len(), new.len());
+    let delta = n as isize - m as isize;
+    let odd = delta & 1 == 1;
+    forward[1] = 0;
+    backward[1] = 0;
+
+    for d in 0..((n + m).div_ceil(2) + 1) as isize {
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x = if k == -d || (k != d && forward[k - 1] < forward[k + 1]) {
+                forward[k + 1]
+            } else {
+                forward[k - 1] + 1
+            };
+            let mut y = (x as isize - k) as usize;
+            let (x0, y0) = (x, y);
+            while x < n && y < m && same(old.start + x, new.start + y) {
+                (x, y) = (x + 1, y + 1);
+            }
+            forward[k] = x;
+            if odd && (k - delta).abs() < d && forward[k] + backward[-(k - delta)] >= n {
+                return (old.start + x0, new.start + y0);
+            }
+        }
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x = if k == -d || (k != d && backward[k - 1] < backward[k + 1]) {
+                backward[k + 1]
+            } else {
+                backward[k - 1] + 1
+            };
+            let mut y = (x as isize - k) as usize;
+            while x < n && y < m && same(old.end - 1 - x, new.end - 1 - y) {
+                (x, y) = (x + 1, y + 1);
+            }
+            backward[k] = x;
+            if !odd && (k - delta).abs() <= d && backward[k] + forward[-(k - delta)] >= n {
+                return (old.end - x, new.end - y);
+            }
+        }
+    }
+    unreachable!("the forward and backward paths of an edit script always overlap")
+}
+
+/// Get the data of a cell, or an empty text if the indices are out of bounds.
+
+fn cell_data(
+    grid: &Grid,
+    row_index: usize,
+    col_index: usize,
+) -> &str
+{
+    grid.get_cell(row_index, col_index).map_or("", Cell::get_data)
+}
+
+/// Moves the rows removed and added between two unchanged rows into the differences,
+/// pairing them up in order as changed rows.
+
+fn push_hunk(
+    rows: &mut Vec<RowDiff>,
+    removed: &mut Vec<usize>,
+    added: &mut Vec<usize>,
+    changed_cols: &impl Fn(usize, usize) -> Vec<usize>,
+)
+{
+    let paired = removed.len().min(added.len());
+    rows.extend(removed.iter().zip(added.iter()).map(|(&old, &new)| RowDiff::Changed { old, new, cols: changed_cols(old, new) }));
+    rows.extend(removed[paired..].iter().map(|&old| RowDiff::Removed { old }));
+    rows.extend(added[paired..].iter().map(|&new| RowDiff::Added { new }));
+    removed.clear();
+    added.clear();
+}
+
+impl GridDiff<'_> {
+
+    /// Get the differences of every row, in the order of the rows of both grids.
+
+    pub fn rows(
+        &self
+    ) -> &[RowDiff]
+    {
+        &self.rows
+    }
+
+    /// Returns true if any row was changed, added, or removed.
+
+    pub fn has_changes(
+        &self
+    ) -> bool
+    {
+        self.rows.iter().any(|row| !matches!(row, RowDiff::Unchanged { .. }))
+    }
+
+    /// Lays out the differences as a grid, with a marker column on the left.
+    ///
+    /// Added rows are marked with `+` and shown in green, removed rows with `-` in red,
+    /// and changed rows with `~`, with each changed cell shown as `old -> new` in yellow.
+    /// Unchanged rows keep the cells of the new grid, with their formatting.
+
+    pub fn to_grid(
+        &self
+    ) -> Grid
+    {
+        let col_size = self.old.col_size().max(self.new.col_size());
+        let cells = |grid: &Grid, row_index| -> Vec<Cell> {
+            let mut cells: Vec<Cell> = grid.row_iter(row_index).cloned().collect();
+            cells.resize(col_size, Cell::default());
+            cells
+        };
+        let colored = |mut cell: Cell, color| {
+            cell.set_color(color);
+            cell
+        };
+
+        let mut grid = Grid::with_capacity(self.rows.len(), col_size + 1);
+        for row in &self.rows {
+            let (marker, row_cells) = match row {
+                RowDiff::Unchanged { new, .. } => (Cell::from_static(" "), cells(self.new, *new)),
+                RowDiff::Added { new } => (
+                    colored(Cell::from_static("+"), Color::GREEN),
+                    cells(self.new, *new).into_iter().map(|cell| colored(cell, Color::GREEN)).collect(),
+                ),
+                RowDiff::Removed { old } => (
+                    colored(Cell::from_static("-"), Color::RED),
+                    cells(self.old, *old).into_iter().map(|cell| colored(cell, Color::RED)).collect(),
+                ),
+                RowDiff::Changed { old, new, cols } => {
+                    let mut row_cells = cells(self.new, *new);
+                    for &col_index in cols {
+                        let text = format!("{} -> {}", cell_data(self.old, *old, col_index), cell_data(self.new, *new, col_index));
+                        row_cells[col_index] = colored(Cell::new(text), Color::YELLOW);
+                    }
+                    (colored(Cell::from_static("~"), Color::YELLOW), row_cells)
+                },
+            };
+            grid.push_row(core::iter::once(marker).chain(row_cells));
+        }
+        grid
+    }
+
+    /// Renders the differences using the given render options, as laid out by `to_grid`.
+    ///
+    /// The `Display` implementation is a shortcut for rendering with the default options.
+
+    pub fn render_with(
+        &self,
+        options: &RenderOptions,
+    ) -> String
+    {
+        self.to_grid().render_with(options)
+    }
+
+}
+
+impl Display for GridDiff<'_> {
+
+    fn fmt(
+        &self,
+        f: &mut Formatter,
+    ) -> Result<(), Error>
+    {
+        Display::fmt(&self.to_grid(), f)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::RowDiff;
+    use crate::Grid;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn grid(
+        rows: &[&str],
+    ) -> Grid
+    {
+        Grid::from(rows.iter().map(|row| row.split(',')))
+    }
+
+    /// Gets the length of the longest common subsequence of two lists of rows, with the full table.
+
+    fn lcs_len(
+        old: &[&str],
+        new: &[&str],
+    ) -> usize
+    {
+        let mut lengths = vec![vec![0; new.len() + 1]; old.len() + 1];
+        for i in (0..old.len()).rev() {
+            for j in (0..new.len()).rev() {
+                lengths[i][j] = if old[i] == new[j] { lengths[i + 1][j + 1] + 1 } else { lengths[i + 1][j].max(lengths[i][j + 1]) };
+            }
+        }
+        lengths[0][0]
+    }
+
+    #[test]
+    fn inserted_and_removed_rows_leave_the_others_unchanged() {
+        let old = grid(&["a,1", "b,2", "c,3", "d,4"]);
+        let new = grid(&["a,1", "x,9", "b,2", "d,4"]);
+        assert_eq!(old.diff(&new).rows(), [
+            RowDiff::Unchanged { old: 0, new: 0 },
+            RowDiff::Added { new: 1 },
+            RowDiff::Unchanged { old: 1, new: 2 },
+            RowDiff::Removed { old: 2 },
+            RowDiff::Unchanged { old: 3, new: 3 },
+        ]);
+    }
+
+    #[test]
+    fn replaced_rows_are_changed_in_the_differing_columns() {
+        let old = grid(&["a,1,x", "b,2,y"]);
+        let new = grid(&["a,1,x", "b,3,z"]);
+        let diff = old.diff(&new);
+        assert!(diff.has_changes());
+        assert_eq!(diff.rows()[1], RowDiff::Changed { old: 1, new: 1, cols: vec![1, 2] });
+        assert!(!old.diff(&old).has_changes());
+    }
+
+    #[test]
+    fn alignment_keeps_a_longest_common_subsequence() {
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut next = |bound: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % bound) as usize
+        };
+        const ROWS: [&str; 4] = ["a", "b", "c", "d"];
+        for _ in 0..500 {
+            let old: Vec<&str> = (0..next(12)).map(|_| ROWS[next(4)]).collect();
+            let new: Vec<&str> = (0..next(12)).map(|_| ROWS[next(4)]).collect();
+            let (old_grid, new_grid) = (grid(&old), grid(&new));
+            let diff = old_grid.diff(&new_grid);
+
+            let (mut old_rows, mut new_rows) = (Vec::new(), Vec::new());
+            let mut unchanged = 0;
+            for row in diff.rows() {
+                match *row {
+                    RowDiff::Unchanged { old: old_row, new: new_row } => {
+                        assert_eq!(old[old_row], new[new_row]);
+                        unchanged += 1;
+                        old_rows.push(old_row);
+                        new_rows.push(new_row);
+                    },
+                    RowDiff::Changed { old: old_row, new: new_row, .. } => {
+                        old_rows.push(old_row);
+                        new_rows.push(new_row);
+                    },
+                    RowDiff::Removed { old: old_row } => old_rows.push(old_row),
+                    RowDiff::Added { new: new_row } => new_rows.push(new_row),
+                }
+            }
+            old_rows.sort_unstable();
+            new_rows.sort_unstable();
+            assert_eq!(old_rows, (0..old.len()).collect::<Vec<_>>(), "{old:?} {new:?}");
+            assert_eq!(new_rows, (0..new.len()).collect::<Vec<_>>(), "{old:?} {new:?}");
+            assert_eq!(unchanged, lcs_len(&old, &new), "{old:?} {new:?}");
+        }
+    }
+
+}
@@ -0,0 +1,159 @@
+use crate::grid::Grid;
+use crate::memory::btree_bytes;
+
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+/// The rows of a grid that were mutated since they were last taken with `Grid::take_dirty_rows`.
+///
+/// Single rows are kept in a set, while mutations reaching every row from some index on,
+/// such as inserting a row or setting a column, only keep the lowest such index.
+/// A new grid has all of its rows dirty, since none of them was taken yet.
+/// Dirty rows never affect equality, so two grids with the same cells are always equal.
+
+#[derive(Debug, Clone)]
+pub(crate) struct DirtyRows {
+    rows: BTreeSet<usize>,
+    from: Option<usize>,
+}
+
+impl Default for DirtyRows {
+
+    fn default() -> Self {
+        DirtyRows {
+            rows: BTreeSet::new(),
+            from: Some(0),
+        }
+    }
+
+}
+
+impl DirtyRows {
+
+    /// Gets the number of heap bytes used to keep the dirty rows, approximately.
+
+    pub(crate) fn heap_bytes(
+        &self
+    ) -> usize
+    {
+        btree_bytes::<usize>(self.rows.len())
+    }
+
+    /// Marks a single row as dirty.
+
+    pub(crate) fn mark(
+        &mut self,
+        row_index: usize,
+    )
+    {
+        if self.from.is_none_or(|from| row_index < from) {
+            self.rows.insert(row_index);
+        }
+    }
+
+    /// Marks every row from the given index on as dirty.
+
+    pub(crate) fn mark_from(
+        &mut self,
+        row_index: usize,
+    )
+    {
+        let from = self.from.map_or(row_index, |from| from.min(row_index));
+        self.rows.retain(|&row_index| row_index < from);
+        self.from = Some(from);
+    }
+
+    /// Takes the dirty rows that exist in a grid of the given number of rows, in ascending order,
+    /// and marks every row as clean.
+
+    fn take(
+        &mut self,
+        row_size: usize,
+    ) -> Vec<usize>
+    {
+        let from = self.from.take().unwrap_or(row_size).min(row_size);
+        let rows = core::mem::take(&mut self.rows);
+        rows.into_iter()
+            .filter(|&row_index| row_index < from)
+            .chain(from..row_size)
+            .collect()
+    }
+
+}
+
+impl PartialEq for DirtyRows {
+
+    fn eq(
+        &self,
+        _other: &Self,
+    ) -> bool
+    {
+        true
+    }
+
+}
+
+impl Eq for DirtyRows {}
+
+impl Grid {
+
+    /// Takes the indices of the rows mutated since the last call, in ascending order.
+    ///
+    /// Any method giving mutable access to cells marks the rows it can reach, so a live UI
+    /// can re-render only those rows on each frame, e.g. with `Grid::render_row`,
+    /// as long as the column widths stay the same. Inserting a row marks it and every row
+    /// below it, since they all moved down, and growing the grid marks the new rows.
+    /// Rows removed by shrinking the grid are not reported.
+    ///
+    /// The first call on a new grid returns every row, since none of them was taken yet.
+
+    pub fn take_dirty_rows(
+        &mut self
+    ) -> Vec<usize>
+    {
+        let row_size = self.row_size();
+        self.dirty_rows_mut().take(row_size)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    extern crate std;
+
+    use crate::Grid;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    fn clean_grid() -> Grid {
+        let mut grid = Grid::new(3, 2);
+        grid.take_dirty_rows();
+        grid
+    }
+
+    #[test]
+    fn out_of_bounds_access_marks_nothing() {
+        let mut grid = clean_grid();
+        assert!(grid.try_set_cell(1, 5, "x").is_err());
+        assert!(grid.get_cell_mut(1, 5).is_none());
+        assert!(grid.get_cell_mut(5, 0).is_none());
+        assert_eq!(grid.row_iter_mut(5).count(), 0);
+        assert_eq!(grid.col_iter_mut(5).count(), 0);
+        assert!(catch_unwind(AssertUnwindSafe(|| { grid.set_cell(1, 5, "x"); })).is_err());
+        assert!(catch_unwind(AssertUnwindSafe(|| grid.insert_col(5, ["x"]))).is_err());
+        assert!(catch_unwind(AssertUnwindSafe(|| grid.insert_row(5, ["x"]))).is_err());
+        assert_eq!(grid.take_dirty_rows(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn in_bounds_access_marks_the_row() {
+        let mut grid = clean_grid();
+        grid.set_cell(1, 1, "x");
+        assert!(grid.get_cell_mut(2, 0).is_some());
+        assert_eq!(grid.take_dirty_rows(), vec![1, 2]);
+    }
+
+}
@@ -0,0 +1,119 @@
+use crate::border::BorderStyle;
+use crate::options::{ColorMode, RenderOptions};
+
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Environment variable selecting a preset of render options: `default`, `compact`, or `plain`.
+pub const STYLE_ENV_VAR  : &str = "FLATGRID_STYLE";
+/// Environment variable selecting the border style: `unicode`, `ascii`, or `none`.
+pub const BORDER_ENV_VAR : &str = "FLATGRID_BORDER";
+/// Environment variable selecting the color mode: `always` or `never`.
+pub const COLOR_ENV_VAR  : &str = "FLATGRID_COLOR";
+
+/// Whether the default render options honor the environment variables.
+static ENV_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// The built-in default render options with the environment variables applied,
+/// read the first time default options are created.
+static ENV_OPTIONS: OnceLock<RenderOptions> = OnceLock::new();
+
+impl RenderOptions {
+
+    /// Sets whether the default render options honor the `FLATGRID_*` environment variables.
+    ///
+    /// Enabled by default, so end users can pick their preferred style once for every tool built
+    /// on flatgrid. Applications whose output must not change, e.g. because it is parsed by
+    /// other programs, can disable it once at startup.
+    /// This affects options created afterwards, including the ones used by `Display`.
+
+    pub fn set_env_enabled(
+        enabled: bool,
+    )
+    {
+        ENV_ENABLED.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Gets whether the default render options honor the `FLATGRID_*` environment variables.
+
+    pub fn env_enabled() -> bool {
+        ENV_ENABLED.load(Ordering::Relaxed)
+    }
+
+    /// Gets the built-in default render options with the `FLATGRID_*` environment variables applied.
+    ///
+    /// The variables are read once, the first time this is called, so creating default options
+    /// for every render does not query the environment, and a run renders the same way throughout.
+
+    pub(crate) fn env_defaults() -> &'static RenderOptions {
+        ENV_OPTIONS.get_or_init(|| {
+            let mut options = RenderOptions::without_env();
+            options.apply_env();
+            options
+        })
+    }
+
+    /// Applies the `FLATGRID_*` environment variables to the options, whether or not they are
+    /// enabled for the default options.
+    ///
+    /// Unlike the default options, which read the variables once, this reads their current values.
+    ///
+    /// `FLATGRID_STYLE` selects a preset and is applied first:
+    /// `default` keeps the options, `compact` removes the separators between rows,
+    /// and `plain` removes borders and colors.
+    /// `FLATGRID_BORDER` (`unicode`, `ascii`, or `none`) and `FLATGRID_COLOR`
+    /// (`always` or `never`) then override the border style and color mode.
+    /// Values are case-insensitive, and unset or unrecognized variables are ignored.
+
+    pub fn apply_env(
+        &mut self
+    )
+    {
+        match env_value(STYLE_ENV_VAR).as_deref() {
+            Some("compact") => self.set_row_separators(false),
+            Some("plain") => {
+                self.set_border_style(BorderStyle::None);
+                self.set_color_mode(ColorMode::Never);
+            },
+            _ => {},
+        }
+        match env_value(BORDER_ENV_VAR).as_deref() {
+            Some("unicode") => self.set_border_style(BorderStyle::Unicode),
+            Some("ascii") => self.set_border_style(BorderStyle::Ascii),
+            Some("none") => self.set_border_style(BorderStyle::None),
+            _ => {},
+        }
+        match env_value(COLOR_ENV_VAR).as_deref() {
+            Some("always") => self.set_color_mode(ColorMode::Always),
+            Some("never") => self.set_color_mode(ColorMode::Never),
+            _ => {},
+        }
+    }
+
+}
+
+/// Gets the trimmed, lowercase value of an environment variable, if set.
+
+fn env_value(
+    name: &str,
+) -> Option<String>
+{
+    std::env::var(name).ok().map(|value| value.trim().to_ascii_lowercase())
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::RenderOptions;
+
+    #[test]
+    fn default_options_read_the_environment_once() {
+        let snapshot = RenderOptions::env_defaults();
+        assert!(core::ptr::eq(snapshot, RenderOptions::env_defaults()));
+        if RenderOptions::env_enabled() {
+            assert_eq!(&RenderOptions::new(), snapshot);
+        }
+    }
+
+}
@@ -1,4 +1,5 @@
 
+#[allow(clippy::enum_variant_names)]
 pub enum GridError {
     RowIndexOutOfBounds,
     ColIndexOutOfBounds,
@@ -11,4 +12,6 @@ impl ErrorMessage {
     pub const ROW_INDEX_OUT_OF_BOUNDS: &'static str = "Row index out of bounds";
     pub const COL_INDEX_OUT_OF_BOUNDS: &'static str = "Column index out of bounds";
     pub const ROW_AND_COL_INDEX_OUT_OF_BOUNDS: &'static str = "Row and column index out of bounds";
+    pub const COL_NAME_NOT_FOUND: &'static str = "Column name not found";
+    pub const PARENT_ROW_NOT_ABOVE: &'static str = "Parent row must be above its child row";
 }
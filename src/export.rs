@@ -0,0 +1,38 @@
+use crate::grid::Grid;
+
+/// An output format the grid can be exported into.
+///
+/// Exporters receive the whole grid and can read its cells through the public API:
+/// `Cell::get_data`, `Cell::get_align`, `Cell::get_color`, `Cell::get_highlight`, and `Cell::get_style`
+/// for the content and styles, and `Grid::col_widths` and `Grid::row_heights` for the computed sizes.
+///
+/// Implement this trait to add custom output formats, and run them with `Grid::export`.
+/// All built-in formats are exporters as well, such as `AsciiDoc` and `BBCode`.
+
+pub trait Exporter {
+
+    /// The exported result, such as a `String` or encoded bytes.
+    type Output;
+
+    /// Exports the grid into the output format.
+
+    fn export(
+        &self,
+        grid: &Grid,
+    ) -> Self::Output;
+
+}
+
+impl Grid {
+
+    /// Exports the grid using the given exporter.
+
+    pub fn export<E: Exporter + ?Sized>(
+        &self,
+        exporter: &E,
+    ) -> E::Output
+    {
+        exporter.export(self)
+    }
+
+}
@@ -0,0 +1,144 @@
+//! C API for rendering tables from C and C++.
+//!
+//! A grid is created with `flatgrid_new`, filled with `flatgrid_set_cell`,
+//! rendered with `flatgrid_render`, and destroyed with `flatgrid_free`.
+//! The matching declarations are in `include/flatgrid.h`.
+
+use crate::cell::Cell;
+use crate::grid::Grid;
+
+use std::ffi::{CStr, CString, c_char, c_int};
+
+/// The call succeeded.
+pub const FLATGRID_OK                  : c_int = 0;
+/// A pointer argument was null.
+pub const FLATGRID_ERROR_NULL          : c_int = -1;
+/// The row or column index was out of bounds.
+pub const FLATGRID_ERROR_OUT_OF_BOUNDS : c_int = -2;
+/// The text was not valid UTF-8.
+pub const FLATGRID_ERROR_INVALID_UTF8  : c_int = -3;
+
+/// Opaque handle to a grid, created with `flatgrid_new` and destroyed with `flatgrid_free`.
+
+pub struct FlatGrid {
+    grid: Grid,
+}
+
+/// Creates a grid of empty cells with the given number of rows and columns.
+///
+/// Returns null if the cells would not fit in memory. The grid must be destroyed with `flatgrid_free`.
+
+#[no_mangle]
+pub extern "C" fn flatgrid_new(
+    rows: usize,
+    cols: usize,
+) -> *mut FlatGrid
+{
+    // Allocations are limited to `isize::MAX` bytes
+    let size = rows.checked_mul(cols).and_then(|count| count.checked_mul(size_of::<Cell>()));
+    if size.is_none_or(|size| size > isize::MAX as usize) {
+        return std::ptr::null_mut();
+    }
+    Box::into_raw(Box::new(FlatGrid { grid: Grid::new(rows, cols) }))
+}
+
+/// Destroys a grid created with `flatgrid_new`. Does nothing if `grid` is null.
+///
+/// # Safety
+///
+/// `grid` must be null or a pointer returned by `flatgrid_new` that was not destroyed yet.
+
+#[no_mangle]
+pub unsafe extern "C" fn flatgrid_free(
+    grid: *mut FlatGrid,
+)
+{
+    if !grid.is_null() {
+        drop(Box::from_raw(grid));
+    }
+}
+
+/// Sets the text of the cell at the given row and column. The text is copied.
+///
+/// Returns `FLATGRID_OK`, or an error code if a pointer is null, the indices are out of bounds,
+/// or the text is not valid UTF-8.
+///
+/// # Safety
+///
+/// `grid` must be null or a valid grid, and `text` must be null or a NUL-terminated string.
+
+#[no_mangle]
+pub unsafe extern "C" fn flatgrid_set_cell(
+    grid: *mut FlatGrid,
+    row: usize,
+    col: usize,
+    text: *const c_char,
+) -> c_int
+{
+    if grid.is_null() || text.is_null() {
+        return FLATGRID_ERROR_NULL;
+    }
+    let Ok(text) = CStr::from_ptr(text).to_str() else {
+        return FLATGRID_ERROR_INVALID_UTF8;
+    };
+    match (*grid).grid.try_set_cell(row, col, text) {
+        Ok(()) => FLATGRID_OK,
+        Err(_) => FLATGRID_ERROR_OUT_OF_BOUNDS,
+    }
+}
+
+/// Renders the grid into a NUL-terminated string, exactly like the `Display` output.
+///
+/// Returns null if `grid` is null. The string must be freed with `flatgrid_string_free`.
+///
+/// # Safety
+///
+/// `grid` must be null or a valid grid.
+
+#[no_mangle]
+pub unsafe extern "C" fn flatgrid_render(
+    grid: *const FlatGrid,
+) -> *mut c_char
+{
+    if grid.is_null() {
+        return std::ptr::null_mut();
+    }
+    // Cell text comes from C strings, so it never contains NUL bytes
+    match CString::new((*grid).grid.to_string()) {
+        Ok(text) => text.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Frees a string returned by `flatgrid_render`. Does nothing if `text` is null.
+///
+/// # Safety
+///
+/// `text` must be null or a pointer returned by `flatgrid_render` that was not freed yet.
+
+#[no_mangle]
+pub unsafe extern "C" fn flatgrid_string_free(
+    text: *mut c_char,
+)
+{
+    if !text.is_null() {
+        drop(CString::from_raw(text));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{flatgrid_free, flatgrid_new};
+
+    #[test]
+    fn new_returns_null_for_grids_larger_than_memory() {
+        assert!(flatgrid_new(usize::MAX, 2).is_null());
+        assert!(flatgrid_new(isize::MAX as usize, 1).is_null());
+
+        let grid = flatgrid_new(2, 3);
+        assert!(!grid.is_null());
+        unsafe { flatgrid_free(grid) };
+    }
+
+}
@@ -0,0 +1,227 @@
+use crate::align::Align;
+use crate::cell::Cell;
+use crate::col_type::parse_bool;
+use crate::color::Color;
+use crate::error::ErrorMessage;
+use crate::grid::Grid;
+
+use alloc::borrow::Cow;
+
+/// The pair of glyphs shown for true and false values by boolean cells.
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum BoolGlyphs {
+    /// `✓` and `✗`.
+    #[default]
+    Check,
+    /// `●` and `○`.
+    Dot,
+    /// `yes` and `no`.
+    YesNo,
+    /// Any pair of texts, true first, such as `("on".into(), "off".into())`,
+    /// either string literals or texts built at runtime, e.g. translated.
+    /// Line breaks are shown as spaces, so boolean cells always take a single line.
+    Custom(Cow<'static, str>, Cow<'static, str>),
+}
+
+impl BoolGlyphs {
+
+    /// Gets the glyph shown for a value, with the line breaks of a custom glyph replaced by spaces.
+
+    pub fn glyph(
+        &self,
+        value: bool,
+    ) -> Cow<'static, str>
+    {
+        let (true_glyph, false_glyph) = match self {
+            BoolGlyphs::Check => ("✓", "✗"),
+            BoolGlyphs::Dot => ("●", "○"),
+            BoolGlyphs::YesNo => ("yes", "no"),
+            BoolGlyphs::Custom(true_glyph, false_glyph) => {
+                let glyph = if value { true_glyph } else { false_glyph };
+                return single_line(glyph.clone());
+            },
+        };
+        Cow::Borrowed(if value { true_glyph } else { false_glyph })
+    }
+
+}
+
+/// Replaces the line breaks of a glyph by spaces.
+
+fn single_line(
+    glyph: Cow<'static, str>,
+) -> Cow<'static, str>
+{
+    if glyph.contains(['\n', '\r']) {
+        Cow::Owned(glyph.replace("\r\n", " ").replace(['\n', '\r'], " "))
+    } else {
+        glyph
+    }
+}
+
+/// How boolean cells are shown, as used by `Cell::from_bool_with` and `Grid::format_bool_col`:
+/// a pair of glyphs, centered, and colored green for true and red for false unless turned off.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoolFormat {
+    glyphs: BoolGlyphs,
+    colored: bool,
+}
+
+impl Default for BoolFormat {
+
+    fn default() -> Self {
+        BoolFormat {
+            glyphs: BoolGlyphs::default(),
+            colored: true,
+        }
+    }
+
+}
+
+impl BoolFormat {
+
+    /// Creates the default format: `✓` and `✗`, colored green and red.
+
+    pub fn new() -> Self {
+        BoolFormat::default()
+    }
+
+    /// Gets the glyphs shown for true and false.
+
+    pub fn glyphs(
+        &self
+    ) -> &BoolGlyphs
+    {
+        &self.glyphs
+    }
+
+    /// Sets the glyphs shown for true and false.
+
+    pub fn set_glyphs(
+        &mut self,
+        new_glyphs: BoolGlyphs,
+    )
+    {
+        self.glyphs = new_glyphs;
+    }
+
+    /// Returns true if true values are colored green and false values red.
+
+    pub fn colored(
+        &self
+    ) -> bool
+    {
+        self.colored
+    }
+
+    /// Sets whether true values are colored green and false values red.
+
+    pub fn set_colored(
+        &mut self,
+        colored: bool,
+    )
+    {
+        self.colored = colored;
+    }
+
+}
+
+impl Cell {
+
+    /// Creates a cell showing a boolean value as `✓` or `✗`, centered and colored green or red,
+    /// so flag columns look the same everywhere.
+    ///
+    /// This is a shortcut for `from_bool_with` with the default `BoolFormat`.
+
+    pub fn from_bool(
+        value: bool,
+    ) -> Self
+    {
+        Cell::from_bool_with(value, &BoolFormat::default())
+    }
+
+    /// Creates a cell showing a boolean value with the glyphs and colors of a format.
+    ///
+    /// The data of the cell is the glyph, which is what exports see, and `get_bool` gets the value back.
+    /// The glyph is aligned by its characters when rendering, so glyphs such as `✓` line up
+    /// even though they take several bytes. Setting the data of the cell makes it a regular cell.
+
+    pub fn from_bool_with(
+        value: bool,
+        format: &BoolFormat,
+    ) -> Self
+    {
+        let mut cell = match format.glyphs.glyph(value) {
+            Cow::Borrowed(glyph) => Cell::from_static(glyph),
+            Cow::Owned(glyph) => Cell::from(glyph),
+        };
+        cell.set_flag(value);
+        cell.set_align(Align::Center);
+        if format.colored {
+            cell.set_color(if value { Color::GREEN } else { Color::RED });
+        }
+        cell
+    }
+
+}
+
+impl Grid {
+
+    /// Shows the cells of a column holding a boolean as boolean cells with the given format,
+    /// e.g. to turn a column of `true`/`false` or `yes`/`no` into checkmarks.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// Cells are read as booleans like in a `ColType::Bool` column, ignoring case and surrounding
+    /// whitespace. Cells holding other text, such as a header, are left unchanged.
+
+    pub fn format_bool_col(
+        &mut self,
+        col_index: usize,
+        format: &BoolFormat,
+    )
+    {
+        if col_index >= self.col_size() {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        for cell in self.col_iter_mut(col_index) {
+            if let Some(value) = parse_bool(cell.get_data().trim()) {
+                *cell = Cell::from_bool_with(value, format);
+            }
+        }
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{BoolFormat, BoolGlyphs, Cell};
+
+    use alloc::string::String;
+
+    #[test]
+    fn custom_glyphs_can_be_built_at_runtime() {
+        let mut format = BoolFormat::new();
+        format.set_glyphs(BoolGlyphs::Custom(String::from("oui").into(), "non".into()));
+        let cell = Cell::from_bool_with(true, &format);
+        assert_eq!(cell.get_data(), "oui");
+        assert_eq!(cell.get_bool(), Some(true));
+        assert_eq!(Cell::from_bool_with(false, &format).get_data(), "non");
+    }
+
+    #[test]
+    fn line_breaks_in_custom_glyphs_become_spaces() {
+        let glyphs = BoolGlyphs::Custom("all\ngood".into(), "not\r\nok".into());
+        assert_eq!(glyphs.glyph(true), "all good");
+        assert_eq!(glyphs.glyph(false), "not ok");
+        let mut format = BoolFormat::new();
+        format.set_glyphs(glyphs);
+        assert_eq!(Cell::from_bool_with(true, &format).height(), 1);
+    }
+
+}
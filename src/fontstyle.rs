@@ -1,6 +1,11 @@
-use std::ops::{BitOr, BitOrAssign};
+use core::ops::{BitOr, BitOrAssign};
 use crate::ansi::*;
 
+#[cfg(feature = "serde")]
+use alloc::string::String;
+#[cfg(feature = "serde")]
+use alloc::vec::Vec;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct FontStyle(u8);
 
@@ -30,6 +35,19 @@ impl FontStyle {
         FontStyle::STRIKE,
     ];
 
+    /// Lowercase names of the font styles, in the order of `ALL_FLAGS`.
+    #[cfg(any(feature = "serde", feature = "pyo3"))]
+    pub(crate) const NAMES : [&'static str; FontStyle::COUNT] = [
+        "bold",
+        "dim",
+        "italic",
+        "underline",
+        "blink",
+        "reverse",
+        "hidden",
+        "strike",
+    ];
+
     // Constants for easy access to individual font styles
     pub const Bold      : FontStyle = FontStyle(FontStyle::BOLD);
     pub const Dim       : FontStyle = FontStyle(FontStyle::DIM);
@@ -40,10 +58,44 @@ impl FontStyle {
     pub const Hidden    : FontStyle = FontStyle(FontStyle::HIDDEN);
     pub const Strike    : FontStyle = FontStyle(FontStyle::STRIKE);
 
-    pub fn new() -> FontStyle {
+    pub const fn new() -> FontStyle {
         FontStyle(0)
     }
 
+    /// Gets the single font style with the given lowercase name, such as `"bold"` or `"underline"`.
+
+    #[cfg(any(feature = "serde", feature = "pyo3"))]
+    pub(crate) fn from_name(
+        name: &str,
+    ) -> Option<FontStyle>
+    {
+        FontStyle::NAMES.iter()
+            .position(|&style_name| style_name == name)
+            .map(|index| FontStyle(FontStyle::ALL_FLAGS[index]))
+    }
+
+    /// Gets the lowercase name of a single font style, or an empty string for combined styles.
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn name(
+        &self
+    ) -> &'static str
+    {
+        FontStyle::ALL_FLAGS.iter()
+            .position(|&flag| flag == self.0)
+            .map_or("", |index| FontStyle::NAMES[index])
+    }
+
+    /// Checks whether all the font styles of `other` are set.
+
+    pub fn contains(
+        &self,
+        other: FontStyle,
+    ) -> bool
+    {
+        self.0 & other.0 == other.0
+    }
+
     /// Returns the ANSI code string for the given font style.
     /// 
     /// # Returns
@@ -134,4 +186,36 @@ impl IntoIterator for FontStyle {
         }
     }
 
-}
\ No newline at end of file
+}
+
+/// Font styles are serialized as a list of names, such as `["bold", "underline"]`.
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for FontStyle {
+
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    {
+        serializer.collect_seq(self.into_iter().map(|style| style.name()))
+    }
+
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FontStyle {
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    {
+        let names: Vec<String> = serde::Deserialize::deserialize(deserializer)?;
+        names.iter().try_fold(FontStyle::new(), |font_style, name| {
+            FontStyle::from_name(name)
+                .map(|style| font_style | style)
+                .ok_or_else(|| serde::de::Error::unknown_variant(name, &FontStyle::NAMES))
+        })
+    }
+
+}
@@ -2,50 +2,178 @@ use crate::ansi::RESET_ANSI_CODE;
 use crate::color::{Foreground, Background};
 use crate::fontstyle::FontStyle;
 
-/// Applies color and style formatting to text using ANSI escape codes.
-/// 
-/// This function combines foreground color, background color, and font styles
-/// into a single formatted string with proper ANSI escape sequences.
-/// 
-/// # Arguments
-/// 
-/// * `text` - The text to format
-/// * `fg_color` - Optional foreground color
-/// * `bg_color` - Optional background color
-/// * `style` - Font style flags to apply
-/// 
-/// # Returns
-/// 
-/// A `String` with the text formatted using ANSI escape codes
-
-pub fn apply_ansi_formatting(
-    text: &str,
-    fg_color: Option<Foreground>,
-    bg_color: Option<Background>,
-    style_flags: FontStyle,
-) -> String
-{
-    let mut formatted_text = String::new();
-    let mut is_formatted = false;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The colors and font styles of a run of text, identifying its ANSI prefix.
+
+type StyleKey = (Option<Foreground>, Option<Background>, FontStyle);
+
+/// Cache of the ANSI escape sequences that open each combination of colors and font styles.
+///
+/// A grid typically uses only a handful of distinct styles, so the concatenated prefix
+/// of each one is built once per render and reused for every line of every cell,
+/// instead of being assembled from its individual codes each time.
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AnsiPrefixes {
+    prefixes: Vec<(StyleKey, String)>,
+}
 
-    if let Some(color) = fg_color {
-        formatted_text.push_str(color.as_ansi_code());
-        is_formatted = true;
+impl AnsiPrefixes {
+
+    /// Creates an empty cache.
+
+    pub fn new() -> Self {
+        AnsiPrefixes {
+            prefixes: Vec::new(),
+        }
     }
-    if let Some(bg_color) = bg_color {
-        formatted_text.push_str(bg_color.as_ansi_code());
-        is_formatted = true;
+
+    /// Gets the escape sequences enabling the given colors and font styles, building them on first use.
+    ///
+    /// # Arguments
+    ///
+    /// * `fg_color` - Optional foreground color
+    /// * `bg_color` - Optional background color
+    /// * `style_flags` - Font style flags to apply
+    ///
+    /// # Returns
+    ///
+    /// The concatenated escape sequences, empty if the text is unformatted
+
+    pub fn prefix(
+        &mut self,
+        fg_color: Option<Foreground>,
+        bg_color: Option<Background>,
+        style_flags: FontStyle,
+    ) -> &str
+    {
+        let key = (fg_color, bg_color, style_flags);
+        let index = match self.prefixes.iter().position(|(cached, _)| *cached == key) {
+            Some(index) => index,
+            None => {
+                let mut prefix = String::new();
+                if let Some(color) = fg_color {
+                    prefix.push_str(color.as_ansi_code());
+                }
+                if let Some(bg_color) = bg_color {
+                    prefix.push_str(bg_color.as_ansi_code());
+                }
+                for style in style_flags.into_iter() {
+                    prefix.push_str(style.as_style_ansi_code());
+                }
+                self.prefixes.push((key, prefix));
+                self.prefixes.len() - 1
+            },
+        };
+        &self.prefixes[index].1
     }
-    for style in style_flags.into_iter() {
-        formatted_text.push_str(style.as_style_ansi_code());
-        is_formatted = true;
+
+}
+
+/// Writes text formatted with an ANSI prefix, as cached by `AnsiPrefixes`.
+///
+/// The text is followed by a reset code if the prefix is not empty.
+/// Empty text is written as nothing at all, without any escape sequences.
+
+pub fn write_ansi_formatted(
+    output: &mut impl fmt::Write,
+    prefix: &str,
+    text: &str,
+) -> fmt::Result
+{
+    if text.is_empty() {
+        return Ok(());
     }
+    if prefix.is_empty() {
+        return output.write_str(text);
+    }
+    output.write_str(prefix)?;
+    output.write_str(text)?;
+    output.write_str(RESET_ANSI_CODE)
+}
 
-    formatted_text.push_str(text);
+/// Counts the characters of a rendered line that are visible in a terminal.
+///
+/// ANSI escape sequences (`ESC [ ... letter`) are skipped.
 
-    if is_formatted {
-        formatted_text.push_str(&RESET_ANSI_CODE);
+pub fn visible_width(
+    text: &str,
+) -> usize
+{
+    // Most text has no escape sequences, and counting its characters needs no decoding
+    if !text.as_bytes().contains(&0x1b) {
+        return text.chars().count();
     }
 
-    formatted_text
+    let mut width = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip the escape sequence up to and including its final letter
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            width += 1;
+        }
+    }
+    width
+}
+
+/// Clips a rendered line to a window of visible characters.
+///
+/// The window starts `start` visible characters into the line and is at most `width` characters wide.
+/// Characters and ANSI escape sequences are never split: styles opened before the window
+/// are re-emitted at its start, and a reset is appended if a style is still open at its end.
+
+pub fn clip_visible(
+    text: &str,
+    start: usize,
+    width: usize,
+) -> String
+{
+    let mut clipped = String::new();
+    // Escape sequences that are in effect at the current position
+    let mut active = String::new();
+    let mut position = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            let mut sequence = String::from(c);
+            for c in chars.by_ref() {
+                sequence.push(c);
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            if sequence == RESET_ANSI_CODE {
+                active.clear();
+            } else {
+                active.push_str(&sequence);
+            }
+            if position > start {
+                clipped.push_str(&sequence);
+            }
+            continue;
+        }
+        if position >= start + width {
+            break;
+        }
+        if position == start {
+            clipped.push_str(&active);
+        }
+        if position >= start {
+            clipped.push(c);
+        }
+        position += 1;
+    }
+    if !active.is_empty() && position > start {
+        clipped.push_str(RESET_ANSI_CODE);
+    }
+    clipped
 }
@@ -1,17 +1,47 @@
 use crate::cell::Cell;
-use crate::border::Border;
+use crate::cell_style::CellStyle;
+use crate::col_type::ColType;
+use crate::dirty::DirtyRows;
+use crate::history::{Edit, History};
 use crate::error::{GridError, ErrorMessage};
-
-use std::fmt::{Display, Formatter, Error};
-use std::collections::{VecDeque};
+use crate::options::RenderOptions;
+use crate::border::BorderStyle;
+use crate::format::AnsiPrefixes;
+use crate::iter::{ColIter, ColIterMut};
+use crate::locale::Locale;
+use crate::render::{Layout, LayoutCache, Segment, write_segment};
+use crate::search::MatchHighlight;
+use crate::selection::Selection;
+use crate::tree::Tree;
+use crate::validate::{ColValidator, ColValidators};
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::vec;
+use core::fmt::{Alignment, Debug, Display, Formatter, Error, Write};
+use core::ops::{Index, IndexMut, Range};
 
 /// A 2D grid of cells stored in a flat vector in row-major order.
 
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Clone, Default, PartialEq, Eq)]
 pub struct Grid {
     cells: Vec<Cell>,
     row_size: usize,
     col_size: usize,
+    footer_rows: usize,
+    merged_cols: BTreeSet<usize>,
+    col_types: BTreeMap<usize, ColType>,
+    col_validators: ColValidators,
+    history: History,
+    tree: Tree,
+    layout_cache: LayoutCache,
+    dirty_rows: DirtyRows,
+    match_highlight: MatchHighlight,
+    selection: Selection,
+    border_style: Option<BorderStyle>,
+    default_style: CellStyle,
+    locale: Locale,
 }
 
 
@@ -27,7 +57,22 @@ impl Grid {
     ) -> Self
     {
         let cells = vec![Cell::default(); col_size * row_size];
-        Grid { cells, col_size, row_size }
+        Grid { cells, col_size, row_size, footer_rows: 0, merged_cols: BTreeSet::new(), col_types: BTreeMap::new(), col_validators: ColValidators::default(), history: History::default(), tree: Tree::default(), layout_cache: LayoutCache::default(), dirty_rows: DirtyRows::default(), match_highlight: MatchHighlight::default(), selection: Selection::default(), border_style: None, default_style: CellStyle::default(), locale: Locale::PLAIN }
+    }
+
+    /// Create a new grid without rows, with the specified number of columns
+    /// and room for the specified number of rows.
+    /// 
+    /// Rows added with `push_row` or `insert_row` up to that number
+    /// do not reallocate the cells.
+
+    pub fn with_capacity(
+        row_capacity: usize,
+        col_size: usize,
+    ) -> Self
+    {
+        let cells = Vec::with_capacity(row_capacity * col_size);
+        Grid { cells, col_size, row_size: 0, footer_rows: 0, merged_cols: BTreeSet::new(), col_types: BTreeMap::new(), col_validators: ColValidators::default(), history: History::default(), tree: Tree::default(), layout_cache: LayoutCache::default(), dirty_rows: DirtyRows::default(), match_highlight: MatchHighlight::default(), selection: Selection::default(), border_style: None, default_style: CellStyle::default(), locale: Locale::PLAIN }
     }
 
     /// Create a new grid from a 2D iterator.
@@ -61,7 +106,444 @@ impl Grid {
                 row_cells
             })
             .collect();
-        Grid { cells, col_size, row_size }
+        Grid { cells, col_size, row_size, footer_rows: 0, merged_cols: BTreeSet::new(), col_types: BTreeMap::new(), col_validators: ColValidators::default(), history: History::default(), tree: Tree::default(), layout_cache: LayoutCache::default(), dirty_rows: DirtyRows::default(), match_highlight: MatchHighlight::default(), selection: Selection::default(), border_style: None, default_style: CellStyle::default(), locale: Locale::PLAIN }
+    }
+
+    /// Get the number of rows in the grid.
+
+    pub fn row_size(
+        &self
+    ) -> usize
+    {
+        self.row_size
+    }
+
+    /// Get the number of columns in the grid.
+
+    pub fn col_size(
+        &self
+    ) -> usize
+    {
+        self.col_size
+    }
+
+    /// Get the number of rows at the bottom of the grid that form its footer.
+    ///
+    /// The footer is drawn below a heavier border than the other rows, e.g. for totals.
+    /// It is always made of the last rows, so rows pushed after it join the footer.
+
+    pub fn footer_rows(
+        &self
+    ) -> usize
+    {
+        self.footer_rows.min(self.row_size)
+    }
+
+    /// Set the number of rows at the bottom of the grid that form its footer.
+    ///
+    /// Rows are not added or removed: a count larger than the grid makes every row part of the footer.
+
+    pub fn set_footer_rows(
+        &mut self,
+        new_footer_rows: usize,
+    )
+    {
+        self.record_edit(|grid| Edit::SetFooterRows { footer_rows: grid.footer_rows });
+        self.layout_cache.clear();
+        self.footer_rows = new_footer_rows;
+    }
+
+    /// Get the border style set with `set_border_style`, or None if the grid uses the one of the render options.
+
+    pub fn border_style(
+        &self
+    ) -> Option<BorderStyle>
+    {
+        self.border_style
+    }
+
+    /// Set the border style of the grid, used wherever it is rendered with the default options:
+    /// `Display`, `render_plain`, `render_to`, `render_lines`, and the exporters drawing the table.
+    ///
+    /// It takes precedence over the `FLATGRID_BORDER` environment variable, but not over
+    /// the options passed to `render_with` and the other `_with` methods.
+
+    pub fn set_border_style(
+        &mut self,
+        new_border_style: BorderStyle,
+    )
+    {
+        self.border_style = Some(new_border_style);
+    }
+
+    /// Get the locale in which numbers are read, set with `set_locale` or `format_numbers`.
+
+    pub fn locale(
+        &self
+    ) -> &Locale
+    {
+        &self.locale
+    }
+
+    /// Set the locale in which numbers are read when sorting, aggregating, computing statistics,
+    /// and typing columns, so `1 234,5` is a number with `Locale::FRENCH`.
+    ///
+    /// Numbers written like Rust does, such as `1234.5`, are read in any locale.
+
+    pub fn set_locale(
+        &mut self,
+        new_locale: Locale,
+    )
+    {
+        self.locale = new_locale;
+    }
+
+    /// Get the formatting of the cells that do not set their own, set with `set_default_style`.
+
+    pub fn default_style(
+        &self
+    ) -> &CellStyle
+    {
+        &self.default_style
+    }
+
+    /// Get the formatting of the cells that do not set their own to change it,
+    /// marking every row as dirty since the rows render differently.
+
+    pub(crate) fn default_style_mut(
+        &mut self
+    ) -> &mut CellStyle
+    {
+        self.touch_rows_from(0);
+        &mut self.default_style
+    }
+
+    /// Get the render options the grid is rendered with when none are given:
+    /// the default options, with the border style of the grid if it has one.
+
+    pub(crate) fn default_options(
+        &self
+    ) -> RenderOptions
+    {
+        let mut options = RenderOptions::default();
+        if let Some(border_style) = self.border_style {
+            options.set_border_style(border_style);
+        }
+        options
+    }
+
+    /// Get the columns whose runs of identical cells are merged when rendering.
+
+    pub(crate) fn merged_cols(
+        &self
+    ) -> &BTreeSet<usize>
+    {
+        &self.merged_cols
+    }
+
+    /// Set whether runs of identical cells in a column are merged when rendering,
+    /// marking every row as dirty since the merged rows render differently.
+
+    pub(crate) fn set_merged_col(
+        &mut self,
+        col_index: usize,
+        merged: bool,
+    )
+    {
+        self.touch_rows_from(0);
+        if merged {
+            self.merged_cols.insert(col_index);
+        } else {
+            self.merged_cols.remove(&col_index);
+        }
+    }
+
+    /// Get the types set on columns with `set_col_type`, by column index.
+
+    pub(crate) fn col_types(
+        &self
+    ) -> &BTreeMap<usize, ColType>
+    {
+        &self.col_types
+    }
+
+    /// Get the types set on columns mutably.
+
+    pub(crate) fn col_types_mut(
+        &mut self
+    ) -> &mut BTreeMap<usize, ColType>
+    {
+        &mut self.col_types
+    }
+
+    /// Get the validators set on columns with `set_col_validator`, by column index.
+
+    pub(crate) fn col_validators(
+        &self
+    ) -> &BTreeMap<usize, ColValidator>
+    {
+        &self.col_validators.0
+    }
+
+    /// Get the validators set on columns mutably.
+
+    pub(crate) fn col_validators_mut(
+        &mut self
+    ) -> &mut BTreeMap<usize, ColValidator>
+    {
+        &mut self.col_validators.0
+    }
+
+    /// Get the edit history.
+
+    pub(crate) fn history(
+        &self
+    ) -> &History
+    {
+        &self.history
+    }
+
+    /// Get the edit history mutably.
+
+    pub(crate) fn history_mut(
+        &mut self
+    ) -> &mut History
+    {
+        &mut self.history
+    }
+
+    /// Get the parents of the child rows and the collapsed rows.
+
+    pub(crate) fn tree(
+        &self
+    ) -> &Tree
+    {
+        &self.tree
+    }
+
+    /// Get the parents of the child rows and the collapsed rows to change them,
+    /// marking every row as dirty since the tree glyphs and hidden rows may change.
+
+    pub(crate) fn tree_mut(
+        &mut self
+    ) -> &mut Tree
+    {
+        self.touch_rows_from(0);
+        &mut self.tree
+    }
+
+    /// Removes a row, returning its cells, as `remove_row` does without recording it.
+    ///
+    /// Returns no cells if the index is out of bounds.
+
+    pub(crate) fn remove_row_cells(
+        &mut self,
+        row_index: usize,
+    ) -> Vec<Cell>
+    {
+        if row_index >= self.row_size {
+            return Vec::new();
+        }
+        self.touch_rows_from(row_index);
+        let start = row_index * self.col_size;
+        let cells = self.cells.drain(start..start + self.col_size).collect();
+        self.row_size -= 1;
+        self.tree.remove_row(row_index);
+        cells
+    }
+
+    /// Removes a column, returning its cells, as `remove_col` does without recording it.
+    ///
+    /// Returns no cells if the index is out of bounds.
+
+    pub(crate) fn remove_col_cells(
+        &mut self,
+        col_index: usize,
+    ) -> Vec<Cell>
+    {
+        if col_index >= self.col_size {
+            return Vec::new();
+        }
+        self.touch_rows_from(0);
+        let mut removed = Vec::with_capacity(self.row_size);
+        let mut cells = Vec::with_capacity(self.row_size * (self.col_size - 1));
+        for (index, cell) in core::mem::take(&mut self.cells).into_iter().enumerate() {
+            if index % self.col_size == col_index {
+                removed.push(cell);
+            } else {
+                cells.push(cell);
+            }
+        }
+        self.cells = cells;
+        self.col_size -= 1;
+
+        // Merged, typed, and validated columns after the removed one move to the left
+        let shifted = |col: usize| if col > col_index { Some(col - 1) } else { (col < col_index).then_some(col) };
+        self.merged_cols = self.merged_cols.iter().filter_map(|&merged_col| shifted(merged_col)).collect();
+        self.col_types = self.col_types.iter().filter_map(|(&typed_col, &col_type)| Some((shifted(typed_col)?, col_type))).collect();
+        self.col_validators.0 = core::mem::take(&mut self.col_validators.0).into_iter()
+            .filter_map(|(validated_col, validator)| Some((shifted(validated_col)?, validator)))
+            .collect();
+        removed
+    }
+
+    /// Moves the rows into the given order, where `order[i]` is the current index of the row to put at `i`,
+    /// without recording it.
+
+    pub(crate) fn move_rows(
+        &mut self,
+        order: &[usize],
+    )
+    {
+        let first_moved = order.iter()
+            .enumerate()
+            .position(|(row_index, &old_index)| row_index != old_index)
+            .unwrap_or(order.len());
+        self.touch_rows_from(first_moved);
+        let mut cells = core::mem::take(&mut self.cells);
+        self.cells.reserve_exact(cells.len());
+        for &old_index in order {
+            let start = old_index * self.col_size;
+            self.cells.extend(cells[start..start + self.col_size].iter_mut().map(core::mem::take));
+        }
+        self.tree.move_rows(order);
+    }
+
+    /// Moves the columns into the given order, where `order[i]` is the current index of the column to put at `i`,
+    /// without recording it.
+    ///
+    /// The cells are swapped in place along the cycles of the order, so no cell is moved out of the grid.
+
+    pub(crate) fn move_cols(
+        &mut self,
+        order: &[usize],
+    )
+    {
+        // Every cycle of the order is a series of swaps, found once and made in every row
+        let mut visited = vec![false; order.len()];
+        let mut swaps = Vec::new();
+        for start in 0..order.len() {
+            let mut col_index = start;
+            while !visited[col_index] && order[col_index] != start {
+                visited[col_index] = true;
+                swaps.push((col_index, order[col_index]));
+                col_index = order[col_index];
+            }
+            visited[col_index] = true;
+        }
+        if swaps.is_empty() {
+            return;
+        }
+
+        self.touch_rows_from(0);
+        for row in self.cells.chunks_mut(self.col_size) {
+            for &(a, b) in &swaps {
+                row.swap(a, b);
+            }
+        }
+    }
+
+    /// Get the index of the first row of the footer, or the number of rows if there is no footer.
+
+    pub(crate) fn footer_start(
+        &self
+    ) -> usize
+    {
+        self.row_size - self.footer_rows()
+    }
+
+    /// Get the rows mutated since they were last taken.
+
+    pub(crate) fn dirty_rows(
+        &self
+    ) -> &DirtyRows
+    {
+        &self.dirty_rows
+    }
+
+    /// Get the rows mutated since they were last taken, to mark or take them.
+
+    pub(crate) fn dirty_rows_mut(
+        &mut self
+    ) -> &mut DirtyRows
+    {
+        &mut self.dirty_rows
+    }
+
+    /// Get the pattern whose matches are highlighted when rendering.
+
+    pub(crate) fn match_highlight(
+        &self
+    ) -> &MatchHighlight
+    {
+        &self.match_highlight
+    }
+
+    /// Get the pattern whose matches are highlighted when rendering, to replace it.
+
+    pub(crate) fn match_highlight_mut(
+        &mut self
+    ) -> &mut MatchHighlight
+    {
+        &mut self.match_highlight
+    }
+
+    /// Get the selected cells, shown in the selection style when rendering.
+
+    pub(crate) fn selection(
+        &self
+    ) -> &Selection
+    {
+        &self.selection
+    }
+
+    /// Get the selected cells, to change them.
+
+    pub(crate) fn selection_mut(
+        &mut self
+    ) -> &mut Selection
+    {
+        &mut self.selection
+    }
+
+    /// Discard the cached layout and mark a row as dirty, before mutating it.
+
+    fn touch_row(
+        &mut self,
+        row_index: usize,
+    )
+    {
+        self.layout_cache.clear();
+        self.dirty_rows.mark(row_index);
+    }
+
+    /// Discard the cached layout and mark every row from the given index on as dirty, before mutating them.
+
+    fn touch_rows_from(
+        &mut self,
+        row_index: usize,
+    )
+    {
+        self.layout_cache.clear();
+        self.dirty_rows.mark_from(row_index);
+    }
+
+    /// Get the number of cells the grid can hold without reallocating.
+
+    pub(crate) fn cell_capacity(
+        &self
+    ) -> usize
+    {
+        self.cells.capacity()
+    }
+
+    /// Get the cache holding the layout of the whole grid.
+
+    pub(crate) fn layout_cache(
+        &self
+    ) -> &LayoutCache
+    {
+        &self.layout_cache
     }
 
     /// Set the entire grid's cells from an iterator.
@@ -77,24 +559,89 @@ impl Grid {
         new_cells: impl IntoIterator<Item = impl Into<Cell>>,
     )
     {
-        let mut new_cells_iter = new_cells.into_iter();
-        for cell in self.cells.iter_mut() {
-            *cell = new_cells_iter
-                .next()
-                .map(Into::into)
-                .unwrap_or_default();
-        }
+        let new_cells = new_cells.into_iter()
+            .map(Into::into)
+            .take(self.cells.len())
+            .collect();
+        self.set_cells_vec(new_cells);
+    }
+
+    /// Set the entire grid's cells from a vector, in row-major order.
+    /// 
+    /// The vector is moved into the grid as a whole, instead of setting the cells one by one
+    /// as `set_cells` does, which makes loading large amounts of data faster.
+    /// 
+    /// If the vector has fewer cells than the grid size,
+    /// the remaining cells will be filled with the default cell.
+    /// 
+    /// If the vector has more cells than the grid size,
+    /// the extra cells will be ignored.
+
+    pub fn set_cells_vec(
+        &mut self,
+        mut new_cells: Vec<Cell>,
+    )
+    {
+        self.touch_rows_from(0);
+        new_cells.resize_with(self.cells.len(), Cell::default);
+        let old_cells = core::mem::replace(&mut self.cells, new_cells);
+        // Only the cells that changed are kept to undo this
+        self.record_edit(|grid| Edit::SetCells {
+            cells: old_cells.into_iter()
+                .enumerate()
+                .filter(|(index, cell)| *cell != grid.cells[*index])
+                .collect(),
+        });
+    }
+
+    /// Puts cells at the given indices in row-major order, to undo `set_cells` or `resize`.
+    ///
+    /// # Returns
+    ///
+    /// The replaced cells, with their indices
+
+    pub(crate) fn replace_cells(
+        &mut self,
+        cells: Vec<(usize, Cell)>,
+    ) -> Vec<(usize, Cell)>
+    {
+        cells.into_iter()
+            .map(|(index, cell)| {
+                self.touch_row(index / self.col_size);
+                (index, core::mem::replace(&mut self.cells[index], cell))
+            })
+            .collect()
     }
 
     /// Set the cell at the specified row and column indices.
     /// 
     /// Panics if the indices are out of bounds.
+    ///
+    /// # Returns
+    ///
+    /// The new cell, to format it in the same statement, e.g. `grid.set_cell(0, 1, "x").set_color(Color::RED)`
 
     pub fn set_cell(
         &mut self,
         row_index: usize,
         col_index: usize,
         cell_data: impl Into<Cell>,
+    ) -> &mut Cell
+    {
+        self.check_cell_index(row_index, col_index);
+        self.touch_row(row_index);
+        let index = row_index * self.col_size + col_index;
+        self.record_edit(|grid| Edit::SetCell { row_index, col_index, cell: grid.cells[index].clone() });
+        self.cells[index] = cell_data.into();
+        &mut self.cells[index]
+    }
+
+    /// Panics with the matching message if the indices are out of bounds.
+
+    fn check_cell_index(
+        &self,
+        row_index: usize,
+        col_index: usize,
     )
     {
         if row_index >= self.row_size && col_index >= self.col_size {
@@ -106,7 +653,6 @@ impl Grid {
         if col_index >= self.col_size {
             panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
         }
-        self.cells[row_index * self.col_size + col_index] = cell_data.into();
     }
 
     /// Try to set the cell at the specified row and column indices.
@@ -129,7 +675,10 @@ impl Grid {
         if col_index >= self.col_size {
             return Err(GridError::ColIndexOutOfBounds);
         }
-        self.cells[row_index * self.col_size + col_index] = cell_data.into();
+        self.touch_row(row_index);
+        let index = row_index * self.col_size + col_index;
+        self.record_edit(|grid| Edit::SetCell { row_index, col_index, cell: grid.cells[index].clone() });
+        self.cells[index] = cell_data.into();
         Ok(())
     }
 
@@ -143,6 +692,9 @@ impl Grid {
         col_index: usize
     ) -> Option<&Cell>
     {
+        if col_index >= self.col_size {
+            return None;
+        }
         self.cells.get(row_index * self.col_size + col_index)
     }
 
@@ -156,6 +708,10 @@ impl Grid {
         col_index: usize
     ) -> Option<&mut Cell>
     {
+        if row_index >= self.row_size || col_index >= self.col_size {
+            return None;
+        }
+        self.touch_row(row_index);
         self.cells.get_mut(row_index * self.col_size + col_index)
     }
 
@@ -166,18 +722,47 @@ impl Grid {
     pub fn row_iter(
         &self,
         row_index: usize
-    ) -> impl Iterator<Item = &Cell>
+    ) -> impl DoubleEndedIterator<Item = &Cell> + ExactSizeIterator
     {
-        let row_index = if row_index < self.row_size {
-            row_index
-        } else {
-            // Skip everything if out of bounds
-            // This will return an empty iterator
-            self.row_size
-        };
-        self.cells.iter()
-            .skip(row_index * self.col_size)
-            .take(self.col_size)
+        self.cells[self.row_range(row_index)].iter()
+    }
+
+    /// Get the cells in the specified row as a slice, which is empty if the index is out of bounds.
+
+    pub(crate) fn row_slice(
+        &self,
+        row_index: usize,
+    ) -> &[Cell]
+    {
+        &self.cells[self.row_range(row_index)]
+    }
+
+    /// Get the cells in the specified row as a mutable slice, marking the row as dirty.
+    /// The slice is empty if the index is out of bounds.
+
+    pub(crate) fn row_slice_mut(
+        &mut self,
+        row_index: usize,
+    ) -> &mut [Cell]
+    {
+        if row_index < self.row_size {
+            self.touch_row(row_index);
+        }
+        let range = self.row_range(row_index);
+        &mut self.cells[range]
+    }
+
+    /// Get the cells of every row as separate mutable slices, marking every row as dirty.
+
+    pub(crate) fn row_slices_mut(
+        &mut self
+    ) -> impl Iterator<Item = &mut [Cell]>
+    {
+        self.touch_rows_from(0);
+        // Rows of a grid without columns have no cells to chunk, so they are empty slices
+        self.cells.chunks_mut(self.col_size.max(1))
+            .chain(core::iter::repeat_with(<&mut [Cell]>::default))
+            .take(self.row_size)
     }
 
     /// Get an immutable iterator over the cells in the specified column.
@@ -187,19 +772,9 @@ impl Grid {
     pub fn col_iter(
         &self,
         col_index: usize
-    ) -> impl Iterator<Item = &Cell>
+    ) -> impl DoubleEndedIterator<Item = &Cell> + ExactSizeIterator
     {
-        let col_index = if col_index < self.col_size {
-            col_index
-        } else {
-            // Skip everything if out of bounds
-            // This will return an empty iterator
-            self.row_size * self.col_size
-        };
-        let step = self.col_size.max(1);
-        self.cells.iter()
-            .skip(col_index)
-            .step_by(step)
+        ColIter::new(&self.cells[self.col_range(col_index)], self.col_size)
     }
 
     /// Get a mutable iterator over the cells in the specified row.
@@ -209,18 +784,13 @@ impl Grid {
     pub fn row_iter_mut(
         &mut self,
         row_index: usize
-    ) -> impl Iterator<Item = &mut Cell>
+    ) -> impl DoubleEndedIterator<Item = &mut Cell> + ExactSizeIterator
     {
-        let row_index = if row_index < self.row_size {
-            row_index
-        } else {
-            // Skip everything if out of bounds
-            // This will return an empty iterator
-            self.row_size
-        };
-        self.cells.iter_mut()
-            .skip(row_index * self.col_size)
-            .take(self.col_size)
+        if row_index < self.row_size {
+            self.touch_row(row_index);
+        }
+        let range = self.row_range(row_index);
+        self.cells[range].iter_mut()
     }
 
     /// Get a mutable iterator over the cells in the specified column.
@@ -229,20 +799,14 @@ impl Grid {
 
     pub fn col_iter_mut(
         &mut self,
-        col_index: usize
-    ) -> impl Iterator<Item = &mut Cell>
-    {
-        let col_index = if col_index < self.col_size {
-            col_index
-        } else {
-            // Skip everything if out of bounds
-            // This will return an empty iterator
-            self.row_size * self.col_size
-        };
-        let step = self.col_size.max(1);
-        self.cells.iter_mut()
-            .skip(col_index)
-            .step_by(step)
+        col_index: usize
+    ) -> impl DoubleEndedIterator<Item = &mut Cell> + ExactSizeIterator
+    {
+        if col_index < self.col_size {
+            self.touch_rows_from(0);
+        }
+        let range = self.col_range(col_index);
+        ColIterMut::new(&mut self.cells[range], self.col_size)
     }
 
     /// Get an immutable iterator over all cells in the grid.
@@ -251,7 +815,7 @@ impl Grid {
 
     pub fn flat_iter(
         &self
-    ) -> impl Iterator<Item = &Cell>
+    ) -> impl DoubleEndedIterator<Item = &Cell> + ExactSizeIterator
     {
         self.cells.iter()
     }
@@ -262,14 +826,71 @@ impl Grid {
 
     pub fn flat_iter_mut(
         &mut self
-    ) -> impl Iterator<Item = &mut Cell>
+    ) -> impl DoubleEndedIterator<Item = &mut Cell> + ExactSizeIterator
     {
+        self.touch_rows_from(0);
         self.cells.iter_mut()
     }
 
+    /// Get the range of the cells of a row, which is empty if the index is out of bounds.
+
+    fn row_range(
+        &self,
+        row_index: usize,
+    ) -> Range<usize>
+    {
+        if row_index >= self.row_size {
+            return 0..0;
+        }
+        let start = row_index * self.col_size;
+        start..start + self.col_size
+    }
+
+    /// Get the range of the cells from the first to the last cell of a column,
+    /// which is empty if the index is out of bounds.
+
+    fn col_range(
+        &self,
+        col_index: usize,
+    ) -> Range<usize>
+    {
+        if col_index >= self.col_size || self.row_size == 0 {
+            return 0..0;
+        }
+        col_index..col_index + (self.row_size - 1) * self.col_size + 1
+    }
+
+    /// Get the data of every cell as text, stripped of all formatting, one `Vec` per row.
+    ///
+    /// This is the inverse of `Grid::from` for plain data, so the grid can be the single
+    /// source of truth for both display and further processing.
+
+    pub fn to_records(
+        &self
+    ) -> Vec<Vec<String>>
+    {
+        (0..self.row_size)
+            .map(|row_index| self.row_iter(row_index).map(|cell| cell.get_data().to_string()).collect())
+            .collect()
+    }
+
+    /// Consume the grid and return the data of every cell as text, one `Vec` per row.
+    ///
+    /// Same as `Grid::to_records`, without copying the data.
+
+    pub fn into_records(
+        self
+    ) -> Vec<Vec<String>>
+    {
+        let mut data = self.cells.into_iter().map(Cell::into_data);
+        (0..self.row_size)
+            .map(|_| data.by_ref().take(self.col_size).collect())
+            .collect()
+    }
+
     /// Insert a new column at the specified column index.
     /// Existing columns to the right of the inserted column will be shifted
-    /// in place to the right.
+    /// to the right; inserting at the number of columns appends the new column.
     /// 
     /// Panics if the column index is out of bounds.
     /// 
@@ -278,6 +899,9 @@ impl Grid {
     /// 
     /// If the new column has more cells than the number of rows in the grid,
     /// the excess cells will be truncated.
+    /// 
+    /// The cells are shifted in place if room was reserved with `reserve_cols`,
+    /// and moved into a larger buffer otherwise.
 
     pub fn insert_col(
         &mut self,
@@ -285,49 +909,75 @@ impl Grid {
         new_column: impl IntoIterator<Item = impl Into<Cell>>,
     )
     {
-        if col_index >= self.col_size {
+        if col_index > self.col_size {
             panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
         }
-
-        let mut new_column: Vec<Cell> = new_column.into_iter().map(Into::into).collect();
+        self.touch_rows_from(0);
+        self.record_edit(|_| Edit::RemoveCol { col_index });
 
         // Fill or truncate the new column to match the number of rows
-        new_column.resize(self.row_size, Cell::default());
+        let new_column = new_column.into_iter()
+            .map(Into::into)
+            .chain(core::iter::repeat_with(Cell::default))
+            .take(self.row_size);
 
         let old_col_size = self.col_size;
         let new_col_size = self.col_size + 1;
         let new_size = self.row_size * new_col_size;
 
-        // Resize the existing cells to make room for the new column
-        self.cells.resize(new_size, Cell::default());
-
-        for ri in (0..self.row_size).rev() {
-            // Iterator for the previous column indices
-            let mut cols_iter = (0..self.col_size).rev();
-            for ci in (0..new_col_size).rev() {
-                if ci == col_index {
-                    // Insert the new cell
-                    self.cells[ri * new_col_size + ci] = new_column.pop().unwrap_or_default();
-                    if new_column.is_empty() {
-                        // We can break early here because
-                        // its guaranteed that the rest of the cells in the
-                        // current row are already in place if the new column
-                        // is empty
-                        break;
-                    }
-                } else {
-                    // Only move the iterator when we're not inserting
-                    let old_ci = cols_iter.next().unwrap();
-                    // Move the cells from the old index to the new index
-                    self.cells[ri * new_col_size + ci] =
-                        std::mem::take(&mut self.cells[ri * old_col_size + old_ci]);
-                }
+        if self.cells.capacity() < new_size {
+            // Move the cells into a larger buffer row by row, inserting the new cells on the way,
+            // rather than growing the buffer first and then shifting every cell again
+            let mut old_cells = core::mem::take(&mut self.cells).into_iter();
+            let mut cells = Vec::with_capacity(new_size);
+            for new_cell in new_column {
+                cells.extend(old_cells.by_ref().take(col_index));
+                cells.push(new_cell);
+                cells.extend(old_cells.by_ref().take(old_col_size - col_index));
+            }
+            self.cells = cells;
+        } else {
+            // Shift the rows within the reserved room, from the last row to the first,
+            // so no cell is moved onto a cell that still has to be moved
+            let mut new_column: Vec<Cell> = new_column.collect();
+            self.cells.resize(new_size, Cell::default());
+            for ri in (0..self.row_size).rev() {
+                let row_start = ri * old_col_size;
+                // Every row above this one and the new cell push the cells after the insertion point
+                self.shift_cells(row_start + col_index..row_start + old_col_size, ri + 1);
+                self.cells[ri * new_col_size + col_index] = new_column.pop().unwrap_or_default();
+                self.shift_cells(row_start..row_start + col_index, ri);
             }
         }
 
         self.col_size = new_col_size;
+        // Merged, typed, and validated columns after the new one moved to the right
+        let shifted = |col: usize| if col >= col_index { col + 1 } else { col };
+        self.merged_cols = self.merged_cols.iter().map(|&merged_col| shifted(merged_col)).collect();
+        self.col_types = self.col_types.iter().map(|(&typed_col, &col_type)| (shifted(typed_col), col_type)).collect();
+        self.col_validators.0 = core::mem::take(&mut self.col_validators.0).into_iter()
+            .map(|(validated_col, validator)| (shifted(validated_col), validator))
+            .collect();
     }
 
+    /// Moves a range of cells to the right by the given offset.
+    /// 
+    /// The cells at the destination are moved into the vacated range,
+    /// so they should be placeholders.
+
+    fn shift_cells(
+        &mut self,
+        range: Range<usize>,
+        offset: usize,
+    )
+    {
+        if offset == 0 {
+            return;
+        }
+        for index in range.rev() {
+            self.cells.swap(index, index + offset);
+        }
+    }
 
     /// Try to insert a new column at the specified column index.
     /// Existing columns to the right of the inserted column will be shifted
@@ -351,7 +1001,8 @@ impl Grid {
             return Err(GridError::ColIndexOutOfBounds);
         }
 
-        Ok(self.insert_col(col_index, new_column))
+        self.insert_col(col_index, new_column);
+        Ok(())
     }
 
     /// Insert a new row at the specified row index.
@@ -374,6 +1025,8 @@ impl Grid {
         if row_index > self.row_size {
             panic!("{}", ErrorMessage::ROW_INDEX_OUT_OF_BOUNDS);
         }
+        self.touch_rows_from(row_index);
+        self.record_edit(|_| Edit::RemoveRow { row_index });
 
         let mut new_row: Vec<Cell> = new_row.into_iter().map(Into::into).collect();
 
@@ -406,12 +1059,13 @@ impl Grid {
                     let old_ri = rows_iter.next().unwrap();
                     // Move the cells from the old index to the new index
                     self.cells[ri * self.col_size + ci] =
-                        std::mem::take(&mut self.cells[old_ri * self.col_size + ci]);
+                        core::mem::take(&mut self.cells[old_ri * self.col_size + ci]);
                 }
             }
         }
 
         self.row_size = new_row_size;
+        self.tree.insert_row(row_index);
     }
 
     /// Try to insert a new row at the specified row index.
@@ -435,7 +1089,104 @@ impl Grid {
             return Err(GridError::RowIndexOutOfBounds);
         }
 
-        Ok(self.insert_row(row_index, new_row))
+        self.insert_row(row_index, new_row);
+        Ok(())
+    }
+
+    /// Append a new row below the last row.
+    /// 
+    /// Unlike inserting a row, no existing cells are moved,
+    /// so building a grid row by row takes time proportional to its size.
+    /// 
+    /// If the new row has fewer cells than the number of columns in the grid,
+    /// the remaining cells will be filled with the default cell.
+    /// 
+    /// If the new row has more cells than the number of columns in the grid,
+    /// the excess cells will be truncated.
+
+    pub fn push_row(
+        &mut self,
+        new_row: impl IntoIterator<Item = impl Into<Cell>>,
+    )
+    {
+        self.record_edit(|grid| Edit::RemoveRow { row_index: grid.row_size });
+        self.touch_row(self.row_size);
+        let new_row = new_row.into_iter()
+            .map(Into::into)
+            .chain(core::iter::repeat_with(Cell::default))
+            .take(self.col_size);
+        self.cells.extend(new_row);
+        self.row_size += 1;
+    }
+
+    /// Remove the row at the specified row index.
+    /// Existing rows below the removed row will be shifted upwards.
+    ///
+    /// Panics if the row index is out of bounds.
+    ///
+    /// The footer keeps its number of rows, as when inserting rows.
+    ///
+    /// # Returns
+    ///
+    /// The cells of the removed row
+
+    pub fn remove_row(
+        &mut self,
+        row_index: usize,
+    ) -> Vec<Cell>
+    {
+        if row_index >= self.row_size {
+            panic!("{}", ErrorMessage::ROW_INDEX_OUT_OF_BOUNDS);
+        }
+        self.record_edit(|grid| Edit::InsertRow { row_index, cells: grid.row_iter(row_index).cloned().collect() });
+        self.remove_row_cells(row_index)
+    }
+
+    /// Remove the column at the specified column index.
+    /// Existing columns to the right of the removed column will be shifted to the left,
+    /// along with their merging, type, and validator.
+    ///
+    /// Panics if the column index is out of bounds.
+    ///
+    /// # Returns
+    ///
+    /// The cells of the removed column
+
+    pub fn remove_col(
+        &mut self,
+        col_index: usize,
+    ) -> Vec<Cell>
+    {
+        if col_index >= self.col_size {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+        self.record_edit(|grid| Edit::InsertCol { col_index, cells: grid.col_iter(col_index).cloned().collect() });
+        self.remove_col_cells(col_index)
+    }
+
+    /// Reserve room for at least the specified number of additional rows.
+    /// 
+    /// Adding that many rows afterwards does not reallocate the cells,
+    /// which avoids repeated reallocations when loading large datasets row by row.
+
+    pub fn reserve_rows(
+        &mut self,
+        additional_rows: usize,
+    )
+    {
+        self.cells.reserve(additional_rows * self.col_size);
+    }
+
+    /// Reserve room for at least the specified number of additional columns.
+    /// 
+    /// Inserting that many columns afterwards does not reallocate the cells.
+
+    pub fn reserve_cols(
+        &mut self,
+        additional_cols: usize,
+    )
+    {
+        self.cells.reserve(additional_cols * self.row_size);
     }
 
     /// Set the entire column at the specified index.
@@ -457,6 +1208,7 @@ impl Grid {
         if col_index >= self.col_size {
             panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
         }
+        self.record_edit(|grid| Edit::SetCol { col_index, cells: grid.col_iter(col_index).cloned().collect() });
 
         let mut new_column_iter = new_column.into_iter();
 
@@ -488,7 +1240,38 @@ impl Grid {
             return Err(GridError::ColIndexOutOfBounds);
         }
 
-        Ok(self.set_col(col_index, new_column))
+        self.set_col(col_index, new_column);
+        Ok(())
+    }
+
+    /// Set the entire column at the specified index from a vector.
+    /// 
+    /// Panics if the index is out of bounds.
+    /// 
+    /// The cells are moved into the column in a single pass, without converting them
+    /// one by one as `set_col` does.
+    /// 
+    /// If the vector has fewer cells than the number of rows in the grid,
+    /// the remaining cells will be filled with the default cell.
+    /// 
+    /// If the vector has more cells than the number of rows in the grid,
+    /// the excess cells will be truncated.
+
+    pub fn set_col_vec(
+        &mut self,
+        col_index: usize,
+        mut new_column: Vec<Cell>,
+    )
+    {
+        if col_index >= self.col_size {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+        self.record_edit(|grid| Edit::SetCol { col_index, cells: grid.col_iter(col_index).cloned().collect() });
+
+        new_column.resize_with(self.row_size, Cell::default);
+        for (cell, new_cell) in self.col_iter_mut(col_index).zip(new_column) {
+            *cell = new_cell;
+        }
     }
 
     /// Set the entire row at the specified index.
@@ -509,6 +1292,7 @@ impl Grid {
         if row_index >= self.row_size {
             panic!("{}", ErrorMessage::ROW_INDEX_OUT_OF_BOUNDS);
         }
+        self.record_edit(|grid| Edit::SetRow { row_index, cells: grid.row_iter(row_index).cloned().collect() });
 
         let mut new_row_iter = new_row.into_iter();
 
@@ -540,7 +1324,38 @@ impl Grid {
             return Err(GridError::RowIndexOutOfBounds);
         }
 
-        Ok(self.set_row(row_index, new_row))
+        self.set_row(row_index, new_row);
+        Ok(())
+    }
+
+    /// Set the entire row at the specified index from a vector.
+    /// 
+    /// Panics if the index is out of bounds.
+    /// 
+    /// The cells are swapped into the row as a single slice, without converting them
+    /// one by one as `set_row` does.
+    /// 
+    /// If the vector has fewer cells than the number of columns in the grid,
+    /// the remaining cells will be filled with the default cell.
+    /// 
+    /// If the vector has more cells than the number of columns in the grid,
+    /// the excess cells will be truncated.
+
+    pub fn set_row_vec(
+        &mut self,
+        row_index: usize,
+        mut new_row: Vec<Cell>,
+    )
+    {
+        if row_index >= self.row_size {
+            panic!("{}", ErrorMessage::ROW_INDEX_OUT_OF_BOUNDS);
+        }
+        self.record_edit(|grid| Edit::SetRow { row_index, cells: grid.row_iter(row_index).cloned().collect() });
+
+        self.touch_row(row_index);
+        new_row.resize_with(self.col_size, Cell::default);
+        let start = row_index * self.col_size;
+        self.cells[start..start + self.col_size].swap_with_slice(&mut new_row);
     }
 
     /// Resize the grid to the specified number of rows and columns.
@@ -557,99 +1372,338 @@ impl Grid {
         new_col_size: usize,
     )
     {
+        let (old_row_size, old_col_size) = (self.row_size, self.col_size);
+        let discarded = self.resize_cells(new_row_size, new_col_size);
+        self.record_edit(|_| Edit::Resize { row_size: old_row_size, col_size: old_col_size, cells: discarded });
+    }
+
+    /// Resizes the grid as `resize` does, without recording it.
+    ///
+    /// # Returns
+    ///
+    /// The discarded cells that are not empty, with their indices in row-major order before resizing,
+    /// or no cells if the history is disabled
+
+    pub(crate) fn resize_cells(
+        &mut self,
+        new_row_size: usize,
+        new_col_size: usize,
+    ) -> Vec<(usize, Cell)>
+    {
+        self.touch_rows_from(if new_col_size == self.col_size { self.row_size } else { 0 });
         let old_row_size = self.row_size;
         let old_col_size = self.col_size;
 
         let mut new_cells = vec![Cell::default(); new_row_size * new_col_size];
 
-        let rows = std::cmp::min(old_row_size, new_row_size);
-        let cols = std::cmp::min(old_col_size, new_col_size);
+        let rows = core::cmp::min(old_row_size, new_row_size);
+        let cols = core::cmp::min(old_col_size, new_col_size);
 
         for row_index in 0..rows {
             for col_index in 0..cols {
                 new_cells[row_index * new_col_size + col_index] =
-                    std::mem::take(&mut self.cells[row_index * old_col_size + col_index]);
+                    core::mem::take(&mut self.cells[row_index * old_col_size + col_index]);
             }
         }
 
+        // The cells kept were taken, so the cells left that are not empty were discarded
+        let discarded = if self.is_history_enabled() {
+            core::mem::take(&mut self.cells).into_iter()
+                .enumerate()
+                .filter(|(_, cell)| *cell != Cell::default())
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         self.cells = new_cells;
         self.row_size = new_row_size;
         self.col_size = new_col_size;
+        self.merged_cols.retain(|&merged_col| merged_col < new_col_size);
+        self.col_types.retain(|&typed_col, _| typed_col < new_col_size);
+        self.col_validators.0.retain(|&validated_col, _| validated_col < new_col_size);
+        self.selection.retain_in(new_row_size, new_col_size);
+        self.tree.truncate(new_row_size);
+        discarded
     }
 
     /// Removes all cells from the grid.
     /// 
-    /// The grid will have zero rows and zero columns after this operation.
+    /// The grid will have zero rows and zero columns after this operation,
+    /// and no footer, merged columns, column types, or validators.
 
     pub fn clear(
         &mut self
     )
     {
+        self.record_edit(Edit::replace_all);
+        self.touch_rows_from(0);
         self.cells.clear();
         self.row_size = 0;
         self.col_size = 0;
+        self.footer_rows = 0;
+        self.merged_cols.clear();
+        self.col_types.clear();
+        self.col_validators.0.clear();
+        self.selection.clear();
+        self.tree.clear();
+    }
+
+    /// Checks whether two grids have the same size and hold the same content in every cell,
+    /// as compared by `Cell::content_eq`, e.g. to check the data of a grid in a test.
+    ///
+    /// All formatting is ignored, along with the footer rows, merged columns, column types,
+    /// and the other settings that `==` compares.
+
+    pub fn content_eq(
+        &self,
+        other: &Grid,
+    ) -> bool
+    {
+        self.row_size == other.row_size
+            && self.col_size == other.col_size
+            && self.cells.iter().zip(&other.cells).all(|(cell, other_cell)| cell.content_eq(other_cell))
+    }
+
+    /// Formats the first row as a header, in bold and centered.
+
+    pub(crate) fn style_header_row(
+        &mut self
+    )
+    {
+        for cell in self.row_iter_mut(0) {
+            cell.set_style(crate::FontStyle::Bold);
+            cell.set_align(crate::Align::Center);
+        }
     }
 
 }
 
 
-impl Display for Grid {
+impl Grid {
 
-    fn fmt(
+    /// Formats a layout window of the grid, as done by the `Display` implementation.
+    ///
+    /// `{:#}` selects the compact form without borders, and a width such as `{:^80}`
+    /// pads or aligns the whole table as a block.
+
+    pub(crate) fn fmt_layout(
         &self,
         f: &mut Formatter,
+        layout: Layout,
     ) -> Result<(), Error>
     {
-        let row_heights: Vec<usize> =
-            (0..self.row_size).map(|row_index|
-                (0..self.col_size).map(|col_index|
-                    self.get_cell(row_index, col_index)
-                        .unwrap_or(&Cell::default())
-                        .height()
-                )
-                .max().unwrap_or(0)
-            )
-            .collect();
-        let col_widths: Vec<usize> =
-            (0..self.col_size).map(|col_index|
-                (0..self.row_size).map(|row_index|
-                    self.get_cell(row_index, col_index)
-                        .unwrap_or(&Cell::default())
-                        .width()
-                )
-                .max().unwrap_or(0)
-            )
-            .collect();
+        let mut options = self.default_options();
+        // `{:#}` selects the compact form without borders
+        if f.alternate() {
+            options.set_border_style(BorderStyle::None);
+        }
 
-        let top_border = Border::render_top_border(&col_widths);
-        let mid_border = Border::render_mid_border(&col_widths);
-        let bot_border = Border::render_bot_border(&col_widths);
-
-        writeln!(f, "{}", &top_border)?;
-        for row_index in 0..self.row_size {
-            let mut lines = Vec::with_capacity(self.col_size);
-            for col_index in 0..self.col_size {
-                if let Some(cell) = self.get_cell(row_index, col_index) {
-                    let rendered_lines = cell.render_lines(row_heights[row_index], col_widths[col_index]);
-                    lines.push(VecDeque::from(rendered_lines));
-                } else {
-                    lines.push(VecDeque::new());
-                }
+        let mut prefixes = AnsiPrefixes::new();
+        let Some(width) = f.width() else {
+            return self.write_segments(&layout, &options, |segment| write_segment(f, segment, &mut prefixes));
+        };
+
+        // Pad or align the whole table as a block within the requested width.
+        // The lines are measured in a first pass, so the table is still streamed
+        // into the formatter without holding its lines in memory
+        let layout = layout.constrain(&options);
+        let mut table_width = 0;
+        let mut line_width = 0;
+        self.write_segments(&layout, &options, |segment| {
+            if let Segment::LineEnd = segment {
+                table_width = table_width.max(line_width);
+                line_width = 0;
             }
-            for _ in 0..row_heights[row_index] {
-                let row_line: Vec<String> = lines.iter_mut()
-                    .filter_map(|line| line.pop_front())
-                    .collect();
-                let row_str = Border::render_row_lines(row_line);
-                writeln!(f, "{}", row_str)?;
+            line_width += segment.visible_width();
+            Ok::<(), Error>(())
+        })?;
+
+        let space = width.saturating_sub(table_width);
+        let left = match f.align() {
+            Some(Alignment::Right)  => space,
+            Some(Alignment::Center) => space / 2,
+            Some(Alignment::Left) | None => 0,
+        };
+        let fill = f.fill();
+        let write_fill = |f: &mut Formatter, count: usize| -> Result<(), Error> {
+            (0..count).try_for_each(|_| f.write_char(fill))
+        };
+
+        let mut line_started = false;
+        self.write_segments(&layout, &options, |segment| {
+            if !line_started {
+                write_fill(f, left)?;
+                line_started = true;
             }
-            if row_index < self.row_size - 1 {
-                writeln!(f, "{}", &mid_border)?;
+            if let Segment::LineEnd = segment {
+                write_fill(f, width.saturating_sub(left + line_width))?;
+                line_width = 0;
+                line_started = false;
             }
-        }
-        writeln!(f, "{}", &bot_border)?;
+            line_width += segment.visible_width();
+            write_segment(f, segment, &mut prefixes)
+        })?;
         Ok(())
     }
+
+}
+
+impl Display for Grid {
+
+    fn fmt(
+        &self,
+        f: &mut Formatter,
+    ) -> Result<(), Error>
+    {
+        self.fmt_layout(f, self.layout())
+    }
     
 }
 
+impl Index<(usize, usize)> for Grid {
+
+    type Output = Cell;
+
+    /// Gets the cell at a `(row, col)` position, e.g. `grid[(0, 1)]`.
+    ///
+    /// Panics if the indices are out of bounds; use `get_cell` to get None instead.
+
+    fn index(
+        &self,
+        (row_index, col_index): (usize, usize),
+    ) -> &Cell
+    {
+        self.check_cell_index(row_index, col_index);
+        &self.cells[row_index * self.col_size + col_index]
+    }
+
+}
+
+impl IndexMut<(usize, usize)> for Grid {
+
+    /// Gets the cell at a `(row, col)` position to change it, e.g. `grid[(0, 1)].set_color(Color::RED)`.
+    ///
+    /// Panics if the indices are out of bounds. As with `get_cell_mut`, the change is not recorded
+    /// in the history, but the row is marked as dirty.
+
+    fn index_mut(
+        &mut self,
+        (row_index, col_index): (usize, usize),
+    ) -> &mut Cell
+    {
+        self.check_cell_index(row_index, col_index);
+        self.touch_row(row_index);
+        &mut self.cells[row_index * self.col_size + col_index]
+    }
+
+}
+
+/// Number of rows shown in the `Debug` preview of the cell data.
+const DEBUG_PREVIEW_ROWS  : usize = 8;
+/// Number of columns shown in the `Debug` preview of the cell data.
+const DEBUG_PREVIEW_COLS  : usize = 8;
+/// Number of characters shown for each cell in the `Debug` preview of the cell data.
+const DEBUG_PREVIEW_CHARS : usize = 16;
+
+impl Debug for Grid {
+
+    /// Formats the grid's dimensions, computed column widths and row heights,
+    /// and a truncated preview of the cell data, row by row.
+    /// Use `{:#?}` to print every preview row on its own line.
+
+    fn fmt(
+        &self,
+        f: &mut Formatter,
+    ) -> Result<(), Error>
+    {
+        f.debug_struct("Grid")
+            .field("row_size", &self.row_size)
+            .field("col_size", &self.col_size)
+            .field("col_widths", &self.col_widths())
+            .field("row_heights", &self.row_heights())
+            .field("cells", &DebugPreview(self))
+            .finish()
+    }
+
+}
+
+/// Internal utility struct for formatting a truncated preview of the cell data.
+
+struct DebugPreview<'a>(&'a Grid);
+
+impl Debug for DebugPreview<'_> {
+
+    fn fmt(
+        &self,
+        f: &mut Formatter,
+    ) -> Result<(), Error>
+    {
+        let grid = self.0;
+        let mut list = f.debug_list();
+        for row_index in 0..grid.row_size.min(DEBUG_PREVIEW_ROWS) {
+            list.entry(&DebugPreviewRow(grid, row_index));
+        }
+        if grid.row_size > DEBUG_PREVIEW_ROWS {
+            list.entry(&format_args!("... {} more rows", grid.row_size - DEBUG_PREVIEW_ROWS));
+        }
+        list.finish()
+    }
+
+}
+
+/// Internal utility struct for formatting a truncated preview of a single row.
+
+struct DebugPreviewRow<'a>(&'a Grid, usize);
+
+impl Debug for DebugPreviewRow<'_> {
+
+    fn fmt(
+        &self,
+        f: &mut Formatter,
+    ) -> Result<(), Error>
+    {
+        let DebugPreviewRow(grid, row_index) = *self;
+        let mut list = f.debug_list();
+        for cell in grid.row_iter(row_index).take(DEBUG_PREVIEW_COLS) {
+            let data = cell.get_data();
+            if data.chars().count() > DEBUG_PREVIEW_CHARS {
+                let preview: String = data.chars().take(DEBUG_PREVIEW_CHARS).collect();
+                list.entry(&format_args!("{:?}...", preview));
+            } else {
+                list.entry(&data);
+            }
+        }
+        if grid.col_size > DEBUG_PREVIEW_COLS {
+            list.entry(&format_args!("... {} more columns", grid.col_size - DEBUG_PREVIEW_COLS));
+        }
+        list.finish()
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::Grid;
+
+    #[test]
+    fn get_cell_rejects_columns_past_the_last_one() {
+        let mut grid = Grid::from([["a", "b"], ["c", "d"]]);
+        assert_eq!(grid.get_cell(0, 1).map(|cell| cell.get_data()), Some("b"));
+        assert!(grid.get_cell(0, 2).is_none());
+        assert!(grid.get_cell_mut(0, 2).is_none());
+        assert!(grid.get_cell(2, 0).is_none());
+    }
+
+    #[test]
+    fn clear_resets_the_footer() {
+        let mut grid = Grid::from([["a"], ["b"], ["total"]]);
+        grid.set_footer_rows(1);
+        grid.clear();
+        grid.push_row(["c"]);
+        grid.push_row(["d"]);
+        assert_eq!(grid.footer_rows(), 0);
+    }
+
+}
@@ -0,0 +1,516 @@
+use crate::cell::Cell;
+use crate::grid::Grid;
+
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem::size_of;
+
+/// The edits recorded since `Grid::enable_history`, to undo and redo them.
+///
+/// Every edit is stored as the edit reversing it, holding the cells it replaced,
+/// so only the changed cells are kept rather than copies of the whole grid.
+/// The history never affects equality, so two grids with the same cells are always equal.
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct History {
+    enabled: bool,
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+}
+
+/// An edit of the cells of a grid, which is applied to the grid to reverse an earlier edit.
+
+#[derive(Debug, Clone)]
+pub(crate) enum Edit {
+    /// Puts a cell back in place.
+    SetCell { row_index: usize, col_index: usize, cell: Cell },
+    /// Puts the cells of a row back in place.
+    SetRow { row_index: usize, cells: Vec<Cell> },
+    /// Puts the cells of a column back in place.
+    SetCol { col_index: usize, cells: Vec<Cell> },
+    /// Removes an inserted row.
+    RemoveRow { row_index: usize },
+    /// Inserts a removed row back.
+    InsertRow { row_index: usize, cells: Vec<Cell> },
+    /// Removes an inserted column.
+    RemoveCol { col_index: usize },
+    /// Inserts a removed column back.
+    InsertCol { col_index: usize, cells: Vec<Cell> },
+    /// Puts back the cells that changed, by index in row-major order.
+    SetCells { cells: Vec<(usize, Cell)> },
+    /// Resizes the grid back, and puts back the cells that resizing it discarded,
+    /// by index in row-major order at the restored size.
+    Resize { row_size: usize, col_size: usize, cells: Vec<(usize, Cell)> },
+    /// Moves the rows back, where `order[i]` is the current index of the row to put at `i`.
+    ReorderRows { order: Vec<usize> },
+    /// Moves the columns back, where `order[i]` is the current index of the column to put at `i`.
+    ReorderCols { order: Vec<usize> },
+    /// Puts back the number of footer rows.
+    SetFooterRows { footer_rows: usize },
+    /// Reverses the edits of a single operation made of several edits, from the last one.
+    Batch(Vec<Edit>),
+    /// Puts back every cell, the size of the grid, and its footer, after a change of the whole grid.
+    Replace { cells: Vec<Cell>, row_size: usize, col_size: usize, footer_rows: usize },
+}
+
+impl Edit {
+
+    /// Gets the number of heap bytes used by the edit, without the edit itself,
+    /// with the text of the cells it holds counted in `shared` like `Cell::heap_usage`.
+
+    fn heap_bytes(
+        &self,
+        shared: &mut BTreeSet<usize>,
+    ) -> usize
+    {
+        match self {
+            Edit::SetCell { cell, .. } => cell_heap_bytes(core::iter::once(cell), shared),
+            Edit::SetRow { cells, .. } | Edit::SetCol { cells, .. } | Edit::InsertRow { cells, .. } | Edit::InsertCol { cells, .. } | Edit::Replace { cells, .. } => {
+                cells.capacity() * size_of::<Cell>() + cell_heap_bytes(cells.iter(), shared)
+            }
+            Edit::SetCells { cells } | Edit::Resize { cells, .. } => {
+                cells.capacity() * size_of::<(usize, Cell)>() + cell_heap_bytes(cells.iter().map(|(_, cell)| cell), shared)
+            }
+            Edit::ReorderRows { order } | Edit::ReorderCols { order } => order.capacity() * size_of::<usize>(),
+            Edit::Batch(edits) => {
+                edits.capacity() * size_of::<Edit>() + edits.iter().map(|edit| edit.heap_bytes(shared)).sum::<usize>()
+            }
+            Edit::RemoveRow { .. } | Edit::RemoveCol { .. } | Edit::SetFooterRows { .. } => 0,
+        }
+    }
+
+    /// Creates the edit putting back every cell of the grid, before the whole grid changes.
+
+    pub(crate) fn replace_all(
+        grid: &Grid,
+    ) -> Edit
+    {
+        Edit::Replace {
+            cells: grid.flat_iter().cloned().collect(),
+            row_size: grid.row_size(),
+            col_size: grid.col_size(),
+            footer_rows: grid.footer_rows(),
+        }
+    }
+
+    /// Applies the edit to the grid, and returns the edit reversing it.
+
+    fn apply(
+        self,
+        grid: &mut Grid,
+    ) -> Edit
+    {
+        match self {
+            Edit::SetCell { row_index, col_index, cell } => {
+                let old = grid.get_cell_mut(row_index, col_index)
+                    .map(|old| core::mem::replace(old, cell))
+                    .unwrap_or_default();
+                Edit::SetCell { row_index, col_index, cell: old }
+            },
+            Edit::SetRow { row_index, cells } => {
+                let old = grid.row_iter_mut(row_index)
+                    .zip(cells)
+                    .map(|(old, cell)| core::mem::replace(old, cell))
+                    .collect();
+                Edit::SetRow { row_index, cells: old }
+            },
+            Edit::SetCol { col_index, cells } => {
+                let old = grid.col_iter_mut(col_index)
+                    .zip(cells)
+                    .map(|(old, cell)| core::mem::replace(old, cell))
+                    .collect();
+                Edit::SetCol { col_index, cells: old }
+            },
+            Edit::RemoveRow { row_index } => {
+                let cells = grid.remove_row_cells(row_index);
+                Edit::InsertRow { row_index, cells }
+            },
+            Edit::InsertRow { row_index, cells } => {
+                grid.insert_row(row_index, cells);
+                Edit::RemoveRow { row_index }
+            },
+            Edit::RemoveCol { col_index } => {
+                let cells = grid.remove_col_cells(col_index);
+                Edit::InsertCol { col_index, cells }
+            },
+            Edit::InsertCol { col_index, cells } => {
+                grid.insert_col(col_index, cells);
+                Edit::RemoveCol { col_index }
+            },
+            Edit::SetCells { cells } => {
+                Edit::SetCells { cells: grid.replace_cells(cells) }
+            },
+            Edit::Resize { row_size, col_size, cells } => {
+                let (old_row_size, old_col_size) = (grid.row_size(), grid.col_size());
+                let discarded = grid.resize_cells(row_size, col_size);
+                grid.replace_cells(cells);
+                Edit::Resize { row_size: old_row_size, col_size: old_col_size, cells: discarded }
+            },
+            Edit::ReorderRows { order } => {
+                grid.move_rows(&order);
+                Edit::ReorderRows { order: inverse_order(&order) }
+            },
+            Edit::ReorderCols { order } => {
+                grid.move_cols(&order);
+                Edit::ReorderCols { order: inverse_order(&order) }
+            },
+            Edit::SetFooterRows { footer_rows } => {
+                let old = grid.footer_rows();
+                grid.set_footer_rows(footer_rows);
+                Edit::SetFooterRows { footer_rows: old }
+            },
+            Edit::Batch(edits) => {
+                // The reversed edits are applied from the last one too, so they are kept in this order
+                Edit::Batch(edits.into_iter().rev().map(|edit| edit.apply(grid)).collect())
+            },
+            Edit::Replace { cells, row_size, col_size, footer_rows } => {
+                let old = Edit::replace_all(grid);
+                grid.resize(row_size, col_size);
+                grid.set_cells_vec(cells);
+                grid.set_footer_rows(footer_rows);
+                old
+            },
+        }
+    }
+
+}
+
+/// Inverts an order of rows or columns, where `order[i]` is the index moved to `i`,
+/// into the order moving them back.
+
+pub(crate) fn inverse_order(
+    order: &[usize],
+) -> Vec<usize>
+{
+    let mut inverse = vec![0; order.len()];
+    for (index, &old_index) in order.iter().enumerate() {
+        inverse[old_index] = index;
+    }
+    inverse
+}
+
+/// Gets the number of heap bytes used by the text of cells, counted in `shared` like `Cell::heap_usage`.
+
+fn cell_heap_bytes<'a>(
+    cells: impl Iterator<Item = &'a Cell>,
+    shared: &mut BTreeSet<usize>,
+) -> usize
+{
+    cells.map(|cell| {
+        let (used, spare) = cell.heap_usage(shared);
+        used + spare
+    }).sum()
+}
+
+impl History {
+
+    /// Gets the number of heap bytes used by the undo and redo stacks,
+    /// with the text of the cells they hold counted in `shared` like `Cell::heap_usage`.
+
+    pub(crate) fn heap_bytes(
+        &self,
+        shared: &mut BTreeSet<usize>,
+    ) -> usize
+    {
+        (self.undo.capacity() + self.redo.capacity()) * size_of::<Edit>()
+            + self.undo.iter().chain(&self.redo).map(|edit| edit.heap_bytes(shared)).sum::<usize>()
+    }
+
+}
+
+impl PartialEq for History {
+
+    fn eq(
+        &self,
+        _other: &Self,
+    ) -> bool
+    {
+        true
+    }
+
+}
+
+impl Eq for History {}
+
+impl Grid {
+
+    /// Starts recording the edits of the cells, so they can be undone and redone,
+    /// e.g. in an interactive table editor.
+    ///
+    /// The edits made by setting cells, rows, columns, or all cells, inserting, pushing, and removing
+    /// rows and columns, resizing, clearing, sorting, and setting the footer rows are recorded,
+    /// along with the other operations built on them, such as `add_summary_row`, which is undone at once.
+    /// Edits made through mutable references, such as `get_cell_mut` or `row_iter_mut`, are not recorded,
+    /// and neither are merged columns, column types, validators, or parent rows.
+    /// Recording an edit keeps the cells it replaced, or how the rows or columns moved for a sort or a reorder,
+    /// so memory grows with every edit until the history is cleared.
+
+    pub fn enable_history(
+        &mut self
+    )
+    {
+        self.history_mut().enabled = true;
+    }
+
+    /// Stops recording edits and discards the recorded ones.
+
+    pub fn disable_history(
+        &mut self
+    )
+    {
+        *self.history_mut() = History::default();
+    }
+
+    /// Returns true if edits are being recorded.
+
+    pub fn is_history_enabled(
+        &self
+    ) -> bool
+    {
+        self.history().enabled
+    }
+
+    /// Discards the recorded edits, keeping the recording enabled if it is.
+
+    pub fn clear_history(
+        &mut self
+    )
+    {
+        let history = self.history_mut();
+        history.undo.clear();
+        history.redo.clear();
+    }
+
+    /// Returns true if there is a recorded edit to undo.
+
+    pub fn can_undo(
+        &self
+    ) -> bool
+    {
+        !self.history().undo.is_empty()
+    }
+
+    /// Returns true if there is an undone edit to redo.
+
+    pub fn can_redo(
+        &self
+    ) -> bool
+    {
+        !self.history().redo.is_empty()
+    }
+
+    /// Reverses the last recorded edit.
+    ///
+    /// # Returns
+    ///
+    /// True if an edit was undone, false if there was none
+
+    pub fn undo(
+        &mut self
+    ) -> bool
+    {
+        let Some(edit) = self.history_mut().undo.pop() else {
+            return false;
+        };
+        let redo = self.apply_unrecorded(edit);
+        self.history_mut().redo.push(redo);
+        true
+    }
+
+    /// Makes the last undone edit again.
+    ///
+    /// Recording a new edit discards the edits that can be redone.
+    ///
+    /// # Returns
+    ///
+    /// True if an edit was redone, false if there was none
+
+    pub fn redo(
+        &mut self
+    ) -> bool
+    {
+        let Some(edit) = self.history_mut().redo.pop() else {
+            return false;
+        };
+        let undo = self.apply_unrecorded(edit);
+        self.history_mut().undo.push(undo);
+        true
+    }
+
+    /// Records an edit reversing a change, created from the grid, if the history is enabled.
+
+    pub(crate) fn record_edit(
+        &mut self,
+        edit: impl FnOnce(&Grid) -> Edit,
+    )
+    {
+        if !self.history().enabled {
+            return;
+        }
+        let edit = edit(self);
+        let history = self.history_mut();
+        history.undo.push(edit);
+        history.redo.clear();
+    }
+
+    /// Runs an operation rearranging the whole grid, such as a sort, recording it as a single edit
+    /// even if it moves the cells out of the grid and back in several steps.
+
+    pub(crate) fn record_replace<R>(
+        &mut self,
+        operation: impl FnOnce(&mut Grid) -> R,
+    ) -> R
+    {
+        self.record_edit(Edit::replace_all);
+        let history = core::mem::take(self.history_mut());
+        let result = operation(self);
+        *self.history_mut() = history;
+        result
+    }
+
+    /// Runs an operation made of several recorded edits, such as adding a row and growing the footer,
+    /// recording them as a single edit.
+
+    pub(crate) fn record_batch<R>(
+        &mut self,
+        operation: impl FnOnce(&mut Grid) -> R,
+    ) -> R
+    {
+        let start = self.history().undo.len();
+        let result = operation(self);
+        let history = self.history_mut();
+        if history.undo.len() > start + 1 {
+            let edits = history.undo.split_off(start);
+            history.undo.push(Edit::Batch(edits));
+        }
+        result
+    }
+
+    /// Applies an edit without recording it, and returns the edit reversing it.
+
+    fn apply_unrecorded(
+        &mut self,
+        edit: Edit,
+    ) -> Edit
+    {
+        let history = core::mem::take(self.history_mut());
+        let reverse = edit.apply(self);
+        *self.history_mut() = history;
+        reverse
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Edit;
+    use crate::{Aggregate, Grid, SortType};
+
+    fn grid() -> Grid {
+        let mut grid = Grid::from([["b", "2"], ["c", "3"], ["a", "1"]]);
+        grid.enable_history();
+        grid
+    }
+
+    #[test]
+    fn undo_and_redo_cell_edits() {
+        let mut grid = grid();
+        let before = grid.clone();
+        grid.set_cell(0, 0, "x");
+        grid.insert_row(1, ["y", "9"]);
+        assert!(grid.undo() && grid.undo());
+        assert!(!grid.undo());
+        assert!(grid.content_eq(&before));
+        assert!(grid.redo() && grid.redo());
+        assert_eq!(grid.to_records(), [["x", "2"], ["y", "9"], ["c", "3"], ["a", "1"]]);
+        grid.set_cell(0, 0, "z");
+        assert!(!grid.can_redo());
+    }
+
+    #[test]
+    fn set_cells_keeps_only_the_changed_cells() {
+        let mut grid = grid();
+        grid.set_cells(["b", "2", "c", "4", "a", "1"]);
+        let Some(Edit::SetCells { cells }) = grid.history().undo.last() else {
+            panic!("set_cells should record the changed cells");
+        };
+        assert_eq!(cells.len(), 1);
+        assert_eq!(cells[0].0, 3);
+        grid.undo();
+        assert_eq!(grid.to_records()[1], ["c", "3"]);
+    }
+
+    #[test]
+    fn resizing_is_undone_with_the_discarded_cells() {
+        let mut grid = grid();
+        let before = grid.clone();
+        grid.resize(1, 1);
+        grid.resize(2, 3);
+        grid.set_cell(1, 2, "new");
+        let Some(Edit::Resize { cells, .. }) = grid.history().undo.first() else {
+            panic!("resize should record the discarded cells");
+        };
+        assert_eq!(cells.len(), 5);
+        assert!(grid.undo() && grid.undo() && grid.undo());
+        assert!(grid.content_eq(&before));
+        assert!(grid.redo() && grid.redo() && grid.redo());
+        assert_eq!(grid.to_records(), [["b", "", ""], ["", "", "new"]]);
+    }
+
+    #[test]
+    fn sorting_is_undone_from_the_order_of_the_rows() {
+        let mut grid = grid();
+        grid.sort_by_col_typed(1, SortType::Numeric);
+        assert!(matches!(grid.history().undo.last(), Some(Edit::ReorderRows { .. })));
+        assert_eq!(grid.to_records(), [["a", "1"], ["b", "2"], ["c", "3"]]);
+        grid.undo();
+        assert_eq!(grid.to_records(), [["b", "2"], ["c", "3"], ["a", "1"]]);
+        grid.redo();
+        assert_eq!(grid.to_records(), [["a", "1"], ["b", "2"], ["c", "3"]]);
+    }
+
+    #[test]
+    fn reordering_columns_is_undone_from_their_order() {
+        let mut grid = Grid::from([["a", "b", "c", "d"], ["1", "2", "3", "4"]]);
+        grid.enable_history();
+        grid.reorder_cols(&[2, 3, 0]);
+        assert!(matches!(grid.history().undo.last(), Some(Edit::ReorderCols { .. })));
+        assert_eq!(grid.to_records(), [["c", "d", "a", "b"], ["3", "4", "1", "2"]]);
+        grid.undo();
+        assert_eq!(grid.to_records(), [["a", "b", "c", "d"], ["1", "2", "3", "4"]]);
+        grid.redo();
+        assert_eq!(grid.to_records(), [["c", "d", "a", "b"], ["3", "4", "1", "2"]]);
+    }
+
+    #[test]
+    fn summary_rows_are_undone_with_the_footer() {
+        let mut grid = grid();
+        grid.add_summary_row(&[(1, Aggregate::Sum)]);
+        assert_eq!(grid.footer_rows(), 1);
+        assert!(grid.undo());
+        assert!(!grid.can_undo());
+        assert_eq!((grid.row_size(), grid.footer_rows()), (3, 0));
+        assert!(grid.redo());
+        assert_eq!((grid.row_size(), grid.footer_rows()), (4, 1));
+        assert_eq!(grid.to_records()[3], ["", "6"]);
+
+        grid.clear();
+        grid.undo();
+        assert_eq!((grid.row_size(), grid.footer_rows()), (4, 1));
+    }
+
+    #[test]
+    fn removed_rows_and_columns_are_put_back() {
+        let mut grid = grid();
+        let before = grid.clone();
+        assert_eq!(grid.remove_row(1).len(), 2);
+        assert_eq!(grid.remove_col(0).len(), 2);
+        assert_eq!(grid.to_records(), [["2"], ["1"]]);
+        grid.undo();
+        grid.undo();
+        assert!(grid.content_eq(&before));
+    }
+
+}
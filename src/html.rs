@@ -0,0 +1,166 @@
+use crate::cell::Cell;
+use crate::export::Exporter;
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+use crate::options::RenderOptions;
+use crate::render::Segment;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::format;
+use core::convert::Infallible;
+
+impl Grid {
+
+    /// Renders the grid as an HTML fragment, for web-based terminals and browser consoles.
+    ///
+    /// The table is laid out exactly like the `Display` output inside a `<pre class="flatgrid">`
+    /// element, and formatted cells are wrapped in `<span>` elements with inline CSS instead of
+    /// ANSI escape sequences. Text is HTML-escaped, so the fragment can be inserted as is.
+
+    pub fn render_html_fragment(
+        &self
+    ) -> String
+    {
+        self.export(&Html)
+    }
+
+    /// Renders the grid as an HTML fragment using the given render options.
+    ///
+    /// Cells are styled unless the color mode is `ColorMode::Never`.
+
+    pub fn render_html_fragment_with(
+        &self,
+        options: &RenderOptions,
+    ) -> String
+    {
+        let layout = self.layout().constrain(options);
+        let mut output = String::from("<pre class=\"flatgrid\">");
+        let _ = self.write_segments(&layout, options, |segment| -> Result<(), Infallible> {
+            match segment {
+                Segment::Text(text) => push_escaped(&mut output, text),
+                Segment::Spaces(count) => output.extend(core::iter::repeat_n(' ', count)),
+                Segment::Styled(text, cell) => {
+                    let style = css_style(cell);
+                    if style.is_empty() {
+                        push_escaped(&mut output, text);
+                    } else {
+                        output.push_str(&format!("<span style=\"{}\">", style));
+                        push_escaped(&mut output, text);
+                        output.push_str("</span>");
+                    }
+                },
+                Segment::LineEnd => output.push('\n'),
+            }
+            Ok(())
+        });
+        output.push_str("</pre>");
+        output
+    }
+
+}
+
+/// Exporter for HTML fragments, as produced by `Grid::render_html_fragment`.
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Html;
+
+impl Exporter for Html {
+
+    type Output = String;
+
+    fn export(
+        &self,
+        grid: &Grid,
+    ) -> String
+    {
+        grid.render_html_fragment_with(&grid.default_options())
+    }
+
+}
+
+/// Creates the inline CSS matching the cell's colors and font styles, or an empty string if it has none.
+///
+/// Reversed cells without explicit colors use the `Canvas` and `CanvasText` system colors,
+/// which follow the page's color scheme.
+
+fn css_style(
+    cell: &Cell,
+) -> String
+{
+    let styles = cell.font_style();
+    let has = |flag: FontStyle| styles.into_iter().any(|style| style == flag);
+    let hex = |(r, g, b): (u8, u8, u8)| format!("#{:02x}{:02x}{:02x}", r, g, b);
+
+    let mut fg = cell.fg_color().map(|color| hex(color.as_rgb()));
+    let mut bg = cell.bg_color().map(|color| hex(color.as_rgb()));
+    if has(FontStyle::Reverse) {
+        (fg, bg) = (
+            Some(bg.unwrap_or_else(|| String::from("Canvas"))),
+            Some(fg.unwrap_or_else(|| String::from("CanvasText"))),
+        );
+    }
+
+    let mut declarations: Vec<String> = Vec::new();
+    if let Some(fg) = fg {
+        declarations.push(format!("color:{}", fg));
+    }
+    if let Some(bg) = bg {
+        declarations.push(format!("background-color:{}", bg));
+    }
+    if has(FontStyle::Bold) {
+        declarations.push(String::from("font-weight:bold"));
+    }
+    if has(FontStyle::Dim) {
+        declarations.push(String::from("opacity:0.5"));
+    }
+    if has(FontStyle::Italic) {
+        declarations.push(String::from("font-style:italic"));
+    }
+    let decorations: Vec<&str> = [(FontStyle::Underline, "underline"), (FontStyle::Strike, "line-through")]
+        .into_iter()
+        .filter(|(flag, _)| has(*flag))
+        .map(|(_, decoration)| decoration)
+        .collect();
+    if !decorations.is_empty() {
+        declarations.push(format!("text-decoration:{}", decorations.join(" ")));
+    }
+    if has(FontStyle::Hidden) {
+        declarations.push(String::from("visibility:hidden"));
+    }
+    declarations.join(";")
+}
+
+/// Appends the text to the output with the HTML special characters escaped.
+
+fn push_escaped(
+    output: &mut String,
+    text: &str,
+)
+{
+    for c in text.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            _ => output.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Html;
+    use crate::{BorderStyle, Grid};
+
+    #[test]
+    fn exporter_renders_with_the_border_style_of_the_grid() {
+        let grid = Grid::from([["a", "<b>"]]).with_border(BorderStyle::Ascii);
+        let html = grid.export(&Html);
+        assert_eq!(html, grid.render_html_fragment());
+        assert!(html.contains("+---+") && html.contains("&lt;b&gt;"), "{html}");
+    }
+
+}
@@ -0,0 +1,250 @@
+use crate::cell::Cell;
+use crate::export::Exporter;
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+use crate::render::Segment;
+
+use ab_glyph::{Font, PxScale, ScaleFont, point};
+use image::{ImageFormat, ImageResult, Rgb, RgbImage};
+use std::convert::Infallible;
+use std::io::Cursor;
+
+/// Default background of the image, similar to a dark terminal theme.
+const DEFAULT_BACKGROUND : Rgb<u8> = Rgb([30, 30, 30]);
+/// Default text and border color of the image.
+const DEFAULT_FOREGROUND : Rgb<u8> = Rgb([220, 220, 220]);
+
+impl Grid {
+
+    /// Renders the grid into a PNG image, using a monospace font.
+    ///
+    /// The table is laid out exactly like the `Display` implementation and drawn on a
+    /// dark background, with cell colors and font styles applied.
+    /// Useful for posting tables where monospace alignment is not preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `font` - A monospace font supporting box-drawing characters,
+    ///   e.g. loaded with `ab_glyph::FontRef::try_from_slice`
+    /// * `scale` - The font size in pixels
+    ///
+    /// # Returns
+    ///
+    /// The encoded PNG bytes
+
+    pub fn to_png(
+        &self,
+        font: &impl Font,
+        scale: f32,
+    ) -> ImageResult<Vec<u8>>
+    {
+        self.export(&Png::new(font, scale))
+    }
+
+}
+
+/// Exporter for PNG images of the rendered table, as produced by `Grid::to_png`.
+
+#[derive(Debug, Clone, Copy)]
+pub struct Png<'a, F> {
+    font: &'a F,
+    scale: f32,
+}
+
+impl<'a, F: Font> Png<'a, F> {
+
+    /// Creates a PNG exporter using the given monospace font and font size in pixels.
+
+    pub fn new(
+        font: &'a F,
+        scale: f32,
+    ) -> Self
+    {
+        Png { font, scale }
+    }
+
+}
+
+impl<F: Font> Exporter for Png<'_, F> {
+
+    type Output = ImageResult<Vec<u8>>;
+
+    fn export(
+        &self,
+        grid: &Grid,
+    ) -> ImageResult<Vec<u8>>
+    {
+        // Collect every visible character together with the pen it is drawn with
+        let mut lines: Vec<Vec<(char, Pen)>> = vec![Vec::new()];
+        let options = grid.default_options();
+        let _ = grid.write_segments(&grid.layout(), &options, |segment| -> Result<(), Infallible> {
+            let line = lines.last_mut().expect("there is always a current line");
+            match segment {
+                Segment::Text(text) => line.extend(text.chars().map(|c| (c, Pen::default()))),
+                Segment::Spaces(count) => line.extend(std::iter::repeat_n((' ', Pen::default()), count)),
+                Segment::Styled(text, cell) => {
+                    let pen = Pen::from_cell(cell);
+                    line.extend(text.chars().map(|c| (c, pen)));
+                },
+                Segment::LineEnd => lines.push(Vec::new()),
+            }
+            Ok(())
+        });
+        lines.pop();
+
+        let scaled = self.font.as_scaled(PxScale::from(self.scale));
+        let char_width = scaled.h_advance(self.font.glyph_id('M')).ceil().max(1.0) as u32;
+        let line_height = scaled.height().ceil().max(1.0) as u32;
+        let ascent = scaled.ascent();
+        let margin = char_width;
+
+        let cols = lines.iter().map(|line| line.len()).max().unwrap_or(0) as u32;
+        let width = cols * char_width + 2 * margin;
+        let height = lines.len() as u32 * line_height + 2 * margin;
+        let mut image = RgbImage::from_pixel(width, height, DEFAULT_BACKGROUND);
+
+        for (line_index, line) in lines.iter().enumerate() {
+            let y = margin + line_index as u32 * line_height;
+            for (col_index, &(c, pen)) in line.iter().enumerate() {
+                let x = margin + col_index as u32 * char_width;
+                if let Some(bg) = pen.bg {
+                    fill_rect(&mut image, x, y, char_width, line_height, bg);
+                }
+                if pen.hidden {
+                    continue;
+                }
+                let fg = pen.fg;
+
+                let glyph = scaled.scaled_glyph(c);
+                if let Some(outlined) = self.font.outline_glyph(
+                    ab_glyph::Glyph { position: point(x as f32, y as f32 + ascent), ..glyph }
+                ) {
+                    let bounds = outlined.px_bounds();
+                    // Fake bold by drawing the glyph a second time, one pixel to the right
+                    let offsets: &[i32] = if pen.bold { &[0, 1] } else { &[0] };
+                    for &offset in offsets {
+                        outlined.draw(|gx, gy, coverage| {
+                            let px = bounds.min.x as i32 + gx as i32 + offset;
+                            let py = bounds.min.y as i32 + gy as i32;
+                            if px >= 0 && py >= 0 && (px as u32) < width && (py as u32) < height {
+                                let pixel = image.get_pixel_mut(px as u32, py as u32);
+                                *pixel = blend(*pixel, fg, coverage);
+                            }
+                        });
+                    }
+                }
+                if pen.underline {
+                    let underline_y = (y as f32 + ascent + 1.0) as u32;
+                    fill_rect(&mut image, x, underline_y, char_width, 1, fg);
+                }
+                if pen.strike {
+                    fill_rect(&mut image, x, y + line_height / 2, char_width, 1, fg);
+                }
+            }
+        }
+
+        let mut bytes = Vec::new();
+        image.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)?;
+        Ok(bytes)
+    }
+
+}
+
+/// The resolved colors and font styles used to draw a single character.
+///
+/// Reverse and dim are applied to the colors up front; italic and blink are not drawn.
+
+#[derive(Debug, Clone, Copy)]
+struct Pen {
+    fg        : Rgb<u8>,
+    bg        : Option<Rgb<u8>>,
+    bold      : bool,
+    underline : bool,
+    strike    : bool,
+    hidden    : bool,
+}
+
+impl Default for Pen {
+    fn default() -> Self {
+        Pen {
+            fg        : DEFAULT_FOREGROUND,
+            bg        : None,
+            bold      : false,
+            underline : false,
+            strike    : false,
+            hidden    : false,
+        }
+    }
+}
+
+impl Pen {
+
+    /// Resolves the cell's colors and font styles into a pen.
+
+    fn from_cell(
+        cell: &Cell,
+    ) -> Self
+    {
+        let styles = cell.font_style();
+        let has = |flag: FontStyle| styles.into_iter().any(|style| style == flag);
+
+        let mut fg = cell.fg_color().map(|color| to_rgb(color.as_rgb())).unwrap_or(DEFAULT_FOREGROUND);
+        let mut bg = cell.bg_color().map(|color| to_rgb(color.as_rgb()));
+        if has(FontStyle::Reverse) {
+            (fg, bg) = (bg.unwrap_or(DEFAULT_BACKGROUND), Some(fg));
+        }
+        if has(FontStyle::Dim) {
+            fg = blend(bg.unwrap_or(DEFAULT_BACKGROUND), fg, 0.5);
+        }
+
+        Pen {
+            fg,
+            bg,
+            bold      : has(FontStyle::Bold),
+            underline : has(FontStyle::Underline),
+            strike    : has(FontStyle::Strike),
+            hidden    : has(FontStyle::Hidden),
+        }
+    }
+
+}
+
+/// Converts an RGB tuple into an image pixel.
+
+fn to_rgb(
+    (r, g, b): (u8, u8, u8),
+) -> Rgb<u8>
+{
+    Rgb([r, g, b])
+}
+
+/// Fills a rectangle of the image, clipped to its bounds.
+
+fn fill_rect(
+    image: &mut RgbImage,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    color: Rgb<u8>,
+)
+{
+    for py in y..(y + height).min(image.height()) {
+        for px in x..(x + width).min(image.width()) {
+            image.put_pixel(px, py, color);
+        }
+    }
+}
+
+/// Mixes two colors, `amount` being the share of `over` from 0 to 1.
+
+fn blend(
+    under: Rgb<u8>,
+    over: Rgb<u8>,
+    amount: f32,
+) -> Rgb<u8>
+{
+    let amount = amount.clamp(0.0, 1.0);
+    let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * amount).round() as u8;
+    Rgb([mix(under[0], over[0]), mix(under[1], over[1]), mix(under[2], over[2])])
+}
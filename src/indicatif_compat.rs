@@ -0,0 +1,55 @@
+use crate::grid::Grid;
+use crate::live::LiveGrid;
+
+use indicatif::{MultiProgress, ProgressBar};
+use std::io;
+
+impl Grid {
+
+    /// Prints the grid to stdout above a progress bar, without corrupting either output.
+    ///
+    /// The bar is cleared while the table is printed and redrawn below it afterwards.
+    /// If the bar was added to a `MultiProgress`, all of its bars are redrawn.
+    /// Unlike `ProgressBar::println`, the table is printed even if the bar is hidden,
+    /// e.g. when the output is not a terminal.
+
+    pub fn println_progress(
+        &self,
+        bar: &ProgressBar,
+    ) -> io::Result<()>
+    {
+        bar.suspend(|| self.render_to(&mut io::stdout().lock()))
+    }
+
+    /// Prints the grid to stdout above all the progress bars of a `MultiProgress`.
+    ///
+    /// See `println_progress` for details.
+
+    pub fn println_multi_progress(
+        &self,
+        multi: &MultiProgress,
+    ) -> io::Result<()>
+    {
+        multi.suspend(|| self.render_to(&mut io::stdout().lock()))
+    }
+
+}
+
+impl LiveGrid {
+
+    /// Renders the grid to stdout, updating the previous frame in place above a progress bar.
+    ///
+    /// The bar is cleared while the table is redrawn, so the cursor movements of both
+    /// don't interfere. As with `LiveGrid::render`, nothing else should be printed
+    /// between renders.
+
+    pub fn render_progress(
+        &mut self,
+        grid: &Grid,
+        bar: &ProgressBar,
+    ) -> io::Result<()>
+    {
+        bar.suspend(|| self.render(grid, &mut io::stdout().lock()))
+    }
+
+}
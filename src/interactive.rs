@@ -0,0 +1,353 @@
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+use crate::options::RenderOptions;
+use crate::sort::Order;
+
+use crossterm::cursor;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, ClearType};
+use crossterm::{execute, queue};
+use std::io::{self, Write};
+
+/// Puts the terminal in raw mode on the alternate screen, and restores it when dropped,
+/// even if the pager fails or panics.
+
+struct RawScreen;
+
+impl RawScreen {
+
+    fn enter(
+        out: &mut impl Write,
+    ) -> io::Result<Self>
+    {
+        terminal::enable_raw_mode()?;
+        let screen = RawScreen;
+        execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(screen)
+    }
+
+}
+
+impl Drop for RawScreen {
+
+    fn drop(
+        &mut self
+    )
+    {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+
+}
+
+/// Checks whether the first row of a grid is formatted as a header, with every cell in bold,
+/// as done for the grids built with a header.
+
+fn has_header_row(
+    grid: &Grid,
+) -> bool
+{
+    grid.row_size() > 0
+        && grid.col_size() > 0
+        && grid.row_iter(0).all(|cell| cell.get_style().contains(FontStyle::Bold))
+}
+
+/// What the pager does with the next key.
+
+enum Input {
+    /// Keys scroll, sort, and quit.
+    Browse,
+    /// Keys are typed into the search text, until enter or escape.
+    Search(String),
+}
+
+/// The state of the pager opened by `Grid::explore`, over its own copy of the grid.
+
+struct Pager {
+    grid: Grid,
+    options: RenderOptions,
+    /// Whether the first row is a header, which stays on top when sorting.
+    header: bool,
+    top: usize,
+    left: usize,
+    sort: Option<(usize, Order)>,
+    pattern: String,
+    matches: Vec<(usize, usize)>,
+    input: Input,
+}
+
+impl Pager {
+
+    /// Creates a pager over a copy of the grid, at its top left corner.
+
+    fn new(
+        grid: &Grid,
+    ) -> Self
+    {
+        let mut options = grid.default_options();
+        options.set_row_separators(false);
+        Pager {
+            grid: grid.clone(),
+            options,
+            header: has_header_row(grid),
+            top: 0,
+            left: 0,
+            sort: None,
+            pattern: String::new(),
+            matches: Vec::new(),
+            input: Input::Browse,
+        }
+    }
+
+    /// Gets the number of rows shown on a screen of the given height,
+    /// leaving room for the top and bottom borders and the status line.
+
+    fn page_rows(
+        height: usize,
+    ) -> usize
+    {
+        height.saturating_sub(3).max(1)
+    }
+
+    /// Draws the visible part of the grid and the status line.
+
+    fn draw(
+        &mut self,
+        out: &mut impl Write,
+        (width, height): (usize, usize),
+    ) -> io::Result<()>
+    {
+        self.options.set_max_width(width);
+        let page_rows = Pager::page_rows(height);
+        let rendered = self.grid.view(self.top..self.top + page_rows, self.left..).render_with(&self.options);
+
+        queue!(out, terminal::Clear(ClearType::All))?;
+        for (line_index, line) in rendered.lines().take(height.saturating_sub(1)).enumerate() {
+            queue!(out, cursor::MoveTo(0, line_index as u16), Print(line))?;
+        }
+        let status: String = self.status(page_rows).chars().take(width).collect();
+        queue!(out, cursor::MoveTo(0, height.saturating_sub(1) as u16), Print(status))?;
+        out.flush()
+    }
+
+    /// Gets the status line: the visible rows, the sort, and the search, or the search being typed.
+
+    fn status(
+        &self,
+        page_rows: usize,
+    ) -> String
+    {
+        if let Input::Search(text) = &self.input {
+            return format!("/{}", text);
+        }
+
+        let row_size = self.grid.row_size();
+        let last = (self.top + page_rows).min(row_size);
+        let mut status = format!("rows {}-{} of {}", (self.top + 1).min(last), last, row_size);
+        if let Some((col_index, order)) = self.sort {
+            let order = if order == Order::Ascending { "ascending" } else { "descending" };
+            status.push_str(&format!(" | sorted by column {} {}", col_index + 1, order));
+        }
+        if !self.pattern.is_empty() {
+            status.push_str(&format!(" | /{}: {} matches", self.pattern, self.matches.len()));
+        }
+        status.push_str(" | arrows scroll, 1-9 sort, / search, n next, q quit");
+        status
+    }
+
+    /// Scrolls to the given row, keeping at least one row on screen.
+
+    fn scroll_to(
+        &mut self,
+        row_index: usize,
+    )
+    {
+        self.top = row_index.min(self.grid.row_size().saturating_sub(1));
+    }
+
+    /// Sorts the rows by a column, in the comparison of its type, reversing the order
+    /// if the rows are already sorted by it.
+
+    fn sort_by(
+        &mut self,
+        col_index: usize,
+    )
+    {
+        if col_index >= self.grid.col_size() {
+            return;
+        }
+
+        let order = match self.sort {
+            Some((sorted_col, Order::Ascending)) if sorted_col == col_index => Order::Descending,
+            _ => Order::Ascending,
+        };
+        let sort_type = self.grid.col_type(col_index).sort_type();
+        self.grid.sort_rows_from_by_keys(usize::from(self.header), &[(col_index, sort_type, order)]);
+        self.sort = Some((col_index, order));
+        // The matches moved with their rows
+        self.matches = self.grid.highlight_matches(&self.pattern, FontStyle::Reverse);
+    }
+
+    /// Highlights a text and scrolls to its first match, if any.
+
+    fn search(
+        &mut self,
+        pattern: String,
+    )
+    {
+        self.matches = self.grid.highlight_matches(&pattern, FontStyle::Reverse);
+        self.pattern = pattern;
+        if let Some(&(row_index, _)) = self.matches.first() {
+            self.scroll_to(row_index);
+        }
+    }
+
+    /// Scrolls to the next row with a match below the top row, wrapping around to the first one.
+
+    fn next_match(
+        &mut self
+    )
+    {
+        let next = self.matches.iter()
+            .find(|&&(row_index, _)| row_index > self.top)
+            .or(self.matches.first());
+        if let Some(&(row_index, _)) = next {
+            self.scroll_to(row_index);
+        }
+    }
+
+    /// Handles a key while browsing.
+    ///
+    /// # Returns
+    ///
+    /// False if the pager should exit
+
+    fn browse(
+        &mut self,
+        code: KeyCode,
+        page_rows: usize,
+    ) -> bool
+    {
+        let last_row = self.grid.row_size().saturating_sub(1);
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Up | KeyCode::Char('k') => self.top = self.top.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_to(self.top + 1),
+            KeyCode::PageUp => self.top = self.top.saturating_sub(page_rows),
+            KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_to(self.top + page_rows),
+            KeyCode::Home | KeyCode::Char('g') => self.top = 0,
+            KeyCode::End | KeyCode::Char('G') => self.top = last_row.saturating_sub(page_rows - 1),
+            KeyCode::Left | KeyCode::Char('h') => self.left = self.left.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('l') => self.left = (self.left + 1).min(self.grid.col_size().saturating_sub(1)),
+            KeyCode::Char(digit @ '1'..='9') => self.sort_by(self.left + (digit as usize - '1' as usize)),
+            KeyCode::Char('/') => self.input = Input::Search(String::new()),
+            KeyCode::Char('n') => self.next_match(),
+            _ => {},
+        }
+        true
+    }
+
+}
+
+impl Grid {
+
+    /// Opens the grid in a full-screen pager on the terminal, and returns when it is closed,
+    /// turning any grid into an explorable view with one call.
+    ///
+    /// The pager works on a copy of the grid, which is left unchanged. Its keys are:
+    ///
+    /// - arrows (or `h` `j` `k` `l`) scroll by a row or a column, page up and page down (or space)
+    ///   by a screen, and home and end (or `g` and `G`) go to the first and last rows
+    /// - `1` to `9` sort the rows by the first to ninth visible column, compared as the type
+    ///   of the column (see `set_col_type`); pressing the same key again reverses the order.
+    ///   A first row in bold, as made by `with_header`, `GridBuilder`, and the importers,
+    ///   is kept on top as a header, and footer rows stay last
+    /// - `/` types a text to highlight with `highlight_matches`, and `n` scrolls to the next match
+    /// - `q` or escape exits
+    ///
+    /// # Returns
+    ///
+    /// An error if the terminal could not be set up or read
+
+    pub fn explore(
+        &self
+    ) -> io::Result<()>
+    {
+        let mut out = io::stdout();
+        let _screen = RawScreen::enter(&mut out)?;
+        let mut pager = Pager::new(self);
+
+        loop {
+            let (width, height) = terminal::size()?;
+            let size = (usize::from(width), usize::from(height));
+            pager.draw(&mut out, size)?;
+
+            let Event::Key(key) = event::read()? else {
+                // Redraw on resize
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+                return Ok(());
+            }
+
+            match &mut pager.input {
+                Input::Browse => {
+                    if !pager.browse(key.code, Pager::page_rows(size.1)) {
+                        return Ok(());
+                    }
+                },
+                Input::Search(text) => match key.code {
+                    KeyCode::Enter => {
+                        let pattern = core::mem::take(text);
+                        pager.input = Input::Browse;
+                        pager.search(pattern);
+                    },
+                    KeyCode::Esc => pager.input = Input::Browse,
+                    KeyCode::Backspace => {
+                        text.pop();
+                    },
+                    KeyCode::Char(c) => text.push(c),
+                    _ => {},
+                },
+            }
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Pager;
+    use crate::Grid;
+
+    fn first_col(
+        grid: &Grid,
+    ) -> Vec<&str>
+    {
+        grid.col_iter(0).map(|cell| cell.get_data()).collect()
+    }
+
+    #[test]
+    fn sorting_keeps_a_bold_header_on_top() {
+        let grid = Grid::from([["b"], ["c"], ["a"]]).with_header(["name"]);
+        let mut pager = Pager::new(&grid);
+        pager.sort_by(0);
+        assert_eq!(first_col(&pager.grid), ["name", "a", "b", "c"]);
+        pager.sort_by(0);
+        assert_eq!(first_col(&pager.grid), ["name", "c", "b", "a"]);
+    }
+
+    #[test]
+    fn sorting_moves_every_row_without_a_header() {
+        let grid = Grid::from([["b"], ["c"], ["a"]]);
+        let mut pager = Pager::new(&grid);
+        pager.sort_by(0);
+        assert_eq!(first_col(&pager.grid), ["a", "b", "c"]);
+    }
+
+}
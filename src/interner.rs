@@ -0,0 +1,117 @@
+use crate::cell::Cell;
+use crate::grid::Grid;
+
+use alloc::collections::BTreeSet;
+use alloc::sync::Arc;
+
+/// Shares the storage of identical cell data, for grids repeating the same values many times.
+///
+/// Grids of categorical data, such as statuses or country names, hold the same few strings
+/// in thousands of cells. Cells created or interned through the same interner keep a single
+/// copy of every distinct text instead. Interning is transparent: the cells behave exactly
+/// as before, and a cell gets its own copy again when its data is modified.
+
+#[derive(Debug, Clone)]
+pub struct Interner {
+    strings: BTreeSet<Arc<str>>,
+}
+
+impl Default for Interner {
+
+    fn default() -> Self {
+        Interner::new()
+    }
+
+}
+
+impl Interner {
+
+    /// Creates an interner without any strings.
+
+    pub fn new() -> Self {
+        Interner {
+            strings: BTreeSet::new(),
+        }
+    }
+
+    /// Creates a cell sharing its data with every other cell of the same text from this interner.
+
+    pub fn cell(
+        &mut self,
+        data: &str,
+    ) -> Cell
+    {
+        let mut cell = Cell::default();
+        cell.set_shared_data(self.share(data));
+        cell
+    }
+
+    /// Gets the number of distinct strings held by the interner.
+
+    pub fn len(
+        &self
+    ) -> usize
+    {
+        self.strings.len()
+    }
+
+    /// Checks whether the interner holds no strings.
+
+    pub fn is_empty(
+        &self
+    ) -> bool
+    {
+        self.strings.is_empty()
+    }
+
+    /// Gets the shared copy of the text, adding it to the interner first if needed.
+
+    fn share(
+        &mut self,
+        text: &str,
+    ) -> Arc<str>
+    {
+        if let Some(shared) = self.strings.get(text) {
+            return Arc::clone(shared);
+        }
+        let shared: Arc<str> = Arc::from(text);
+        self.strings.insert(Arc::clone(&shared));
+        shared
+    }
+
+}
+
+impl Grid {
+
+    /// Makes all cells with identical data share a single copy of it.
+    ///
+    /// This is a shortcut for interning with a new `Interner`.
+    /// Empty cells are left as they are, since they hold no data to share.
+
+    pub fn intern(
+        &mut self
+    )
+    {
+        self.intern_with(&mut Interner::new());
+    }
+
+    /// Makes all cells with identical data share a single copy of it, kept in the given interner.
+    ///
+    /// Interning several grids with the same interner shares the data across all of them.
+    /// Formatting, null cells, and rendering are unchanged.
+
+    pub fn intern_with(
+        &mut self,
+        interner: &mut Interner,
+    )
+    {
+        for cell in self.flat_iter_mut() {
+            if cell.get_data().is_empty() {
+                continue;
+            }
+            let shared = interner.share(cell.get_data());
+            cell.set_shared_data(shared);
+        }
+    }
+
+}
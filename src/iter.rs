@@ -0,0 +1,148 @@
+use crate::cell::Cell;
+
+use core::iter::FusedIterator;
+use core::mem;
+
+/// Iterator over the cells of a column, as returned by `Grid::col_iter`.
+///
+/// The slice runs from the next cell of the column to its last one,
+/// with `step` cells (the width of a row) between consecutive cells of the column.
+
+#[derive(Debug, Clone)]
+pub(crate) struct ColIter<'a> {
+    cells: &'a [Cell],
+    step: usize,
+}
+
+impl<'a> ColIter<'a> {
+
+    /// Creates an iterator over a column, given the slice from its first cell to its last one.
+
+    pub(crate) fn new(
+        cells: &'a [Cell],
+        step: usize,
+    ) -> Self
+    {
+        ColIter { cells, step: step.max(1) }
+    }
+
+}
+
+impl<'a> Iterator for ColIter<'a> {
+
+    type Item = &'a Cell;
+
+    fn next(
+        &mut self
+    ) -> Option<&'a Cell>
+    {
+        let (first, rest) = self.cells.split_first()?;
+        self.cells = rest.get(self.step - 1..).unwrap_or(&[]);
+        Some(first)
+    }
+
+    fn size_hint(
+        &self
+    ) -> (usize, Option<usize>)
+    {
+        let len = column_len(self.cells.len(), self.step);
+        (len, Some(len))
+    }
+
+}
+
+impl DoubleEndedIterator for ColIter<'_> {
+
+    fn next_back(
+        &mut self
+    ) -> Option<Self::Item>
+    {
+        let (last, rest) = self.cells.split_last()?;
+        self.cells = &rest[..rest.len().saturating_sub(self.step - 1)];
+        Some(last)
+    }
+
+}
+
+impl ExactSizeIterator for ColIter<'_> {}
+
+impl FusedIterator for ColIter<'_> {}
+
+/// Mutable iterator over the cells of a column, as returned by `Grid::col_iter_mut`.
+///
+/// See `ColIter` for the layout of the slice.
+
+#[derive(Debug)]
+pub(crate) struct ColIterMut<'a> {
+    cells: &'a mut [Cell],
+    step: usize,
+}
+
+impl<'a> ColIterMut<'a> {
+
+    /// Creates a mutable iterator over a column, given the slice from its first cell to its last one.
+
+    pub(crate) fn new(
+        cells: &'a mut [Cell],
+        step: usize,
+    ) -> Self
+    {
+        ColIterMut { cells, step: step.max(1) }
+    }
+
+}
+
+impl<'a> Iterator for ColIterMut<'a> {
+
+    type Item = &'a mut Cell;
+
+    fn next(
+        &mut self
+    ) -> Option<&'a mut Cell>
+    {
+        let (first, rest) = mem::take(&mut self.cells).split_first_mut()?;
+        self.cells = rest.get_mut(self.step - 1..).unwrap_or(&mut []);
+        Some(first)
+    }
+
+    fn size_hint(
+        &self
+    ) -> (usize, Option<usize>)
+    {
+        let len = column_len(self.cells.len(), self.step);
+        (len, Some(len))
+    }
+
+}
+
+impl DoubleEndedIterator for ColIterMut<'_> {
+
+    fn next_back(
+        &mut self
+    ) -> Option<Self::Item>
+    {
+        let (last, rest) = mem::take(&mut self.cells).split_last_mut()?;
+        let len = rest.len().saturating_sub(self.step - 1);
+        self.cells = &mut rest[..len];
+        Some(last)
+    }
+
+}
+
+impl ExactSizeIterator for ColIterMut<'_> {}
+
+impl FusedIterator for ColIterMut<'_> {}
+
+/// Gets the number of column cells in a slice running from the first cell of a column to its last one.
+
+fn column_len(
+    slice_len: usize,
+    step: usize,
+) -> usize
+{
+    if slice_len == 0 {
+        0
+    } else {
+        (slice_len - 1) / step + 1
+    }
+}
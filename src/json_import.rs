@@ -0,0 +1,31 @@
+use crate::grid::Grid;
+
+use serde::de::Error;
+use serde_json::Value;
+
+impl Grid {
+
+    /// Create a new grid from a JSON array of objects, such as an API response.
+    ///
+    /// The first row is a header holding the union of all object keys, in order of first appearance,
+    /// formatted in bold and centered. Every object becomes a row with its values aligned under the keys.
+    /// A single object is treated as an array holding only that object.
+    ///
+    /// Strings are shown without quotes, numbers and booleans as written,
+    /// and nested arrays and objects as compact JSON.
+    /// `null` values and missing fields are shown as a dimmed `null`.
+    /// Array items that are not objects are placed under a `value` column.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not valid JSON, or is neither an array nor an object
+
+    pub fn from_json(
+        json: &str,
+    ) -> serde_json::Result<Self>
+    {
+        let value: Value = serde_json::from_str(json)?;
+        Grid::from_serialize(&value).map_err(|_| serde_json::Error::custom("expected an array of objects"))
+    }
+
+}
@@ -1,7 +1,18 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
 
 mod cell;
+mod cell_data;
+mod cell_style;
+mod col_type;
+mod flag;
+mod dirty;
 mod grid;
+mod history;
+mod builder;
+mod table;
+mod iter;
 mod align;
 mod border;
 mod ansi;
@@ -9,19 +20,233 @@ mod color;
 mod format;
 mod error;
 mod fontstyle;
+mod export;
+mod asciidoc;
+mod bbcode;
+mod html;
+mod delimited;
+mod delimited_export;
+mod parse;
+mod markdown_import;
+mod to_grid;
+mod tuples;
+mod changes;
+mod diff;
+mod locale;
+mod interner;
+mod memory;
+mod named;
+mod merge;
+mod streaming;
+mod search;
+mod selection;
+mod tree;
+mod reorder;
+mod rows;
+mod cols;
+mod sort;
+mod stats;
+mod summary;
+mod validate;
+mod view;
+mod render;
+mod options;
+mod theme;
+mod theme_registry;
+#[cfg(feature = "std")]
+mod env;
+#[cfg(feature = "std")]
+mod live;
+#[cfg(feature = "std")]
+mod writer;
+#[cfg(feature = "indicatif")]
+mod indicatif_compat;
+#[cfg(feature = "term")]
+mod term;
+#[cfg(feature = "interactive")]
+mod interactive;
+#[cfg(feature = "rayon")]
+mod rayon_render;
+#[cfg(feature = "termcolor")]
+mod termcolor_backend;
+#[cfg(feature = "ratatui")]
+mod ratatui_widget;
+#[cfg(feature = "image")]
+mod image_export;
+#[cfg(feature = "xlsx")]
+mod xlsx_export;
+#[cfg(feature = "csv")]
+mod csv_import;
+#[cfg(feature = "json")]
+mod json_import;
+#[cfg(feature = "serde")]
+mod serialize_import;
+#[cfg(feature = "sqlx")]
+mod sqlx_import;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_import;
+#[cfg(feature = "ndarray")]
+mod ndarray_import;
+#[cfg(feature = "polars")]
+mod polars_import;
+#[cfg(feature = "comfy-table")]
+mod comfy_table_compat;
+#[cfg(feature = "prettytable")]
+mod prettytable_compat;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "pyo3")]
+mod python;
 
 pub use cell::Cell;
+pub use cell_style::CellStyle;
+pub use flag::{BoolFormat, BoolGlyphs};
 pub use grid::Grid;
+pub use builder::{GridBuilder, ColumnDef};
+pub use table::Table;
 pub use align::Align;
 pub use color::Color;
 pub use fontstyle::FontStyle;
+pub use border::BorderStyle;
+pub use options::{RenderOptions, ColorMode};
+pub use theme::Theme;
+pub use theme_registry::ThemeRegistry;
+#[cfg(feature = "std")]
+pub use env::{STYLE_ENV_VAR, BORDER_ENV_VAR, COLOR_ENV_VAR};
+#[cfg(feature = "std")]
+pub use live::LiveGrid;
+#[cfg(feature = "std")]
+pub use writer::GridWriter;
+#[cfg(feature = "term")]
+pub use term::terminal_size;
+pub use to_grid::ToGrid;
+pub use tuples::IntoRow;
+pub use changes::ChangeHighlighter;
+pub use diff::{GridDiff, RowDiff};
+pub use locale::Locale;
+pub use interner::Interner;
+pub use memory::MemoryStats;
+pub use streaming::StreamingGrid;
+pub use sort::{SortType, Order};
+pub use col_type::ColType;
+pub use stats::ColStats;
+pub use summary::Aggregate;
+pub use validate::Violation;
+pub use view::GridView;
+pub use rows::{RowRef, RowMut};
+pub use cols::ColMut;
+#[cfg(feature = "derive")]
+pub use flatgrid_derive::ToGrid;
+pub use export::Exporter;
+pub use asciidoc::AsciiDoc;
+pub use bbcode::{BBCode, FencedMarkdown};
+pub use html::Html;
+pub use delimited_export::{Delimited, QuotePolicy, NewlinePolicy};
+#[cfg(feature = "ratatui")]
+pub use ratatui_widget::GridWidget;
+#[cfg(feature = "image")]
+pub use image_export::Png;
+#[cfg(feature = "xlsx")]
+pub use xlsx_export::Xlsx;
+#[cfg(feature = "csv")]
+pub use csv_import::{CsvOptions, CsvHeader};
+#[cfg(feature = "polars")]
+pub use polars_import::DataFrameOptions;
 
+/// Items used by the exported macros, not part of the public API.
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec::Vec;
+}
+
+
+/// Creates a cell from any `Display` value, or a `Cell`, with the annotations after a `;` styling it,
+/// such as `cell!("DOWN"; red bold right)`.
+///
+/// An annotation is a font style (`bold`, `dim`, `italic`, `underline`, `blink`, `reverse`,
+/// `hidden`, `strike`), an alignment (`left`, `center`, `right`, `top`, `middle`, `bottom`),
+/// or a color (`black`, `red`, `green`, `yellow`, `blue`, `magenta`, `cyan`, `white`).
+/// Annotations are separated by spaces, as in `grid!` and `table!`, where commas separate the cells.
+/// Unknown annotations fail to compile.
+
+#[macro_export]
+macro_rules! cell {
+    (@annotate $cell:ident bold) => { $cell.set_style($cell.get_style() | $crate::FontStyle::Bold) };
+    (@annotate $cell:ident dim) => { $cell.set_style($cell.get_style() | $crate::FontStyle::Dim) };
+    (@annotate $cell:ident italic) => { $cell.set_style($cell.get_style() | $crate::FontStyle::Italic) };
+    (@annotate $cell:ident underline) => { $cell.set_style($cell.get_style() | $crate::FontStyle::Underline) };
+    (@annotate $cell:ident blink) => { $cell.set_style($cell.get_style() | $crate::FontStyle::Blink) };
+    (@annotate $cell:ident reverse) => { $cell.set_style($cell.get_style() | $crate::FontStyle::Reverse) };
+    (@annotate $cell:ident hidden) => { $cell.set_style($cell.get_style() | $crate::FontStyle::Hidden) };
+    (@annotate $cell:ident strike) => { $cell.set_style($cell.get_style() | $crate::FontStyle::Strike) };
+    (@annotate $cell:ident left) => { $cell.set_align($cell.get_align() | $crate::Align::Left) };
+    (@annotate $cell:ident center) => { $cell.set_align($cell.get_align() | $crate::Align::Center) };
+    (@annotate $cell:ident right) => { $cell.set_align($cell.get_align() | $crate::Align::Right) };
+    (@annotate $cell:ident top) => { $cell.set_align($cell.get_align() | $crate::Align::Top) };
+    (@annotate $cell:ident middle) => { $cell.set_align($cell.get_align() | $crate::Align::Middle) };
+    (@annotate $cell:ident bottom) => { $cell.set_align($cell.get_align() | $crate::Align::Bottom) };
+    (@annotate $cell:ident black) => { $cell.set_color($crate::Color::BLACK) };
+    (@annotate $cell:ident red) => { $cell.set_color($crate::Color::RED) };
+    (@annotate $cell:ident green) => { $cell.set_color($crate::Color::GREEN) };
+    (@annotate $cell:ident yellow) => { $cell.set_color($crate::Color::YELLOW) };
+    (@annotate $cell:ident blue) => { $cell.set_color($crate::Color::BLUE) };
+    (@annotate $cell:ident magenta) => { $cell.set_color($crate::Color::MAGENTA) };
+    (@annotate $cell:ident cyan) => { $cell.set_color($crate::Color::CYAN) };
+    (@annotate $cell:ident white) => { $cell.set_color($crate::Color::WHITE) };
+    (@annotate $cell:ident $other:ident) => {
+        ::core::compile_error!(::core::concat!("unknown cell annotation `", ::core::stringify!($other), "`"))
+    };
+    ($value:expr $(; $($annotation:ident)+)?) => {{
+        #[allow(unused_mut)]
+        let mut cell: $crate::Cell = ::core::convert::Into::into($value);
+        $($( $crate::cell!(@annotate cell $annotation); )+)?
+        cell
+    }};
+}
+
+/// Creates a row as a `Vec<Cell>` from `Display` values and cells, such as `row![cell!("api"; bold), "OK", 42]`,
+/// to pass to `Grid::push_row` or `grid!([row![...], row![...]])`.
+
+#[macro_export]
+macro_rules! row {
+    ($($value:expr),* $(,)?) => {
+        <$crate::__private::Vec<$crate::Cell> as ::core::convert::From<_>>::from([
+            $( $crate::cell!($value) ),*
+        ])
+    };
+}
+
+/// Creates a grid: empty with `grid!()`, of a given size with `grid!(rows, cols)`,
+/// or from data with `grid!([["a", "b"], ["c", "d"]])`.
+///
+/// Rows can also be listed directly, with annotations after a `;` styling a cell,
+/// such as `grid![["Name"; bold, "Age"; bold right], ["Ada", 36; right]]`,
+/// so small styled tables can be declared in place.
+///
+/// The annotations are the ones of `cell!`.
+/// Rows of different lengths are filled with empty cells, as in `Grid::from`. A single row without
+/// annotations reads as data, so it needs a trailing comma: `grid![["a", "b"],]`.
 
 #[macro_export]
 macro_rules! grid {
     () => {
         $crate::Grid::default()
     };
+    // A single array without annotations is data, as in `grid!([["a", "b"], ["c", "d"]])`
+    ([ $($data:expr),* $(,)? ]) => {
+        $crate::Grid::from([ $($data),* ])
+    };
+    ($( [ $( $value:expr $(; $($annotation:ident)+)? ),* $(,)? ] ),+ $(,)?) => {
+        // Rows of different lengths are iterated as trait objects, to fit in one array
+        $crate::Grid::from([
+            $(
+                &mut ::core::iter::IntoIterator::into_iter([
+                    $( $crate::cell!($value $(; $($annotation)+)?) ),*
+                ]) as &mut dyn ::core::iter::Iterator<Item = $crate::Cell>
+            ),+
+        ])
+    };
     ($data:expr) => {
         $crate::Grid::from($data)
     };
@@ -29,3 +254,64 @@ macro_rules! grid {
         $crate::Grid::new($rows, $cols)
     };
 }
+
+/// Creates a `Table` from its header and data rows in one expression,
+/// such as `table! { ["ID", "Name", "Status"] => [[1, "api", "OK"], [2, "db", "DOWN"; red]] }`.
+///
+/// Cells take the annotations of `cell!` after a `;`, as in `grid!`.
+/// The header defines the columns, as in `Table::new`: shorter rows are filled with empty cells,
+/// and cells beyond the last column are dropped.
+
+#[macro_export]
+macro_rules! table {
+    (
+        [ $( $header:expr $(; $($header_annotation:ident)+)? ),* $(,)? ]
+        => [ $( [ $( $value:expr $(; $($annotation:ident)+)? ),* $(,)? ] ),* $(,)? ]
+    ) => {{
+        // The header and rows are typed explicitly, so an empty one `[]` still has an item type
+        let header: $crate::__private::Vec<$crate::Cell> = <$crate::__private::Vec<$crate::Cell> as ::core::convert::From<_>>::from([
+            $( $crate::cell!($header $(; $($header_annotation)+)?) ),*
+        ]);
+        #[allow(unused_mut)]
+        let mut table = $crate::Table::new(header);
+        $(
+            let row: $crate::__private::Vec<$crate::Cell> = <$crate::__private::Vec<$crate::Cell> as ::core::convert::From<_>>::from([
+                $( $crate::cell!($value $(; $($annotation)+)?) ),*
+            ]);
+            table.push_row(row);
+        )*
+        table
+    }};
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Align, Color, FontStyle};
+
+    #[test]
+    fn cell_annotations_are_separated_by_spaces() {
+        let cell = cell!("DOWN"; red bold right);
+        assert_eq!(cell.get_data(), "DOWN");
+        assert_eq!(cell.get_color(), Some(Color::RED));
+        assert!(cell.get_style().contains(FontStyle::Bold));
+        assert!(cell.get_align().contains(Align::Right));
+
+        let grid = grid![["Name"; bold, "Age"; bold right], ["Ada", cell!(36; right)]];
+        assert_eq!(grid.get_cell(0, 1), Some(&cell!("Age"; bold right)));
+        assert_eq!(grid.get_cell(1, 1), Some(&cell!("36"; right)));
+    }
+
+    #[test]
+    fn table_rows_can_be_empty() {
+        let table = table! { ["ID", "Name"] => [[1, "api"], [], [2, "db"; red]] };
+        assert_eq!(table.data().row_size(), 3);
+        assert!(table.data().row_iter(1).all(|cell| cell.get_data().is_empty()));
+        assert_eq!(table.data().get_cell(2, 1), Some(&cell!("db"; red)));
+
+        let empty = table! { [] => [] };
+        assert_eq!((empty.col_size(), empty.data().row_size()), (0, 0));
+    }
+
+}
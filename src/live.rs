@@ -0,0 +1,142 @@
+use crate::ansi::CLEAR_LINE_ANSI_CODE;
+use crate::grid::Grid;
+use crate::options::RenderOptions;
+
+use std::io;
+
+/// Helper for redrawing a grid in place, such as in `top`-style dashboards.
+///
+/// The first render prints the whole table. Every following render moves the cursor
+/// back up over the previous frame and only rewrites the lines that changed,
+/// clearing any leftover lines if the table became shorter.
+///
+/// This assumes nothing else was printed below the table between renders.
+
+#[derive(Debug, Clone, Default)]
+pub struct LiveGrid {
+    options: RenderOptions,
+    previous: Vec<String>,
+}
+
+impl LiveGrid {
+
+    /// Creates a live grid helper using the default render options.
+
+    pub fn new() -> Self {
+        LiveGrid::default()
+    }
+
+    /// Creates a live grid helper using the given render options.
+
+    pub fn with_options(
+        options: RenderOptions,
+    ) -> Self
+    {
+        LiveGrid { options, previous: Vec::new() }
+    }
+
+    /// Renders the grid, updating the previously rendered frame in place.
+    ///
+    /// The cursor is left at the start of the line below the table.
+
+    pub fn render<W: io::Write>(
+        &mut self,
+        grid: &Grid,
+        w: &mut W,
+    ) -> io::Result<()>
+    {
+        let lines = grid.render_lines_with(&self.options);
+
+        if !self.previous.is_empty() {
+            // Go back to the first line of the previous frame
+            write!(w, "\x1b[{}A\r", self.previous.len())?;
+        }
+
+        // Number of unchanged lines the cursor still has to move down over
+        let mut skipped = 0;
+        for (line_index, line) in lines.iter().enumerate() {
+            if self.previous.get(line_index) == Some(line) {
+                skipped += 1;
+                continue;
+            }
+            if skipped > 0 {
+                write!(w, "\x1b[{}B", skipped)?;
+                skipped = 0;
+            }
+            writeln!(w, "{}{}", CLEAR_LINE_ANSI_CODE, line)?;
+        }
+        if skipped > 0 {
+            write!(w, "\x1b[{}B", skipped)?;
+        }
+
+        // Clear whatever is left of a taller previous frame
+        let leftover = self.previous.len().saturating_sub(lines.len());
+        if leftover > 0 {
+            for _ in 0..leftover {
+                writeln!(w, "{}", CLEAR_LINE_ANSI_CODE)?;
+            }
+            write!(w, "\x1b[{}A", leftover)?;
+        }
+
+        w.flush()?;
+        self.previous = lines;
+        Ok(())
+    }
+
+    /// Forgets the previous frame, so the next render prints the whole table again.
+    ///
+    /// Call this after the screen was cleared or other output was printed.
+
+    pub fn reset(
+        &mut self
+    )
+    {
+        self.previous.clear();
+    }
+
+}
+
+impl Grid {
+
+    /// Renders the grid over its previous render, for refreshing dashboards without keeping state.
+    ///
+    /// Moves the cursor up `prev_line_count` lines, redraws every line of the table, and clears
+    /// any leftover lines if the table became shorter. Pass `0` for the first render, then the
+    /// returned line count for every following one. The cursor is left at the start of the line
+    /// below the table, and nothing else should be printed between renders.
+    ///
+    /// Unlike `LiveGrid`, every line is rewritten; use `LiveGrid` to only rewrite the lines that changed.
+    ///
+    /// # Returns
+    ///
+    /// The number of lines rendered, to pass to the next call
+
+    pub fn render_in_place<W: io::Write>(
+        &self,
+        w: &mut W,
+        prev_line_count: usize,
+    ) -> io::Result<usize>
+    {
+        let lines = self.render_lines();
+
+        if prev_line_count > 0 {
+            write!(w, "\x1b[{}A\r", prev_line_count)?;
+        }
+        for line in &lines {
+            writeln!(w, "{}{}", CLEAR_LINE_ANSI_CODE, line)?;
+        }
+
+        // Clear whatever is left of a taller previous render
+        let leftover = prev_line_count.saturating_sub(lines.len());
+        if leftover > 0 {
+            for _ in 0..leftover {
+                writeln!(w, "{}", CLEAR_LINE_ANSI_CODE)?;
+            }
+            write!(w, "\x1b[{}A", leftover)?;
+        }
+
+        w.flush()?;
+        Ok(lines.len())
+    }
+
+}
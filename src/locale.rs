@@ -0,0 +1,327 @@
+use crate::align::Align;
+use crate::grid::Grid;
+
+use alloc::string::String;
+
+/// Regional conventions for writing numbers, used by `Grid::format_numbers`.
+///
+/// Holds the decimal separator, the optional separator between groups of three digits,
+/// and the horizontal alignment of numbers. Presets cover common conventions, such as
+/// `Locale::GERMAN` for `1.234,5` and `Locale::FRENCH` for `1 234,5`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    decimal_separator: char,
+    grouping_separator: Option<char>,
+    numeric_align: Align,
+}
+
+impl Default for Locale {
+
+    fn default() -> Self {
+        Locale::new()
+    }
+
+}
+
+impl Locale {
+
+    /// Numbers as written by Rust, like `-1234.5`: a point as decimal separator and no grouping.
+    pub const PLAIN   : Locale = Locale::with_separators('.', None);
+    /// English conventions, like `1,234.5`.
+    pub const ENGLISH : Locale = Locale::with_separators('.', Some(','));
+    /// German conventions, like `1.234,5`.
+    pub const GERMAN  : Locale = Locale::with_separators(',', Some('.'));
+    /// French conventions, like `1 234,5`.
+    pub const FRENCH  : Locale = Locale::with_separators(',', Some(' '));
+    /// Swiss conventions, like `1'234.5`.
+    pub const SWISS   : Locale = Locale::with_separators('.', Some('\''));
+
+    /// Creates the `Locale::PLAIN` locale, which writes numbers like Rust does and right-aligns them.
+
+    pub fn new() -> Self {
+        Locale::PLAIN
+    }
+
+    /// Creates a locale with the given separators that right-aligns numbers.
+
+    pub const fn with_separators(
+        decimal_separator: char,
+        grouping_separator: Option<char>,
+    ) -> Self
+    {
+        Locale {
+            decimal_separator,
+            grouping_separator,
+            numeric_align: Align::Right,
+        }
+    }
+
+    /// Gets the separator between the integer and fractional parts of a number.
+
+    pub fn decimal_separator(
+        &self
+    ) -> char
+    {
+        self.decimal_separator
+    }
+
+    /// Sets the separator between the integer and fractional parts of a number.
+
+    pub fn set_decimal_separator(
+        &mut self,
+        new_decimal_separator: char,
+    )
+    {
+        self.decimal_separator = new_decimal_separator;
+    }
+
+    /// Gets the separator between groups of three digits of the integer part, if any.
+
+    pub fn grouping_separator(
+        &self
+    ) -> Option<char>
+    {
+        self.grouping_separator
+    }
+
+    /// Sets the separator between groups of three digits of the integer part, or `None` for no grouping.
+
+    pub fn set_grouping_separator(
+        &mut self,
+        new_grouping_separator: Option<char>,
+    )
+    {
+        self.grouping_separator = new_grouping_separator;
+    }
+
+    /// Gets the horizontal alignment given to numbers.
+
+    pub fn numeric_align(
+        &self
+    ) -> Align
+    {
+        self.numeric_align
+    }
+
+    /// Sets the horizontal alignment given to numbers, `Align::Right` by default.
+    ///
+    /// Vertical alignments are ignored, so the vertical alignment of the cells is kept.
+
+    pub fn set_numeric_align(
+        &mut self,
+        new_numeric_align: Align,
+    )
+    {
+        self.numeric_align = new_numeric_align;
+    }
+
+    /// Formats a number written like Rust does, such as `-1234.5`, with the conventions of the locale.
+    ///
+    /// # Returns
+    ///
+    /// The formatted number, or `None` if the text is not a plain decimal number
+    /// (an optional sign, digits, and optional decimals)
+
+    pub fn format_number(
+        &self,
+        text: &str,
+    ) -> Option<String>
+    {
+        let (sign, integer, fraction) = split_number(text, '.', None)?;
+
+        let mut formatted = String::with_capacity(text.len() + integer.len() / 3);
+        formatted.push_str(sign);
+        for (index, digit) in integer.chars().enumerate() {
+            let remaining = integer.len() - index;
+            if index > 0 && remaining % 3 == 0 {
+                formatted.extend(self.grouping_separator);
+            }
+            formatted.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            formatted.push(self.decimal_separator);
+            formatted.push_str(fraction);
+        }
+        Some(formatted)
+    }
+
+    /// Rewrites a number written with the conventions of the locale, such as `-1 234,5` with `Locale::FRENCH`,
+    /// like Rust writes it, `-1234.5`, or returns None if the text is not one.
+
+    pub(crate) fn unlocalize_number(
+        &self,
+        text: &str,
+    ) -> Option<String>
+    {
+        let (sign, mut digits, fraction) = split_number(text, self.decimal_separator, self.grouping_separator)?;
+        digits.insert_str(0, sign);
+        if let Some(fraction) = fraction {
+            digits.push('.');
+            digits.push_str(fraction);
+        }
+        Some(digits)
+    }
+
+    /// Checks whether the locale writes numbers like Rust does, with a point and no grouping.
+
+    pub(crate) fn is_plain(
+        &self
+    ) -> bool
+    {
+        self.decimal_separator == '.' && self.grouping_separator.is_none()
+    }
+
+    /// Checks whether the text is a number already written with the conventions of the locale.
+
+    fn is_formatted_number(
+        &self,
+        text: &str,
+    ) -> bool
+    {
+        split_number(text, self.decimal_separator, self.grouping_separator).is_some()
+    }
+
+}
+
+/// Splits a decimal number into its sign, integer digits, and fractional digits.
+///
+/// If a grouping separator is given, the integer part may be split into groups of three digits
+/// by it, as long as the groups are consistent, like `1,234,567` but not `12,34`.
+
+fn split_number(
+    text: &str,
+    decimal_separator: char,
+    grouping_separator: Option<char>,
+) -> Option<(&str, String, Option<&str>)>
+{
+    let text = text.trim();
+    let sign_len = usize::from(text.starts_with(['-', '+']));
+    let (sign, unsigned) = text.split_at(sign_len);
+    let (integer, fraction) = match unsigned.split_once(decimal_separator) {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+
+    let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit());
+    if fraction.is_some_and(|fraction| !is_digits(fraction)) {
+        return None;
+    }
+    let digits = match grouping_separator {
+        Some(grouping_separator) if integer.contains(grouping_separator) => {
+            let mut groups = integer.split(grouping_separator);
+            let first = groups.next()?;
+            if !is_digits(first) || first.len() > 3 {
+                return None;
+            }
+            groups.try_fold(String::from(first), |mut digits, group| {
+                (is_digits(group) && group.len() == 3).then(|| {
+                    digits.push_str(group);
+                    digits
+                })
+            })?
+        },
+        _ => is_digits(integer).then(|| String::from(integer))?,
+    };
+    Some((sign, digits, fraction))
+}
+
+impl Grid {
+
+    /// Writes every number of the grid with the conventions of the locale, and aligns it.
+    ///
+    /// Cells holding a number written like Rust does, such as `-1234.5`, are reformatted,
+    /// e.g. to `-1 234,5` with `Locale::FRENCH`. Cells already holding a number in the format
+    /// of the locale are kept as they are. Both are given the locale's numeric alignment,
+    /// keeping their vertical alignment. Other cells, such as headers, are left unchanged.
+    ///
+    /// Numbers like `1.234` are valid in both formats when the grouping separator is a point;
+    /// they are read as written by Rust, so use this on data produced by the program rather
+    /// than on text that is already localized.
+    ///
+    /// The locale becomes the locale of the grid (see `set_locale`), so the formatted numbers
+    /// are still read as numbers when sorting, aggregating, and computing statistics.
+    ///
+    /// # Returns
+    ///
+    /// The number of numeric cells
+
+    pub fn format_numbers(
+        &mut self,
+        locale: &Locale,
+    ) -> usize
+    {
+        self.set_locale(*locale);
+        let mut count = 0;
+        for cell in self.flat_iter_mut() {
+            if cell.is_null() {
+                continue;
+            }
+            match locale.format_number(cell.get_data()) {
+                Some(formatted) => cell.set_data(formatted),
+                None if locale.is_formatted_number(cell.get_data()) => {},
+                None => continue,
+            }
+            cell.set_align(Align::from_parts(locale.numeric_align().get_h(), cell.v_align()));
+            count += 1;
+        }
+        count
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Aggregate, ColType, Grid, Locale, SortType};
+
+    use alloc::vec::Vec;
+
+    fn french_grid() -> Grid {
+        let mut grid = Grid::from([["amount"], ["1 234,5"], ["-2,25"], ["300"]]);
+        grid.set_locale(Locale::FRENCH);
+        grid
+    }
+
+    fn col(
+        grid: &Grid,
+    ) -> Vec<&str>
+    {
+        grid.col_iter(0).map(|cell| cell.get_data()).collect()
+    }
+
+    #[test]
+    fn localized_numbers_sort_by_value() {
+        let mut grid = french_grid();
+        grid.sort_by_col_typed(0, SortType::Numeric);
+        assert_eq!(col(&grid), ["-2,25", "300", "1 234,5", "amount"]);
+    }
+
+    #[test]
+    fn localized_numbers_are_aggregated_in_the_locale() {
+        let mut grid = french_grid();
+        grid.add_summary_row(&[(0, Aggregate::Sum)]);
+        assert_eq!(grid.get_cell(4, 0).unwrap().get_data(), "1 532,25");
+
+        let stats = grid.col_stats(0);
+        assert_eq!(stats.numeric_count(), 4);
+        assert_eq!(stats.min(), Some(-2.25));
+    }
+
+    #[test]
+    fn localized_numbers_are_numeric_cells() {
+        let mut grid = french_grid();
+        grid.set_col_type(0, ColType::Numeric);
+        assert_eq!(grid.get_cell(1, 0).unwrap().h_align(), Some(crate::align::AlignH::Right));
+        assert_eq!(grid.get_cell(0, 0).unwrap().h_align(), None);
+    }
+
+    #[test]
+    fn format_numbers_keeps_the_numbers_readable() {
+        let mut grid = Grid::from([["1234.5"], ["99"]]);
+        assert_eq!(grid.format_numbers(&Locale::GERMAN), 2);
+        grid.sort_by_col_typed(0, SortType::Numeric);
+        assert_eq!(col(&grid), ["99", "1.234,5"]);
+    }
+
+}
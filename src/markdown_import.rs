@@ -0,0 +1,119 @@
+use crate::align::Align;
+use crate::grid::Grid;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+impl Grid {
+
+    /// Parse the first Markdown pipe table found in the text into a grid.
+    ///
+    /// The header row is formatted in bold and centered, and the alignment markers of the
+    /// delimiter row (`:--`, `:-:`, `--:`) are applied to the cells of their column.
+    /// Text around the table is skipped.
+    ///
+    /// Leading and trailing pipes are optional, `\|` stands for a pipe inside a cell,
+    /// and `<br>` for a line break, so multiline cells are restored.
+    /// As in GitHub Flavored Markdown, the delimiter row sets the number of columns:
+    /// extra cells are ignored and missing ones are left empty.
+    ///
+    /// # Returns
+    ///
+    /// The parsed grid, or `None` if the text contains no table
+
+    pub fn from_markdown(
+        text: &str,
+    ) -> Option<Self>
+    {
+        let lines: Vec<&str> = text.lines().collect();
+        let (header_index, aligns) = lines.windows(2)
+            .enumerate()
+            .find_map(|(index, pair)| {
+                let aligns = parse_delimiter_row(pair[1])?;
+                (pair[0].contains('|') && split_row(pair[0]).len() == aligns.len()).then_some((index, aligns))
+            })?;
+
+        let body = lines[header_index + 2..].iter()
+            .take_while(|line| !line.trim().is_empty() && line.contains('|'));
+        let rows = core::iter::once(lines[header_index]).chain(body.copied())
+            .map(|line| {
+                let mut cells = split_row(line);
+                cells.resize(aligns.len(), String::new());
+                cells
+            });
+
+        let mut grid = Grid::from(rows);
+        for (col_index, align) in aligns.into_iter().enumerate() {
+            if let Some(align) = align {
+                for cell in grid.col_iter_mut(col_index) {
+                    cell.set_align(align);
+                }
+            }
+        }
+        grid.style_header_row();
+        Some(grid)
+    }
+
+}
+
+/// Parses a delimiter row like `| :-- | :-: | --: |` into the alignment of every column.
+///
+/// # Returns
+///
+/// The alignments, with `None` for columns without markers, or `None` if the line is not a delimiter row
+
+fn parse_delimiter_row(
+    line: &str,
+) -> Option<Vec<Option<Align>>>
+{
+    if !line.contains('-') {
+        return None;
+    }
+    split_row(line).iter()
+        .map(|cell| {
+            let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+            if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+                return None;
+            }
+            Some(match (cell.starts_with(':'), cell.ends_with(':')) {
+                (true, true)  => Some(Align::Center),
+                (true, false) => Some(Align::Left),
+                (false, true) => Some(Align::Right),
+                (false, false) => None,
+            })
+        })
+        .collect()
+}
+
+/// Splits a table row into the trimmed text of its cells, unescaping `\|` and turning `<br>` into line breaks.
+
+fn split_row(
+    line: &str,
+) -> Vec<String>
+{
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => cell.extend(chars.next()),
+            '|' => cells.push(core::mem::take(&mut cell)),
+            _ => cell.push(c),
+        }
+    }
+    // The trailing pipe is optional
+    if !cell.trim().is_empty() {
+        cells.push(cell);
+    }
+
+    cells.iter()
+        .map(|cell| {
+            let cell = ["<br>", "<br/>", "<br />"].iter()
+                .fold(cell.clone(), |cell, tag| cell.replace(tag, "\n"));
+            cell.split('\n').map(str::trim).collect::<Vec<&str>>().join("\n")
+        })
+        .collect()
+}
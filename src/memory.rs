@@ -0,0 +1,194 @@
+use crate::cell::Cell;
+use crate::col_type::ColType;
+use crate::grid::Grid;
+use crate::validate::ColValidator;
+
+use alloc::collections::BTreeSet;
+use core::mem::size_of;
+
+/// Memory used by a grid, as reported by `Grid::memory_footprint`.
+///
+/// All sizes are in bytes. Together they add up to `total_bytes`, the heap memory held by the grid;
+/// the `Grid` value itself is not included.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryStats {
+    cell_count: usize,
+    cell_bytes: usize,
+    data_bytes: usize,
+    capacity_overhead: usize,
+    layout_bytes: usize,
+    history_bytes: usize,
+    metadata_bytes: usize,
+}
+
+impl MemoryStats {
+
+    /// Gets the number of cells in the grid.
+
+    pub fn cell_count(
+        &self
+    ) -> usize
+    {
+        self.cell_count
+    }
+
+    /// Gets the size of the cells themselves, with their formatting but without the text they hold on the heap.
+
+    pub fn cell_bytes(
+        &self
+    ) -> usize
+    {
+        self.cell_bytes
+    }
+
+    /// Gets the size of the cell text held on the heap.
+    ///
+    /// Text borrowed from string literals or stored inline with the `compact_str` feature takes no heap memory,
+    /// and text shared by interned cells is counted once.
+
+    pub fn data_bytes(
+        &self
+    ) -> usize
+    {
+        self.data_bytes
+    }
+
+    /// Gets the memory allocated but not in use: spare room for more cells and spare capacity of cell text.
+    ///
+    /// A large overhead after bulk loading can be released by rebuilding the grid,
+    /// and reserving room up front avoids growing it in steps.
+
+    pub fn capacity_overhead(
+        &self
+    ) -> usize
+    {
+        self.capacity_overhead
+    }
+
+    /// Gets the size of the cached layout, or 0 if no layout is cached.
+
+    pub fn layout_bytes(
+        &self
+    ) -> usize
+    {
+        self.layout_bytes
+    }
+
+    /// Gets the size of the undo and redo history, with the cells it holds, or 0 if history is off.
+
+    pub fn history_bytes(
+        &self
+    ) -> usize
+    {
+        self.history_bytes
+    }
+
+    /// Gets the size of everything else held by the grid: merged and typed columns, validators,
+    /// the row tree, the selection, dirty rows, and the highlighted search pattern.
+    ///
+    /// Entries of ordered maps and sets are estimated from the size of their keys and values,
+    /// and validators from the size of their captured state; a compiled regex pattern is not counted.
+
+    pub fn metadata_bytes(
+        &self
+    ) -> usize
+    {
+        self.metadata_bytes
+    }
+
+    /// Gets the total heap memory held by the grid.
+
+    pub fn total_bytes(
+        &self
+    ) -> usize
+    {
+        self.cell_bytes + self.data_bytes + self.capacity_overhead + self.layout_bytes + self.history_bytes + self.metadata_bytes
+    }
+
+}
+
+impl Grid {
+
+    /// Measures the memory held by the grid, for reporting and tuning memory consumption.
+    ///
+    /// Every cell is visited, so this takes time proportional to the size of the grid.
+
+    pub fn memory_footprint(
+        &self
+    ) -> MemoryStats
+    {
+        let cell_count = self.row_size() * self.col_size();
+        let mut shared = BTreeSet::new();
+        let mut data_bytes = 0;
+        let mut spare_data_bytes = 0;
+        for cell in self.flat_iter() {
+            let (used, spare) = cell.heap_usage(&mut shared);
+            data_bytes += used;
+            spare_data_bytes += spare;
+        }
+        let spare_cells = self.cell_capacity() - cell_count;
+        let history_bytes = self.history().heap_bytes(&mut shared);
+        let validator_bytes: usize = self.col_validators().values().map(|validator| btree_bytes::<(usize, ColValidator)>(1) + validator.heap_bytes()).sum();
+        let metadata_bytes = btree_bytes::<usize>(self.merged_cols().len())
+            + btree_bytes::<(usize, ColType)>(self.col_types().len())
+            + validator_bytes
+            + self.tree().heap_bytes()
+            + self.selection().heap_bytes()
+            + self.dirty_rows().heap_bytes()
+            + self.match_highlight().heap_bytes();
+
+        MemoryStats {
+            cell_count,
+            cell_bytes: cell_count * size_of::<Cell>(),
+            data_bytes,
+            capacity_overhead: spare_cells * size_of::<Cell>() + spare_data_bytes,
+            layout_bytes: self.layout_cache().heap_bytes(),
+            history_bytes,
+            metadata_bytes,
+        }
+    }
+
+}
+
+/// Estimates the heap bytes used by the entries of an ordered map or set, from the size of each entry.
+///
+/// The nodes of `BTreeMap` and `BTreeSet` are not public, so their own bookkeeping is not counted.
+
+pub(crate) fn btree_bytes<T>(
+    len: usize
+) -> usize
+{
+    len * size_of::<T>()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{ColType, FontStyle, Grid};
+
+    #[test]
+    fn counts_history_and_metadata() {
+        let mut grid = Grid::from([["a", "1"], ["b", "2"]]);
+        let stats = grid.memory_footprint();
+        assert_eq!(stats.history_bytes(), 0);
+        assert_eq!(stats.metadata_bytes(), 0);
+
+        grid.enable_history();
+        grid.set_cell(0, 0, "changed");
+        let stats = grid.memory_footprint();
+        assert!(stats.history_bytes() > 0);
+
+        grid.set_col_type(1, ColType::Numeric);
+        grid.select_row(0);
+        grid.highlight_matches("needle", FontStyle::default());
+        let with_metadata = grid.memory_footprint();
+        assert!(with_metadata.metadata_bytes() >= "needle".len());
+        assert_eq!(
+            with_metadata.total_bytes(),
+            with_metadata.cell_bytes() + with_metadata.data_bytes() + with_metadata.capacity_overhead()
+                + with_metadata.layout_bytes() + with_metadata.history_bytes() + with_metadata.metadata_bytes()
+        );
+    }
+
+}
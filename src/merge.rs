@@ -0,0 +1,44 @@
+use crate::error::ErrorMessage;
+use crate::grid::Grid;
+
+impl Grid {
+
+    /// Visually merges runs of identical cells in a column into a single cell spanning their rows,
+    /// e.g. to show a repeated category or date only once.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// The cells are not modified: when rendering, the first cell of each run is shown and the
+    /// cells below it are left blank, without row separators between them. Only the data is compared,
+    /// empty cells are never merged, and footer rows stay separate. Runs follow the rows as they
+    /// are rendered, so they are merged again after sorting, and within a filtered view or page.
+
+    pub fn merge_duplicates(
+        &mut self,
+        col_index: usize,
+    )
+    {
+        if col_index >= self.col_size() {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        self.set_merged_col(col_index, true);
+    }
+
+    /// Stops merging runs of identical cells in a column, as set by `merge_duplicates`.
+    ///
+    /// Panics if the index is out of bounds.
+
+    pub fn unmerge_duplicates(
+        &mut self,
+        col_index: usize,
+    )
+    {
+        if col_index >= self.col_size() {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        self.set_merged_col(col_index, false);
+    }
+
+}
@@ -0,0 +1,61 @@
+use crate::cell::Cell;
+use crate::grid::Grid;
+
+impl Grid {
+
+    /// Finds the index of the column whose header, the cell in the first row, holds the given name,
+    /// so code can refer to columns by name and keep working when they are reordered.
+    ///
+    /// Names are compared exactly, and the first matching column is returned.
+    /// Returns None if no header cell holds the name.
+
+    pub fn col_index(
+        &self,
+        name: &str,
+    ) -> Option<usize>
+    {
+        self.row_iter(0).position(|cell| cell.get_data() == name)
+    }
+
+    /// Get an immutable reference to the cell at the specified row in the column with the given header name.
+    ///
+    /// The row index counts the header as row 0, like `get_cell`.
+    /// Returns None if the row index is out of bounds or no column has the name.
+
+    pub fn get_cell_by_name(
+        &self,
+        row_index: usize,
+        name: &str,
+    ) -> Option<&Cell>
+    {
+        self.get_cell(row_index, self.col_index(name)?)
+    }
+
+    /// Get a mutable reference to the cell at the specified row in the column with the given header name.
+    ///
+    /// Returns None if the row index is out of bounds or no column has the name.
+
+    pub fn get_cell_by_name_mut(
+        &mut self,
+        row_index: usize,
+        name: &str,
+    ) -> Option<&mut Cell>
+    {
+        let col_index = self.col_index(name)?;
+        self.get_cell_mut(row_index, col_index)
+    }
+
+    /// Get an immutable iterator over the cells below the header in the column with the given header name.
+    ///
+    /// If no column has the name, returns an empty iterator.
+
+    pub fn col_iter_by_name(
+        &self,
+        name: &str,
+    ) -> impl DoubleEndedIterator<Item = &Cell> + ExactSizeIterator
+    {
+        let col_index = self.col_index(name).unwrap_or(self.col_size());
+        self.col_iter(col_index).skip(1)
+    }
+
+}
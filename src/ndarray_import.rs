@@ -0,0 +1,88 @@
+use crate::align::Align;
+use crate::cell::Cell;
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+
+use ndarray::Array2;
+use std::fmt::Display;
+
+impl<T: Display> From<&Array2<T>> for Grid {
+
+    /// Converts a matrix into a grid, see `Grid::from_array`.
+
+    fn from(
+        array: &Array2<T>,
+    ) -> Self
+    {
+        Grid::from_array(array)
+    }
+
+}
+
+impl Grid {
+
+    /// Create a new grid from a matrix, with one row of cells per matrix row.
+    ///
+    /// Every element is formatted with `Display` and right-aligned, so numbers line up.
+    /// This is the same as `let grid: Grid = (&array).into();`.
+    /// Note that `Grid::from(&array)` calls the nested iterator constructor instead,
+    /// which does not accept a matrix.
+
+    pub fn from_array<T: Display>(
+        array: &Array2<T>,
+    ) -> Self
+    {
+        Grid::from(array.rows().into_iter().map(|row| row.into_iter().map(Grid::array_cell)))
+    }
+
+    /// Create a new grid from a matrix with row and column labels, such as sample and feature names.
+    ///
+    /// The column labels are placed in a header row, formatted in bold and centered,
+    /// and the row labels in a first column, formatted in bold.
+    /// Pass an empty iterator, like `[""; 0]`, to leave out either kind of label.
+    /// Labels beyond the size of the matrix are ignored, and missing ones are left empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `array` - The matrix to convert
+    /// * `row_labels` - The labels of the rows
+    /// * `col_labels` - The labels of the columns
+
+    pub fn from_labeled_array<T: Display>(
+        array: &Array2<T>,
+        row_labels: impl IntoIterator<Item = impl Into<Cell>>,
+        col_labels: impl IntoIterator<Item = impl Into<Cell>>,
+    ) -> Self
+    {
+        let (row_size, col_size) = array.dim();
+        let row_labels: Vec<Cell> = row_labels.into_iter().take(row_size).map(Into::into).collect();
+        let col_labels: Vec<Cell> = col_labels.into_iter().take(col_size).map(Into::into).collect();
+
+        let mut grid = Grid::from_array(array);
+        if !row_labels.is_empty() {
+            let mut row_labels = row_labels;
+            for label in &mut row_labels {
+                label.set_style(FontStyle::Bold);
+            }
+            grid.insert_col(0, row_labels);
+        }
+        if !col_labels.is_empty() {
+            let corner = (grid.col_size() > col_size).then(Cell::default);
+            grid.insert_row(0, corner.into_iter().chain(col_labels));
+            grid.style_header_row();
+        }
+        grid
+    }
+
+    /// Formats an element of a matrix as a right-aligned cell.
+
+    fn array_cell<T: Display>(
+        value: &T,
+    ) -> Cell
+    {
+        let mut cell = Cell::from(value);
+        cell.set_align(Align::Right);
+        cell
+    }
+
+}
@@ -0,0 +1,242 @@
+use crate::border::BorderStyle;
+
+/// Whether ANSI colors and font styles are emitted when rendering.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "lowercase"))]
+pub enum ColorMode {
+    /// Emit ANSI escape sequences for every formatted cell.
+    #[default]
+    Always,
+    /// Never emit ANSI escape sequences. The layout is unchanged.
+    Never,
+}
+
+/// Presentation options used when rendering a grid.
+///
+/// Keeps all presentation knobs out of the grid's data model.
+/// The `Display` implementation of `Grid` renders with the default options,
+/// and `Grid::render_with` renders with custom ones.
+///
+/// With the `serde` feature, the options can be loaded from configuration files.
+/// Every field is optional and defaults to the value of `RenderOptions::without_env`,
+/// so the `FLATGRID_*` environment variables never change what a file means:
+/// `border_style` (`"unicode"`, `"ascii"`, or `"none"`), `color_mode` (`"always"` or `"never"`),
+/// `padding`, `gap`, `row_separators`, `max_col_width`, and `max_width`.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default = "RenderOptions::without_env"))]
+pub struct RenderOptions {
+    border_style: BorderStyle,
+    color_mode: ColorMode,
+    padding: usize,
+    gap: usize,
+    row_separators: bool,
+    max_col_width: Option<usize>,
+    max_width: Option<usize>,
+}
+
+impl Default for RenderOptions {
+
+    fn default() -> Self {
+        RenderOptions::new()
+    }
+
+}
+
+impl RenderOptions {
+
+    /// Creates the default render options.
+    ///
+    /// Unicode borders, ANSI colors enabled, one space of padding,
+    /// separators between rows, and no column or table width limit.
+    /// The `FLATGRID_*` environment variables override these defaults,
+    /// unless disabled with `RenderOptions::set_env_enabled`; see `RenderOptions::apply_env`.
+    /// The variables are read once, when the first default options are created.
+
+    pub fn new() -> Self {
+        #[cfg(feature = "std")]
+        if RenderOptions::env_enabled() {
+            return RenderOptions::env_defaults().clone();
+        }
+        RenderOptions::without_env()
+    }
+
+    /// Creates the built-in default render options, ignoring the `FLATGRID_*` environment variables.
+
+    pub fn without_env() -> Self {
+        RenderOptions {
+            border_style: BorderStyle::Unicode,
+            color_mode: ColorMode::Always,
+            padding: 1,
+            gap: 2,
+            row_separators: true,
+            max_col_width: None,
+            max_width: None,
+        }
+    }
+
+    /// Gets the border style.
+
+    pub fn border_style(
+        &self
+    ) -> BorderStyle
+    {
+        self.border_style
+    }
+
+    /// Sets the border style.
+
+    pub fn set_border_style(
+        &mut self,
+        new_border_style: BorderStyle,
+    )
+    {
+        self.border_style = new_border_style;
+    }
+
+    /// Gets the color mode.
+
+    pub fn color_mode(
+        &self
+    ) -> ColorMode
+    {
+        self.color_mode
+    }
+
+    /// Sets the color mode.
+    ///
+    /// `ColorMode::Never` renders the exact same layout without any escape sequences.
+
+    pub fn set_color_mode(
+        &mut self,
+        new_color_mode: ColorMode,
+    )
+    {
+        self.color_mode = new_color_mode;
+    }
+
+    /// Gets the number of spaces between the vertical borders and the cell content.
+
+    pub fn padding(
+        &self
+    ) -> usize
+    {
+        self.padding
+    }
+
+    /// Sets the number of spaces between the vertical borders and the cell content.
+    ///
+    /// Has no effect with `BorderStyle::None`, use `set_gap` instead.
+
+    pub fn set_padding(
+        &mut self,
+        new_padding: usize,
+    )
+    {
+        self.padding = new_padding;
+    }
+
+    /// Gets the number of spaces between columns when rendering without borders.
+
+    pub fn gap(
+        &self
+    ) -> usize
+    {
+        self.gap
+    }
+
+    /// Sets the number of spaces between columns when rendering without borders.
+    ///
+    /// Only used with `BorderStyle::None`.
+
+    pub fn set_gap(
+        &mut self,
+        new_gap: usize,
+    )
+    {
+        self.gap = new_gap;
+    }
+
+    /// Gets whether separator lines are drawn between rows.
+
+    pub fn row_separators(
+        &self
+    ) -> bool
+    {
+        self.row_separators
+    }
+
+    /// Sets whether separator lines are drawn between rows.
+
+    pub fn set_row_separators(
+        &mut self,
+        new_row_separators: bool,
+    )
+    {
+        self.row_separators = new_row_separators;
+    }
+
+    /// Gets the maximum width of a column, if any.
+
+    pub fn max_col_width(
+        &self
+    ) -> Option<usize>
+    {
+        self.max_col_width
+    }
+
+    /// Sets the maximum width of every column.
+    ///
+    /// Cell lines wider than the limit are truncated.
+
+    pub fn set_max_col_width(
+        &mut self,
+        new_max_col_width: usize,
+    )
+    {
+        self.max_col_width = Some(new_max_col_width);
+    }
+
+    /// Clears the column width limit.
+
+    pub fn clear_max_col_width(
+        &mut self
+    )
+    {
+        self.max_col_width = None;
+    }
+
+    /// Gets the maximum width of the whole table, if any.
+
+    pub fn max_width(
+        &self
+    ) -> Option<usize>
+    {
+        self.max_width
+    }
+
+    /// Sets the maximum width of the whole table, borders and padding included.
+    ///
+    /// The widest columns are narrowed until the table fits, and cell lines wider
+    /// than their column are truncated. Columns are never narrowed below one character,
+    /// so a table with many columns may still exceed a very small limit.
+
+    pub fn set_max_width(
+        &mut self,
+        new_max_width: usize,
+    )
+    {
+        self.max_width = Some(new_max_width);
+    }
+
+    /// Clears the table width limit.
+
+    pub fn clear_max_width(
+        &mut self
+    )
+    {
+        self.max_width = None;
+    }
+
+}
@@ -0,0 +1,239 @@
+use crate::grid::Grid;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// The symbols of a border style, as recognized when parsing a rendered table.
+
+struct Symbols {
+    top_left: char,
+    top_middle: char,
+    top_right: char,
+    bottom_left: char,
+    vertical: char,
+    horizontal: char,
+}
+
+impl Symbols {
+
+    const UNICODE: Symbols = Symbols {
+        top_left: '┌',
+        top_middle: '┬',
+        top_right: '┐',
+        bottom_left: '└',
+        vertical: '│',
+        horizontal: '─',
+    };
+
+    const ASCII: Symbols = Symbols {
+        top_left: '+',
+        top_middle: '+',
+        top_right: '+',
+        bottom_left: '+',
+        vertical: '|',
+        horizontal: '-',
+    };
+
+    /// Gets the symbols of the border style whose top border is the given line,
+    /// along with the width of every column, padding included.
+
+    fn from_top_border(
+        line: &str,
+    ) -> Option<(&'static Symbols, Vec<usize>)>
+    {
+        let symbols = match line.chars().next()? {
+            '┌' => &Symbols::UNICODE,
+            '+' => &Symbols::ASCII,
+            _ => return None,
+        };
+        let inner = line.strip_prefix(symbols.top_left)?.strip_suffix(symbols.top_right)?;
+        let col_widths: Vec<usize> = inner.split(symbols.top_middle)
+            .map(|segment| segment.chars().all(|c| c == symbols.horizontal).then(|| segment.chars().count()))
+            .collect::<Option<_>>()?;
+        if col_widths.contains(&0) {
+            return None;
+        }
+        Some((symbols, col_widths))
+    }
+
+    /// Checks whether the line is a horizontal border between or below rows.
+
+    fn is_border(
+        &self,
+        line: &str,
+    ) -> bool
+    {
+        line.contains(self.horizontal)
+            && line.chars().all(|c| c == self.horizontal || "├┼┤└┴┘┬+".contains(c))
+    }
+
+    /// Splits a text line of the table into the text of its cells, padding included.
+    ///
+    /// Cells are cut at the column widths of the top border, so cells containing the
+    /// vertical border symbol are kept whole. The widths are counted in characters, as the
+    /// border symbols are, or else in bytes, which is how flatgrid pads multibyte text.
+    /// If the line matches neither, it is split at the vertical border symbols instead.
+
+    fn split_cells<'a>(
+        &self,
+        line: &'a str,
+        col_widths: &[usize],
+    ) -> Vec<&'a str>
+    {
+        let mut rest = line.strip_prefix(self.vertical).unwrap_or(line);
+        let mut cells = Vec::with_capacity(col_widths.len());
+        for &col_width in col_widths {
+            // The byte offset of the character at the column width, or the end of the line
+            let char_offset = rest.char_indices().nth(col_width).map_or(rest.len(), |(index, _)| index);
+            let fitting_offset = [char_offset, col_width].into_iter()
+                .find(|&offset| rest.get(offset..).is_some_and(|after| after.starts_with(self.vertical)));
+            let (cell, after) = match (fitting_offset, rest.find(self.vertical)) {
+                (Some(offset), _) => rest.split_at(offset),
+                (None, Some(index)) => rest.split_at(index),
+                (None, None) => (rest, ""),
+            };
+            cells.push(cell);
+            rest = after.strip_prefix(self.vertical).unwrap_or(after);
+        }
+        cells
+    }
+
+}
+
+impl Grid {
+
+    /// Parse a table rendered by flatgrid, such as one found in logs or docs, back into a grid.
+    ///
+    /// Both `BorderStyle::Unicode` (`┌─┬─┐`) and `BorderStyle::Ascii` (`+-+-+`) borders are recognized,
+    /// with any padding. Text before the table is skipped, and parsing stops at the end of the
+    /// first table. ANSI escape sequences are ignored, so colored output can be parsed as well.
+    ///
+    /// The lines between two horizontal borders form a row, so multiline cells are restored
+    /// as long as the table was rendered with row separators (the default).
+    /// The padding around cell content is removed, so formatting and alignment are not restored.
+    ///
+    /// # Returns
+    ///
+    /// The parsed grid, or `None` if the text contains no table
+
+    pub fn parse(
+        text: &str,
+    ) -> Option<Self>
+    {
+        let mut lines = text.lines().map(|line| strip_ansi(line).trim().to_string());
+        let (symbols, col_widths) = lines.by_ref().find_map(|line| Symbols::from_top_border(&line))?;
+
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        let mut row_lines: Vec<String> = Vec::new();
+        for line in lines {
+            if symbols.is_border(&line) {
+                // Every border after the top one closes a row, even a row without any lines
+                rows.push(join_row_lines(symbols, &row_lines, &col_widths));
+                row_lines.clear();
+                if line.starts_with(symbols.bottom_left) && symbols.bottom_left != symbols.top_left {
+                    break;
+                }
+            } else if line.starts_with(symbols.vertical) {
+                row_lines.push(line);
+            } else {
+                break;
+            }
+        }
+        // The table may be cut off before its bottom border
+        if !row_lines.is_empty() {
+            rows.push(join_row_lines(symbols, &row_lines, &col_widths));
+        }
+
+        let mut grid = Grid::from(rows);
+        if grid.col_size() < col_widths.len() {
+            grid.resize(grid.row_size(), col_widths.len());
+        }
+        Some(grid)
+    }
+
+}
+
+/// Joins the text lines of a table row into the content of its cells.
+///
+/// The padding shared by all lines of a cell is removed, keeping any further indentation,
+/// and the empty lines added by vertical alignment are dropped.
+
+fn join_row_lines(
+    symbols: &Symbols,
+    row_lines: &[String],
+    col_widths: &[usize],
+) -> Vec<String>
+{
+    let split_lines: Vec<Vec<&str>> = row_lines.iter()
+        .map(|line| symbols.split_cells(line, col_widths))
+        .collect();
+
+    (0..col_widths.len())
+        .map(|col_index| {
+            let cell_lines: Vec<&str> = split_lines.iter()
+                .map(|cells| cells.get(col_index).map_or("", |cell| cell.trim_end()))
+                .collect();
+            let indent = cell_lines.iter()
+                .filter(|line| !line.is_empty())
+                .map(|line| line.len() - line.trim_start().len())
+                .min()
+                .unwrap_or(0);
+            let cell_lines: Vec<&str> = cell_lines.iter()
+                .map(|line| line.get(indent..).unwrap_or(""))
+                .collect();
+            let first = cell_lines.iter().position(|line| !line.is_empty()).unwrap_or(cell_lines.len());
+            let last = cell_lines.iter().rposition(|line| !line.is_empty()).map_or(first, |last| last + 1);
+            cell_lines[first..last].join("\n")
+        })
+        .collect()
+}
+
+/// Removes the ANSI escape sequences (`ESC [ ... letter`) from a line.
+
+fn strip_ansi(
+    line: &str,
+) -> String
+{
+    let mut text = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            if chars.clone().next() == Some('[') {
+                chars.by_ref().find(|c| c.is_ascii_alphabetic());
+            }
+            continue;
+        }
+        text.push(c);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Grid, RenderOptions};
+
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    fn first_row(
+        text: &str
+    ) -> Vec<String>
+    {
+        let grid = Grid::parse(text).unwrap();
+        grid.row_iter(0).map(|cell| cell.get_data().to_string()).collect()
+    }
+
+    #[test]
+    fn splits_multibyte_cells_aligned_by_characters() {
+        let text = "┌────┬───┐\n│ é│ │ a │\n└────┴───┘";
+        assert_eq!(first_row(text), ["é│", "a"]);
+    }
+
+    #[test]
+    fn round_trips_multibyte_cells_padded_by_bytes() {
+        let grid = Grid::from([["é│x", "a"], ["bb", "c"]]);
+        assert_eq!(first_row(&grid.render_with(&RenderOptions::without_env())), ["é│x", "a"]);
+    }
+
+}
@@ -0,0 +1,183 @@
+use crate::align::Align;
+use crate::cell::Cell;
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+
+use polars::prelude::{AnyValue, Column, DataFrame, PolarsResult};
+
+/// Options used when converting a DataFrame into a grid.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataFrameOptions {
+    max_rows: Option<usize>,
+    show_dtypes: bool,
+    float_precision: Option<usize>,
+}
+
+impl Default for DataFrameOptions {
+
+    fn default() -> Self {
+        DataFrameOptions::new()
+    }
+
+}
+
+impl DataFrameOptions {
+
+    /// Creates the default DataFrame options.
+    ///
+    /// At most 10 rows are shown, data types are shown under the column names,
+    /// and floats are formatted like polars does.
+
+    pub fn new() -> Self {
+        DataFrameOptions {
+            max_rows: Some(10),
+            show_dtypes: true,
+            float_precision: None,
+        }
+    }
+
+    /// Gets the maximum number of rows shown, if limited.
+
+    pub fn max_rows(
+        &self
+    ) -> Option<usize>
+    {
+        self.max_rows
+    }
+
+    /// Sets the maximum number of rows shown, or `None` to show every row.
+    ///
+    /// Longer DataFrames show their first and last rows, separated by a row of `...`.
+
+    pub fn set_max_rows(
+        &mut self,
+        new_max_rows: Option<usize>,
+    )
+    {
+        self.max_rows = new_max_rows;
+    }
+
+    /// Gets whether the data type of each column is shown under its name.
+
+    pub fn show_dtypes(
+        &self
+    ) -> bool
+    {
+        self.show_dtypes
+    }
+
+    /// Sets whether the data type of each column is shown under its name.
+
+    pub fn set_show_dtypes(
+        &mut self,
+        new_show_dtypes: bool,
+    )
+    {
+        self.show_dtypes = new_show_dtypes;
+    }
+
+    /// Gets the number of decimals floats are formatted with, if fixed.
+
+    pub fn float_precision(
+        &self
+    ) -> Option<usize>
+    {
+        self.float_precision
+    }
+
+    /// Sets the number of decimals floats are formatted with, or `None` to format them like polars does.
+
+    pub fn set_float_precision(
+        &mut self,
+        new_float_precision: Option<usize>,
+    )
+    {
+        self.float_precision = new_float_precision;
+    }
+
+}
+
+impl Grid {
+
+    /// Create a new grid from a polars DataFrame.
+    ///
+    /// The first row is a header holding the column names, and their data types if enabled,
+    /// formatted in bold and centered.
+    /// Numeric columns are right-aligned, strings are shown without quotes,
+    /// and null values are shown as a dimmed `null`.
+    /// DataFrames longer than the row limit show as many of their first and last rows,
+    /// separated by a dimmed row of `...`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a value cannot be read from its column
+
+    pub fn from_dataframe(
+        dataframe: &DataFrame,
+        options: &DataFrameOptions,
+    ) -> PolarsResult<Self>
+    {
+        let columns = dataframe.get_columns();
+        let height = dataframe.height();
+
+        let header = columns.iter().map(|column| match options.show_dtypes() {
+            true => Cell::new(format!("{}\n{}", column.name(), column.dtype())),
+            false => Cell::new(column.name().as_str()),
+        });
+        let mut rows: Vec<Vec<Cell>> = vec![header.collect()];
+
+        let (head, tail) = match options.max_rows() {
+            Some(max_rows) if height > max_rows => (max_rows.div_ceil(2), max_rows / 2),
+            _ => (height, 0),
+        };
+        for row_index in 0..head {
+            rows.push(Grid::dataframe_row(columns, row_index, options)?);
+        }
+        if head + tail < height {
+            let ellipsis = columns.iter().map(|_| {
+                let mut cell = Cell::new("...");
+                cell.set_style(FontStyle::Dim);
+                cell.set_align(Align::Center);
+                cell
+            });
+            rows.push(ellipsis.collect());
+        }
+        for row_index in height - tail..height {
+            rows.push(Grid::dataframe_row(columns, row_index, options)?);
+        }
+
+        let mut grid = Grid::from(rows);
+        grid.style_header_row();
+        Ok(grid)
+    }
+
+    /// Converts a row of a DataFrame into cells.
+
+    fn dataframe_row(
+        columns: &[Column],
+        row_index: usize,
+        options: &DataFrameOptions,
+    ) -> PolarsResult<Vec<Cell>>
+    {
+        columns.iter()
+            .map(|column| {
+                let value = column.get(row_index)?;
+                let mut cell = match (&value, options.float_precision()) {
+                    (AnyValue::Null, _) => return Ok(Cell::null()),
+                    (AnyValue::Float32(number), Some(precision)) => Cell::new(format!("{:.*}", precision, number)),
+                    (AnyValue::Float64(number), Some(precision)) => Cell::new(format!("{:.*}", precision, number)),
+                    _ => match value.get_str() {
+                        Some(text) => Cell::new(text),
+                        None => Cell::from(&value),
+                    },
+                };
+                if column.dtype().is_primitive_numeric() {
+                    cell.set_align(Align::Right);
+                }
+                Ok(cell)
+            })
+            .collect()
+    }
+
+}
@@ -0,0 +1,50 @@
+use crate::align::AlignH;
+use crate::cell::Cell;
+use crate::grid::Grid;
+
+use prettytable::format::Alignment;
+use prettytable::{Row, Table};
+
+impl From<&Grid> for Table {
+
+    /// Converts a grid into a prettytable `Table`, with one table row per grid row.
+    ///
+    /// The data and horizontal alignment of every cell are carried over;
+    /// other formatting is left to the table's own styling.
+
+    fn from(
+        grid: &Grid,
+    ) -> Self
+    {
+        let mut table = Table::new();
+        for row_index in 0..grid.row_size() {
+            let row = grid.row_iter(row_index).map(|cell| {
+                let align = match cell.h_align() {
+                    Some(AlignH::Center) => Alignment::CENTER,
+                    Some(AlignH::Right)  => Alignment::RIGHT,
+                    Some(AlignH::Left) | None => Alignment::LEFT,
+                };
+                prettytable::Cell::new_align(cell.get_data(), align)
+            });
+            table.add_row(Row::new(row.collect()));
+        }
+        table
+    }
+
+}
+
+impl From<&Table> for Grid {
+
+    /// Converts the rows of a prettytable `Table` into a grid, using `let grid: Grid = (&table).into();`.
+    ///
+    /// Only the content of the cells is carried over. Titles set with `Table::set_titles`
+    /// cannot be read back from the table, so they are not included.
+
+    fn from(
+        table: &Table,
+    ) -> Self
+    {
+        Grid::from(table.row_iter().map(|row| row.iter().map(|cell| Cell::new(cell.get_content()))))
+    }
+
+}
@@ -0,0 +1,323 @@
+// The pyo3 macros turn doc comments into attributes, which trips this lint with the blank line after them
+#![allow(clippy::empty_line_after_outer_attr)]
+
+use crate::align::Align;
+use crate::cell::Cell;
+use crate::color::{Background, Foreground};
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+
+/// Python class wrapping a `Cell`.
+
+#[pyclass(name = "Cell", module = "flatgrid", from_py_object)]
+#[derive(Clone)]
+pub struct PyCell {
+    cell: Cell,
+}
+
+#[pymethods]
+impl PyCell {
+
+    #[new]
+    #[pyo3(signature = (data = String::new()))]
+    fn new(
+        data: String,
+    ) -> Self
+    {
+        PyCell { cell: Cell::new(data) }
+    }
+
+    #[getter]
+    fn data(
+        &self
+    ) -> &str
+    {
+        self.cell.get_data()
+    }
+
+    #[setter]
+    fn set_data(
+        &mut self,
+        new_data: String,
+    )
+    {
+        self.cell.set_data(new_data);
+    }
+
+    /// Sets the foreground color by name, e.g. `"red"` or `"bright blue"`.
+
+    fn set_color(
+        &mut self,
+        color: &str,
+    ) -> PyResult<()>
+    {
+        if Foreground::from_str(color).is_none() {
+            return Err(PyValueError::new_err(format!("unknown color: {:?}", color)));
+        }
+        self.cell.set_color(color);
+        Ok(())
+    }
+
+    /// Sets the background color by name, e.g. `"red"` or `"bright blue"`.
+
+    fn set_highlight(
+        &mut self,
+        color: &str,
+    ) -> PyResult<()>
+    {
+        if Background::from_str(color).is_none() {
+            return Err(PyValueError::new_err(format!("unknown color: {:?}", color)));
+        }
+        self.cell.set_highlight(color);
+        Ok(())
+    }
+
+    /// Sets the font styles by name, e.g. `cell.set_style("bold", "underline")`.
+
+    #[pyo3(signature = (*styles))]
+    fn set_style(
+        &mut self,
+        styles: &Bound<'_, PyTuple>,
+    ) -> PyResult<()>
+    {
+        self.cell.set_style(parse_styles(styles)?);
+        Ok(())
+    }
+
+    /// Sets the alignment by name, e.g. `cell.set_align("right", "middle")`.
+
+    #[pyo3(signature = (*aligns))]
+    fn set_align(
+        &mut self,
+        aligns: &Bound<'_, PyTuple>,
+    ) -> PyResult<()>
+    {
+        self.cell.set_align(parse_aligns(aligns)?);
+        Ok(())
+    }
+
+    fn __str__(
+        &self
+    ) -> &str
+    {
+        self.cell.get_data()
+    }
+
+    fn __repr__(
+        &self
+    ) -> String
+    {
+        format!("Cell({:?})", self.cell.get_data())
+    }
+
+}
+
+/// Python class wrapping a `Grid`.
+
+#[pyclass(name = "Grid", module = "flatgrid")]
+pub struct PyGrid {
+    grid: Grid,
+}
+
+#[pymethods]
+impl PyGrid {
+
+    #[new]
+    #[pyo3(signature = (rows = 0, cols = 0))]
+    fn new(
+        rows: usize,
+        cols: usize,
+    ) -> Self
+    {
+        PyGrid { grid: Grid::new(rows, cols) }
+    }
+
+    /// Creates a grid from a list of rows, whose items are `Cell`s or any values, converted with `str()`.
+
+    #[staticmethod]
+    fn from_rows(
+        rows: Vec<Vec<Bound<'_, PyAny>>>,
+    ) -> PyResult<Self>
+    {
+        let rows = rows.iter()
+            .map(|row| row.iter().map(to_cell).collect::<PyResult<Vec<Cell>>>())
+            .collect::<PyResult<Vec<Vec<Cell>>>>()?;
+        Ok(PyGrid { grid: Grid::from(rows) })
+    }
+
+    #[getter]
+    fn row_size(
+        &self
+    ) -> usize
+    {
+        self.grid.row_size()
+    }
+
+    #[getter]
+    fn col_size(
+        &self
+    ) -> usize
+    {
+        self.grid.col_size()
+    }
+
+    /// Gets a copy of the cell at the given row and column.
+
+    fn get_cell(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> PyResult<PyCell>
+    {
+        match self.grid.get_cell(row, col) {
+            Some(cell) => Ok(PyCell { cell: cell.clone() }),
+            None => Err(PyIndexError::new_err("cell index out of bounds")),
+        }
+    }
+
+    /// Sets the cell at the given row and column to a `Cell` or any value, converted with `str()`.
+
+    fn set_cell(
+        &mut self,
+        row: usize,
+        col: usize,
+        value: &Bound<'_, PyAny>,
+    ) -> PyResult<()>
+    {
+        let cell = to_cell(value)?;
+        self.grid.try_set_cell(row, col, cell)
+            .map_err(|_| PyIndexError::new_err("cell index out of bounds"))
+    }
+
+    /// Sets the font styles of every cell in a row by name, e.g. `grid.style_row(0, "bold")`.
+
+    #[pyo3(signature = (row, *styles))]
+    fn style_row(
+        &mut self,
+        row: usize,
+        styles: &Bound<'_, PyTuple>,
+    ) -> PyResult<()>
+    {
+        if row >= self.grid.row_size() {
+            return Err(PyIndexError::new_err("row index out of bounds"));
+        }
+        let style = parse_styles(styles)?;
+        self.grid.row_iter_mut(row).for_each(|cell| cell.set_style(style));
+        Ok(())
+    }
+
+    /// Sets the alignment of every cell in a column by name, e.g. `grid.align_col(1, "right")`.
+
+    #[pyo3(signature = (col, *aligns))]
+    fn align_col(
+        &mut self,
+        col: usize,
+        aligns: &Bound<'_, PyTuple>,
+    ) -> PyResult<()>
+    {
+        if col >= self.grid.col_size() {
+            return Err(PyIndexError::new_err("column index out of bounds"));
+        }
+        let align = parse_aligns(aligns)?;
+        self.grid.col_iter_mut(col).for_each(|cell| cell.set_align(align));
+        Ok(())
+    }
+
+    /// Renders the grid with ANSI formatting, like the `Display` output.
+
+    fn render(
+        &self
+    ) -> String
+    {
+        self.grid.to_string()
+    }
+
+    /// Renders the grid without any ANSI escape sequences.
+
+    fn render_plain(
+        &self
+    ) -> String
+    {
+        self.grid.render_plain()
+    }
+
+    fn __str__(
+        &self
+    ) -> String
+    {
+        self.grid.to_string()
+    }
+
+    fn __repr__(
+        &self
+    ) -> String
+    {
+        format!("Grid(rows={}, cols={})", self.grid.row_size(), self.grid.col_size())
+    }
+
+}
+
+/// The `flatgrid` Python module.
+
+#[pymodule]
+fn flatgrid(
+    module: &Bound<'_, PyModule>,
+) -> PyResult<()>
+{
+    module.add_class::<PyGrid>()?;
+    module.add_class::<PyCell>()?;
+    Ok(())
+}
+
+/// Converts a Python value into a cell: `Cell`s are copied, and other values converted with `str()`.
+
+fn to_cell(
+    value: &Bound<'_, PyAny>,
+) -> PyResult<Cell>
+{
+    if let Ok(cell) = value.cast::<PyCell>() {
+        return Ok(cell.borrow().cell.clone());
+    }
+    Ok(Cell::new(value.str()?.to_str()?))
+}
+
+/// Combines font style names, such as `"bold"` and `"underline"`, into a `FontStyle`.
+
+fn parse_styles(
+    styles: &Bound<'_, PyTuple>,
+) -> PyResult<FontStyle>
+{
+    let mut font_style = FontStyle::default();
+    for style in styles.iter() {
+        let name: String = style.extract()?;
+        font_style |= FontStyle::from_name(&name)
+            .ok_or_else(|| PyValueError::new_err(format!("unknown font style: {:?}", name)))?;
+    }
+    Ok(font_style)
+}
+
+/// Combines alignment names, such as `"right"` and `"middle"`, into an `Align`.
+
+fn parse_aligns(
+    aligns: &Bound<'_, PyTuple>,
+) -> PyResult<Align>
+{
+    let mut align = Align::from_parts(None, None);
+    for name in aligns.iter() {
+        let name: String = name.extract()?;
+        align |= match name.as_str() {
+            "left"   => Align::Left,
+            "center" => Align::Center,
+            "right"  => Align::Right,
+            "top"    => Align::Top,
+            "middle" => Align::Middle,
+            "bottom" => Align::Bottom,
+            _ => return Err(PyValueError::new_err(format!("unknown alignment: {:?}", name))),
+        };
+    }
+    Ok(align)
+}
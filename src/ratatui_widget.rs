@@ -0,0 +1,173 @@
+use crate::cell::Cell;
+use crate::color::{Foreground, Background};
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+use crate::options::RenderOptions;
+use crate::render::Segment;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color as TuiColor, Modifier, Style};
+use ratatui::widgets::Widget;
+
+use std::borrow::Cow;
+use std::convert::Infallible;
+
+/// A `ratatui` widget that paints a grid into a terminal buffer.
+///
+/// The grid is laid out exactly like the `Display` implementation,
+/// with cell colors and font styles translated to `ratatui` styles.
+/// Anything outside of the widget's area is clipped.
+
+#[derive(Debug, Clone)]
+pub struct GridWidget<'a> {
+    grid: &'a Grid,
+    options: RenderOptions,
+}
+
+impl<'a> GridWidget<'a> {
+
+    /// Creates a widget for the grid using the default render options.
+
+    pub fn new(
+        grid: &'a Grid,
+    ) -> Self
+    {
+        GridWidget { grid, options: grid.default_options() }
+    }
+
+    /// Creates a widget for the grid using the given render options.
+
+    pub fn with_options(
+        grid: &'a Grid,
+        options: RenderOptions,
+    ) -> Self
+    {
+        GridWidget { grid, options }
+    }
+
+}
+
+impl Grid {
+
+    /// Creates a `ratatui` widget for the grid using the default render options.
+
+    pub fn widget(
+        &self
+    ) -> GridWidget<'_>
+    {
+        GridWidget::new(self)
+    }
+
+}
+
+impl Widget for GridWidget<'_> {
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+    )
+    {
+        let layout = self.grid.layout().constrain(&self.options);
+        let mut x = area.x;
+        let mut y = area.y;
+        let _ = self.grid.write_segments(&layout, &self.options, |segment| -> Result<(), Infallible> {
+            let (text, style) = match segment {
+                Segment::Text(text) => (Cow::Borrowed(text), Style::default()),
+                Segment::Spaces(count) => (Cow::Owned(" ".repeat(count)), Style::default()),
+                Segment::Styled(text, cell) => (Cow::Borrowed(text), cell_style(cell)),
+                Segment::LineEnd => {
+                    x = area.x;
+                    y = y.saturating_add(1);
+                    return Ok(());
+                },
+            };
+            // Clip everything outside of the area
+            if y < area.bottom() && x < area.right() {
+                let max_width = (area.right() - x) as usize;
+                (x, _) = buf.set_stringn(x, y, text, max_width, style);
+            }
+            Ok(())
+        });
+    }
+
+}
+
+/// Translates the cell's colors and font styles into a `ratatui` style.
+
+fn cell_style(
+    cell: &Cell,
+) -> Style
+{
+    let mut style = Style::default();
+    if let Some(fg_color) = cell.fg_color() {
+        style = style.fg(foreground_color(fg_color));
+    }
+    if let Some(bg_color) = cell.bg_color() {
+        style = style.bg(background_color(bg_color));
+    }
+    for font_style in cell.font_style() {
+        let modifier = match font_style {
+            FontStyle::Bold      => Modifier::BOLD,
+            FontStyle::Dim       => Modifier::DIM,
+            FontStyle::Italic    => Modifier::ITALIC,
+            FontStyle::Underline => Modifier::UNDERLINED,
+            FontStyle::Blink     => Modifier::SLOW_BLINK,
+            FontStyle::Reverse   => Modifier::REVERSED,
+            FontStyle::Hidden    => Modifier::HIDDEN,
+            FontStyle::Strike    => Modifier::CROSSED_OUT,
+            _                    => Modifier::empty(),
+        };
+        style = style.add_modifier(modifier);
+    }
+    style
+}
+
+fn foreground_color(
+    color: Foreground,
+) -> TuiColor
+{
+    match color {
+        Foreground::Black         => TuiColor::Black,
+        Foreground::Red           => TuiColor::Red,
+        Foreground::Green         => TuiColor::Green,
+        Foreground::Yellow        => TuiColor::Yellow,
+        Foreground::Blue          => TuiColor::Blue,
+        Foreground::Magenta       => TuiColor::Magenta,
+        Foreground::Cyan          => TuiColor::Cyan,
+        Foreground::White         => TuiColor::Gray,
+        Foreground::BrightBlack   => TuiColor::DarkGray,
+        Foreground::BrightRed     => TuiColor::LightRed,
+        Foreground::BrightGreen   => TuiColor::LightGreen,
+        Foreground::BrightYellow  => TuiColor::LightYellow,
+        Foreground::BrightBlue    => TuiColor::LightBlue,
+        Foreground::BrightMagenta => TuiColor::LightMagenta,
+        Foreground::BrightCyan    => TuiColor::LightCyan,
+        Foreground::BrightWhite   => TuiColor::White,
+    }
+}
+
+fn background_color(
+    color: Background,
+) -> TuiColor
+{
+    match color {
+        Background::Black         => TuiColor::Black,
+        Background::Red           => TuiColor::Red,
+        Background::Green         => TuiColor::Green,
+        Background::Yellow        => TuiColor::Yellow,
+        Background::Blue          => TuiColor::Blue,
+        Background::Magenta       => TuiColor::Magenta,
+        Background::Cyan          => TuiColor::Cyan,
+        Background::White         => TuiColor::Gray,
+        Background::BrightBlack   => TuiColor::DarkGray,
+        Background::BrightRed     => TuiColor::LightRed,
+        Background::BrightGreen   => TuiColor::LightGreen,
+        Background::BrightYellow  => TuiColor::LightYellow,
+        Background::BrightBlue    => TuiColor::LightBlue,
+        Background::BrightMagenta => TuiColor::LightMagenta,
+        Background::BrightCyan    => TuiColor::LightCyan,
+        Background::BrightWhite   => TuiColor::White,
+    }
+}
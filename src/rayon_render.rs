@@ -0,0 +1,130 @@
+use crate::format::AnsiPrefixes;
+use crate::grid::Grid;
+use crate::options::RenderOptions;
+use crate::render::{Layout, Segment, write_frame_segments, write_segment};
+
+use rayon::prelude::*;
+use std::io;
+
+impl Grid {
+
+    /// Renders the grid with the default options, rendering its rows in parallel.
+    ///
+    /// The output is identical to the `Display` implementation.
+    /// See `par_render_with` for details.
+
+    pub fn par_render(
+        &self
+    ) -> String
+    {
+        self.par_render_with(&self.default_options())
+    }
+
+    /// Renders the grid using the given render options, rendering its rows in parallel.
+    ///
+    /// Every row is rendered into its own buffer on the `rayon` thread pool,
+    /// then the buffers are joined in order with the borders between them.
+    /// The output is identical to `render_with`, but for grids with many thousands of rows
+    /// it takes a fraction of the time on multi-core machines.
+    /// For small grids the cost of dispatching the rows outweighs the gain.
+
+    pub fn par_render_with(
+        &self,
+        options: &RenderOptions,
+    ) -> String
+    {
+        let layout = self.layout().constrain(options);
+        let rows = self.par_render_rows(&layout, options);
+
+        let mut output = String::with_capacity(rows.iter().map(String::len).sum());
+        let mut prefixes = AnsiPrefixes::new();
+        // Writing into a string never fails
+        let _ = write_frame_segments(&layout, options, &mut |segment| write_segment(&mut output, segment, &mut prefixes), |row_index, emit| {
+            emit(Segment::Text(&rows[row_index - layout.rows.start]))
+        });
+        output
+    }
+
+    /// Renders the grid into a writer with the default options, rendering its rows in parallel.
+    ///
+    /// The output is identical to `render_to`. See `par_render_to_with` for details.
+
+    pub fn par_render_to<W: io::Write>(
+        &self,
+        w: &mut W,
+    ) -> io::Result<()>
+    {
+        self.par_render_to_with(w, &self.default_options())
+    }
+
+    /// Renders the grid into a writer using the given render options, rendering its rows in parallel.
+    ///
+    /// The rows are rendered into buffers in parallel first, then written in order
+    /// along with the borders. The output is identical to `par_render_with`.
+
+    pub fn par_render_to_with<W: io::Write>(
+        &self,
+        w: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()>
+    {
+        let layout = self.layout().constrain(options);
+        let rows = self.par_render_rows(&layout, options);
+        let mut prefixes = AnsiPrefixes::new();
+
+        write_frame_segments(&layout, options, &mut |segment| {
+            match segment {
+                Segment::Text(text) => w.write_all(text.as_bytes()),
+                Segment::Spaces(count) => write!(w, "{:count$}", ""),
+                Segment::Styled(text, cell) => write!(w, "{}", cell.styled(text, &mut prefixes)),
+                Segment::LineEnd => writeln!(w),
+            }
+        }, |row_index, emit| {
+            emit(Segment::Text(&rows[row_index - layout.rows.start]))
+        })
+    }
+
+    /// Renders every row of the layout window into its own buffer, in parallel.
+    ///
+    /// Each buffer holds all visual lines of its row, each followed by a newline.
+    /// Every worker thread keeps its own cache of ANSI prefixes across the rows it renders.
+
+    fn par_render_rows(
+        &self,
+        layout: &Layout,
+        options: &RenderOptions,
+    ) -> Vec<String>
+    {
+        layout.rows.clone()
+            .into_par_iter()
+            .map_init(AnsiPrefixes::new, |prefixes, row_index| {
+                let mut row = String::new();
+                // Writing into a string never fails
+                let _ = self.write_row_segments(row_index, layout, options, &mut |segment| write_segment(&mut row, segment, prefixes));
+                row
+            })
+            .collect()
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{BorderStyle, ColorMode, Grid, RenderOptions};
+
+    #[test]
+    fn par_render_to_with_uses_the_given_options() {
+        let grid = Grid::from([["first", "a long second column"], ["third", "fourth"]]);
+        let mut options = RenderOptions::without_env();
+        options.set_border_style(BorderStyle::Ascii);
+        options.set_color_mode(ColorMode::Never);
+        options.set_max_width(24);
+
+        let mut output = Vec::new();
+        grid.par_render_to_with(&mut output, &options).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), grid.render_with(&options));
+    }
+
+}
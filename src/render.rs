@@ -0,0 +1,1409 @@
+use crate::border::{Border, BorderStyle};
+use crate::cell::{Cell, CellLine, CellLines, truncate_line};
+use crate::format::{AnsiPrefixes, clip_visible, visible_width};
+use crate::grid::Grid;
+use crate::options::{ColorMode, RenderOptions};
+use crate::tree::TreePrefix;
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::convert::Infallible;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+use core::ops::{Bound, Range, RangeBounds};
+
+/// The resolved dimensions of every row and column in a window of a grid.
+///
+/// Row heights are the tallest cell in each row and column widths
+/// are the widest cell in each column, both measured within the window only.
+/// `row_heights` and `col_widths` are indexed relative to the start of the window.
+/// If `row_indices` is set, the window holds only those rows of the grid, in that order,
+/// and `rows` ranges over positions in it rather than over rows of the grid.
+/// `footer_from` is the (absolute) row index of the first footer row, if the footer starts
+/// below the first row of the window, so a footer border is drawn above it.
+/// `continued` holds, by (absolute) row index, the columns of the window whose cell
+/// is merged with the cell above it, for the rows that have any.
+/// `tree_prefixes` holds, by (absolute) row index, the tree glyphs drawn before the first cell
+/// of the child rows, if the window starts at the first column; the first column is widened to fit them.
+/// `plain` is set if every cell in the window is plain, which enables a faster render loop.
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Layout {
+    pub(crate) rows: Range<usize>,
+    pub(crate) cols: Range<usize>,
+    pub(crate) row_heights: Vec<usize>,
+    pub(crate) col_widths: Vec<usize>,
+    pub(crate) row_indices: Option<Vec<usize>>,
+    pub(crate) footer_from: Option<usize>,
+    pub(crate) continued: BTreeMap<usize, Vec<bool>>,
+    pub(crate) tree_prefixes: BTreeMap<usize, TreePrefix>,
+    pub(crate) plain: bool,
+}
+
+impl Layout {
+
+    /// Gets the resolved height of the row at the specified (absolute) row index.
+
+    pub(crate) fn row_height(
+        &self,
+        row_index: usize,
+    ) -> usize
+    {
+        self.row_heights[row_index - self.rows.start]
+    }
+
+    /// Gets the index in the grid of the row at the specified (absolute) row index of the window.
+
+    pub(crate) fn grid_row(
+        &self,
+        row_index: usize,
+    ) -> usize
+    {
+        match &self.row_indices {
+            Some(row_indices) => row_indices[row_index - self.rows.start],
+            None => row_index,
+        }
+    }
+
+    /// Gets the columns of the window whose cell is merged with the cell above it
+    /// at the specified (absolute) row index, or None if there are none.
+
+    pub(crate) fn continued_cols(
+        &self,
+        row_index: usize,
+    ) -> Option<&[bool]>
+    {
+        self.continued.get(&row_index).map(Vec::as_slice)
+    }
+
+    /// Gets the tree glyphs drawn before the first cell on a line of the row at the specified
+    /// (absolute) row index, cut to the width of the first column, or None if it is not a child row.
+
+    pub(crate) fn tree_prefix(
+        &self,
+        row_index: usize,
+        line_index: usize,
+    ) -> Option<&str>
+    {
+        let prefix = self.tree_prefixes.get(&row_index)?;
+        let glyphs = if line_index == 0 { &prefix.first } else { &prefix.rest };
+        let end = glyphs.char_indices().nth(self.col_widths[0]).map_or(glyphs.len(), |(end, _)| end);
+        Some(&glyphs[..end])
+    }
+
+    /// Gets the width left for the cell of the first column at the specified (absolute) row index,
+    /// after its tree glyphs if any, or the width of the column.
+
+    fn first_col_width(
+        &self,
+        row_index: usize,
+    ) -> usize
+    {
+        let prefix_width = self.tree_prefixes.get(&row_index).map_or(0, TreePrefix::width);
+        self.col_widths[0].saturating_sub(prefix_width)
+    }
+
+    /// Limits every column width to the maximum column width of the render options, if any,
+    /// then narrows the widest columns until the table fits in the maximum table width, if any.
+
+    pub(crate) fn constrain(
+        mut self,
+        options: &RenderOptions,
+    ) -> Layout
+    {
+        if let Some(max_col_width) = options.max_col_width() {
+            for col_width in self.col_widths.iter_mut() {
+                *col_width = (*col_width).min(max_col_width);
+            }
+        }
+        if let Some(max_width) = options.max_width() {
+            self.fit_width(options, max_width);
+        }
+        self
+    }
+
+    /// Narrows the widest columns until the rendered table is at most `max_width` characters wide.
+    ///
+    /// All columns wider than a common limit are cut to it, and the width left over is handed
+    /// back to the leftmost of them, so the table is as wide as allowed.
+
+    fn fit_width(
+        &mut self,
+        options: &RenderOptions,
+        max_width: usize,
+    )
+    {
+        let content_width: usize = self.col_widths.iter().sum();
+        let frame_width = match Border::from_style(options.border_style(), options.padding()) {
+            // The outer margins, the outer borders and their padding, and the spanned separators
+            Some(border) if !self.col_widths.is_empty() => {
+                border.spanned_width(&self.col_widths) - content_width + 2 * options.padding() + 4
+            },
+            Some(_) => return,
+            None => self.col_widths.len().saturating_sub(1) * options.gap(),
+        };
+        let budget = max_width.saturating_sub(frame_width).max(self.col_widths.len());
+        if content_width <= budget {
+            return;
+        }
+
+        // The widest limit at which the columns fit, found by bisection
+        let fitted_width = |limit: usize| -> usize {
+            self.col_widths.iter().map(|&width| width.min(limit)).sum()
+        };
+        let (mut low, mut high) = (1, self.col_widths.iter().copied().max().unwrap_or(1));
+        while low < high {
+            let limit = (low + high).div_ceil(2);
+            if fitted_width(limit) <= budget {
+                low = limit;
+            } else {
+                high = limit - 1;
+            }
+        }
+
+        let mut leftover = budget - fitted_width(low);
+        for col_width in self.col_widths.iter_mut() {
+            if *col_width > low {
+                *col_width = low + usize::from(leftover > 0);
+                leftover = leftover.saturating_sub(1);
+            }
+        }
+    }
+
+}
+
+/// The layout of a whole grid, kept between renders until the grid is mutated.
+///
+/// Every `Grid` method giving mutable access to its cells or changing its size clears the cache.
+/// Without the `std` feature nothing is cached and the layout is computed on every render.
+/// The cache never affects equality, so two grids with the same cells are always equal.
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LayoutCache {
+    #[cfg(feature = "std")]
+    layout: std::sync::OnceLock<Layout>,
+}
+
+impl LayoutCache {
+
+    /// Gets the cached layout, computing and caching it first if needed.
+
+    fn get_or_compute(
+        &self,
+        compute: impl FnOnce() -> Layout,
+    ) -> Layout
+    {
+        #[cfg(feature = "std")]
+        return self.layout.get_or_init(compute).clone();
+        #[cfg(not(feature = "std"))]
+        return compute();
+    }
+
+    /// Gets the number of heap bytes used by the cached layout, or 0 if nothing is cached.
+
+    pub(crate) fn heap_bytes(
+        &self
+    ) -> usize
+    {
+        #[cfg(feature = "std")]
+        if let Some(layout) = self.layout.get() {
+            let sizes = layout.row_heights.capacity() + layout.col_widths.capacity() + layout.row_indices.as_ref().map_or(0, Vec::capacity);
+            let continued: usize = layout.continued.values().map(|flags| crate::memory::btree_bytes::<(usize, Vec<bool>)>(1) + flags.capacity()).sum();
+            let tree_prefixes: usize = layout.tree_prefixes.values().map(|prefix| crate::memory::btree_bytes::<(usize, TreePrefix)>(1) + prefix.first.capacity() + prefix.rest.capacity()).sum();
+            return sizes * core::mem::size_of::<usize>() + continued + tree_prefixes;
+        }
+        0
+    }
+
+    /// Discards the cached layout, after the grid was mutated.
+
+    pub(crate) fn clear(
+        &mut self
+    )
+    {
+        #[cfg(feature = "std")]
+        self.layout.take();
+    }
+
+}
+
+impl PartialEq for LayoutCache {
+
+    fn eq(
+        &self,
+        _other: &Self,
+    ) -> bool
+    {
+        true
+    }
+
+}
+
+impl Eq for LayoutCache {}
+
+/// A piece of a rendered table, as emitted by `Grid::write_segments`.
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Segment<'a> {
+    /// Unstyled text, such as borders and separators.
+    Text(&'a str),
+    /// A number of unstyled spaces, such as padding.
+    Spaces(usize),
+    /// Cell text to be styled with the cell's colors and font styles.
+    Styled(&'a str, &'a Cell),
+    /// The end of the current line.
+    LineEnd,
+}
+
+impl Segment<'_> {
+
+    /// Gets the number of characters the segment takes up in a terminal.
+    ///
+    /// ANSI escape sequences in the text are not counted, and a line end takes up nothing.
+
+    pub(crate) fn visible_width(
+        &self
+    ) -> usize
+    {
+        match self {
+            Segment::Text(text) | Segment::Styled(text, _) => visible_width(text),
+            Segment::Spaces(count) => *count,
+            Segment::LineEnd => 0,
+        }
+    }
+
+}
+
+/// Takes the next line of every cell of a row, as positioned by `Grid::row_cell_lines`.
+///
+/// Cells whose lines are exhausted are skipped.
+
+pub(crate) fn next_row_line<'a, 'b>(
+    cell_lines: &'b mut [(&'a Cell, CellLines<'a>)],
+) -> impl Iterator<Item = (&'a Cell, CellLine<'a>)> + 'b
+{
+    cell_lines.iter_mut()
+        .filter_map(|(cell, lines)| Some((*cell, lines.next()?)))
+}
+
+/// Gets the first line of a plain cell, truncated to the column width as in `Cell::layout_lines`.
+
+fn plain_line(
+    cell: &Cell,
+    col_width: usize,
+) -> &str
+{
+    truncate_line(cell.get_data().lines().next().unwrap_or(""), col_width)
+}
+
+/// Renders the horizontal borders around and between the rows of a layout window as a stream of segments.
+///
+/// Each row is emitted in order by `write_row`, with the same `emit` callback,
+/// so rows can be rendered on the spot or ahead of time.
+
+pub(crate) fn write_frame_segments<E, F>(
+    layout: &Layout,
+    options: &RenderOptions,
+    emit: &mut F,
+    mut write_row: impl FnMut(usize, &mut F) -> Result<(), E>,
+) -> Result<(), E>
+where
+    F: FnMut(Segment<'_>) -> Result<(), E>,
+{
+    let Some(border) = Border::from_style(options.border_style(), options.padding()) else {
+        for row_index in layout.rows.clone() {
+            write_row(row_index, emit)?;
+        }
+        return Ok(());
+    };
+
+    let top_border = border.render_top_border(&layout.col_widths);
+    let mid_border = border.render_mid_border(&layout.col_widths);
+    let bot_border = border.render_bot_border(&layout.col_widths);
+    let footer_border = layout.footer_from.map(|_| border.render_footer_border(&layout.col_widths));
+
+    emit(Segment::Text(&top_border))?;
+    emit(Segment::LineEnd)?;
+    for row_index in layout.rows.clone() {
+        if let Some(footer_border) = footer_border.as_deref().filter(|_| layout.footer_from == Some(row_index)) {
+            emit(Segment::Text(footer_border))?;
+            emit(Segment::LineEnd)?;
+        }
+        write_row(row_index, emit)?;
+        if options.row_separators() && row_index + 1 < layout.rows.end && layout.footer_from != Some(row_index + 1) {
+            match layout.continued_cols(row_index + 1) {
+                Some(continued) => emit(Segment::Text(&border.render_continued_border(&layout.col_widths, continued)))?,
+                None => emit(Segment::Text(&mid_border))?,
+            }
+            emit(Segment::LineEnd)?;
+        }
+    }
+    emit(Segment::Text(&bot_border))?;
+    emit(Segment::LineEnd)?;
+    Ok(())
+}
+
+/// Writes a segment into a formatter or string, with a newline for `Segment::LineEnd`.
+///
+/// Styled text is written with its escape sequences directly, without an intermediate string.
+/// The escape sequences of each style are built once and kept in `prefixes`,
+/// which should be shared by all segments of a render.
+
+pub(crate) fn write_segment(
+    output: &mut impl fmt::Write,
+    segment: Segment<'_>,
+    prefixes: &mut AnsiPrefixes,
+) -> fmt::Result
+{
+    match segment {
+        Segment::Text(text) => output.write_str(text),
+        Segment::Spaces(count) => write_spaces(output, count),
+        Segment::Styled(text, cell) => write!(output, "{}", cell.styled(text, prefixes)),
+        Segment::LineEnd => output.write_char('\n'),
+    }
+}
+
+/// Writes a number of spaces, a slice of a constant string at a time rather than through padded formatting.
+
+fn write_spaces(
+    output: &mut impl fmt::Write,
+    mut count: usize,
+) -> fmt::Result
+{
+    const SPACES: &str = "                                                                ";
+    while count > 0 {
+        let chunk = count.min(SPACES.len());
+        output.write_str(&SPACES[..chunk])?;
+        count -= chunk;
+    }
+    Ok(())
+}
+
+/// Converts any range bounds into a concrete range clamped to `0..len`.
+
+pub(crate) fn resolve_range(
+    bounds: impl RangeBounds<usize>,
+    len: usize,
+) -> Range<usize>
+{
+    let start = match bounds.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded        => 0,
+    };
+    let end = match bounds.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded      => len,
+    };
+    let end = end.min(len);
+    start.min(end)..end
+}
+
+impl Grid {
+
+    /// Computes the row heights and column widths needed to render the whole grid,
+    /// without the rows hidden under collapsed rows.
+    ///
+    /// The result is cached until the grid is mutated, so rendering an unchanged grid
+    /// again does not measure every cell again.
+
+    pub(crate) fn layout(
+        &self
+    ) -> Layout
+    {
+        self.layout_cache().get_or_compute(|| match self.tree().visible_rows(self.row_size()) {
+            // Rows under collapsed rows are left out
+            Some(row_indices) => self.layout_selected_rows(row_indices, 0..self.col_size()),
+            None => self.layout_window(0..self.row_size(), 0..self.col_size()),
+        })
+    }
+
+    /// Gets the width of every column's content, as used when rendering the grid.
+    ///
+    /// This is the widest cell in each column, without padding or borders.
+
+    pub fn col_widths(
+        &self
+    ) -> Vec<usize>
+    {
+        self.layout().col_widths
+    }
+
+    /// Gets the height of every row's content, as used when rendering the grid.
+    ///
+    /// This is the tallest cell in each row, without row separators.
+
+    pub fn row_heights(
+        &self
+    ) -> Vec<usize>
+    {
+        self.layout().row_heights
+    }
+
+    /// Computes the row heights and column widths needed to render a window of the grid.
+    ///
+    /// Only the cells inside the window are measured.
+
+    pub(crate) fn layout_window(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Layout
+    {
+        let row_heights: Vec<usize> =
+            rows.clone().map(|row_index|
+                self.row_iter(row_index)
+                    .skip(cols.start)
+                    .take(cols.len())
+                    .map(Cell::height)
+                    .max().unwrap_or(0)
+            )
+            .collect();
+        let col_widths: Vec<usize> =
+            cols.clone().map(|col_index|
+                self.col_iter(col_index)
+                    .skip(rows.start)
+                    .take(rows.len())
+                    .map(Cell::width)
+                    .max().unwrap_or(0)
+            )
+            .collect();
+        let plain = rows.clone().all(|row_index|
+            self.row_iter(row_index)
+                .skip(cols.start)
+                .take(cols.len())
+                .all(Cell::is_plain)
+        );
+        let footer_start = self.footer_start();
+        let footer_from = (footer_start > rows.start && footer_start < rows.end).then_some(footer_start);
+        let mut layout = Layout { rows, cols, row_heights, col_widths, row_indices: None, footer_from, continued: BTreeMap::new(), tree_prefixes: BTreeMap::new(), plain };
+        layout.continued = self.continued_cells(&layout);
+        self.add_tree_prefixes(&mut layout);
+        layout
+    }
+
+    /// Computes the row heights and column widths needed to render the given rows of the grid,
+    /// in the given order, within a range of columns.
+    ///
+    /// Only the cells of these rows inside the column range are measured.
+
+    pub(crate) fn layout_selected_rows(
+        &self,
+        row_indices: Vec<usize>,
+        cols: Range<usize>,
+    ) -> Layout
+    {
+        let window_row = |row_index: usize| self.row_iter(row_index).skip(cols.start).take(cols.len());
+        let row_heights: Vec<usize> = row_indices.iter()
+            .map(|&row_index| window_row(row_index).map(Cell::height).max().unwrap_or(0))
+            .collect();
+        let mut col_widths = vec![0; cols.len()];
+        let mut plain = true;
+        for &row_index in &row_indices {
+            for (col_width, cell) in col_widths.iter_mut().zip(window_row(row_index)) {
+                *col_width = (*col_width).max(cell.width());
+                plain &= cell.is_plain();
+            }
+        }
+        let footer_start = self.footer_start();
+        let footer_from = row_indices.iter()
+            .position(|&row_index| row_index >= footer_start)
+            .filter(|&position| position > 0);
+        let mut layout = Layout {
+            rows: 0..row_indices.len(),
+            cols,
+            row_heights,
+            col_widths,
+            row_indices: Some(row_indices),
+            footer_from,
+            continued: BTreeMap::new(),
+            tree_prefixes: BTreeMap::new(),
+            plain,
+        };
+        layout.continued = self.continued_cells(&layout);
+        self.add_tree_prefixes(&mut layout);
+        layout
+    }
+
+    /// Finds the cells of merged columns that repeat the cell above them in the layout window,
+    /// as stored in `Layout::continued`.
+    ///
+    /// Empty cells and footer rows are never merged.
+
+    fn continued_cells(
+        &self,
+        layout: &Layout,
+    ) -> BTreeMap<usize, Vec<bool>>
+    {
+        let mut continued = BTreeMap::new();
+        if self.merged_cols().range(layout.cols.clone()).next().is_none() {
+            return continued;
+        }
+
+        let footer_start = self.footer_start();
+        let data = |row_index, col_index| self.get_cell(row_index, col_index).map_or("", Cell::get_data);
+        for row_index in layout.rows.start + 1..layout.rows.end {
+            let (above, below) = (layout.grid_row(row_index - 1), layout.grid_row(row_index));
+            if below >= footer_start {
+                break;
+            }
+            let flags: Vec<bool> = layout.cols.clone()
+                .map(|col_index| {
+                    self.merged_cols().contains(&col_index)
+                        && !data(below, col_index).is_empty()
+                        && data(above, col_index) == data(below, col_index)
+                })
+                .collect();
+            if flags.contains(&true) {
+                continued.insert(row_index, flags);
+            }
+        }
+        continued
+    }
+
+    /// Finds the tree glyphs of the child rows in the layout window, as stored in `Layout::tree_prefixes`,
+    /// and widens the first column to fit them next to the cells.
+
+    fn add_tree_prefixes(
+        &self,
+        layout: &mut Layout,
+    )
+    {
+        if self.tree().is_empty() || layout.cols.start != 0 || layout.cols.is_empty() {
+            return;
+        }
+
+        let mut prefixes = self.tree().prefixes();
+        for row_index in layout.rows.clone() {
+            let grid_row = layout.grid_row(row_index);
+            if let Some(prefix) = prefixes.remove(&grid_row) {
+                let width = prefix.width() + self.get_cell(grid_row, 0).map_or(0, Cell::width);
+                layout.col_widths[0] = layout.col_widths[0].max(width);
+                layout.tree_prefixes.insert(row_index, prefix);
+            }
+        }
+    }
+
+    /// Positions every cell of a row inside the layout window.
+    ///
+    /// Each item holds a cell together with the iterator over its positioned lines,
+    /// so that `next_row_line` yields the row one visual line at a time.
+
+    pub(crate) fn row_cell_lines(
+        &self,
+        row_index: usize,
+        layout: &Layout,
+    ) -> Vec<(&Cell, CellLines<'_>)>
+    {
+        let row_height = layout.row_height(row_index);
+        let continued = layout.continued_cols(row_index);
+        self.row_iter(layout.grid_row(row_index))
+            .skip(layout.cols.start)
+            .zip(layout.col_widths.iter())
+            .enumerate()
+            .map(|(col_index, (cell, &col_width))| {
+                // The tree glyphs are drawn before the cell of the first column
+                let col_width = if col_index == 0 { layout.first_col_width(row_index) } else { col_width };
+                // A merged cell is shown once, in the first row of its run
+                if continued.is_some_and(|continued| continued[col_index]) {
+                    (cell, CellLines::blank(row_height, col_width))
+                } else {
+                    (cell, cell.layout_lines(row_height, col_width, self.default_style()))
+                }
+            })
+            .collect()
+    }
+
+    /// Renders the lines of a single row inside the layout window as a stream of segments.
+    ///
+    /// Only the row itself is emitted, with its vertical borders (or gaps) but without
+    /// any horizontal borders above or below it.
+    /// Cell text is emitted as `Segment::Styled` only if the color mode allows it.
+    /// Without borders, trailing whitespace is not emitted.
+
+    pub(crate) fn write_row_segments<E>(
+        &self,
+        row_index: usize,
+        layout: &Layout,
+        options: &RenderOptions,
+        emit: &mut impl FnMut(Segment<'_>) -> Result<(), E>,
+    ) -> Result<(), E>
+    {
+        let styled = options.color_mode() == ColorMode::Always;
+        let grid_row = layout.grid_row(row_index);
+        let has_selection = styled && self.selection().contains_row(grid_row);
+        let default_style = self.default_style();
+        if layout.plain && layout.row_height(row_index) == 1 && !self.match_highlight().is_active()
+            && layout.continued_cols(row_index).is_none() && !has_selection && !layout.tree_prefixes.contains_key(&row_index)
+            && default_style.is_empty() {
+            return self.write_plain_row_segments(row_index, layout, options, emit);
+        }
+
+        // Cells without their own colors or font styles take the ones of the default style
+        let default_styled_cells: Vec<Cell> = if styled && !default_style.is_empty() {
+            self.row_iter(grid_row)
+                .skip(layout.cols.start)
+                .take(layout.col_widths.len())
+                .map(|cell| cell.with_default_style(default_style))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        // Selected cells are styled across the whole width of their column, alignment spaces included
+        let selected_cells: Vec<Option<Cell>> = if has_selection {
+            self.row_iter(grid_row)
+                .enumerate()
+                .skip(layout.cols.start)
+                .take(layout.col_widths.len())
+                .map(|(col_index, cell)| {
+                    let cell = default_styled_cells.get(col_index - layout.cols.start).unwrap_or(cell);
+                    self.selection().contains(grid_row, col_index).then(|| self.selection().selected_cell(cell))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let emit_cell_line = |emit: &mut dyn FnMut(Segment<'_>) -> Result<(), E>, line_index: usize, col_index: usize, cell: &Cell, line: CellLine| {
+            if let Some(tree_prefix) = layout.tree_prefix(row_index, line_index).filter(|_| col_index == 0) {
+                emit(Segment::Text(tree_prefix))?;
+            }
+            // Progress bars and boolean glyphs are drawn across the whole width of their column
+            let col_width = if col_index == 0 { layout.first_col_width(row_index) } else { layout.col_widths[col_index] };
+            let drawn_line = if line.text.is_empty() { None } else { cell.drawn_line(col_width) };
+            // A line narrower than its column had its trailing whitespace cut, as the last cell without borders
+            let trimmed = line.left + line.text.len() + line.right < col_width;
+            let line = match &drawn_line {
+                Some(drawn_line) if trimmed => CellLine { left: 0, text: drawn_line.trim_end(), right: 0 },
+                Some(drawn_line) => CellLine { left: 0, text: drawn_line, right: 0 },
+                None => line,
+            };
+            if let Some(Some(selected)) = selected_cells.get(col_index) {
+                let text = format!("{:left$}{}{:right$}", "", line.text, "", left = line.left, right = line.right);
+                return self.match_highlight().write_styled_text(&text, selected, emit);
+            }
+            emit(Segment::Spaces(line.left))?;
+            if styled {
+                let cell = default_styled_cells.get(col_index).unwrap_or(cell);
+                self.match_highlight().write_styled_text(line.text, cell, emit)?;
+            } else {
+                emit(Segment::Text(line.text))?;
+            }
+            emit(Segment::Spaces(line.right))
+        };
+
+        let Some(border) = Border::from_style(options.border_style(), options.padding()) else {
+            // Without borders, columns are only separated by the gap
+            let mut cell_lines = self.row_cell_lines(row_index, layout);
+            let mut row_line = Vec::with_capacity(cell_lines.len());
+            for line_index in 0..layout.row_height(row_index) {
+                row_line.clear();
+                row_line.extend(next_row_line(&mut cell_lines));
+                // Skip the trailing whitespace, keeping the tree glyphs of the line if they are visible
+                let has_glyphs = layout.tree_prefix(row_index, line_index).is_some_and(|glyphs| !glyphs.trim_end().is_empty());
+                let last = row_line.iter()
+                    .rposition(|(_, line)| !line.text.is_empty())
+                    .or(has_glyphs.then_some(0));
+                if let Some(last) = last {
+                    for (col_index, &(cell, line)) in row_line.iter().enumerate().take(last + 1) {
+                        if col_index > 0 {
+                            emit(Segment::Spaces(options.gap()))?;
+                        }
+                        let line = if col_index == last { CellLine { right: 0, ..line } } else { line };
+                        emit_cell_line(emit, line_index, col_index, cell, line)?;
+                    }
+                }
+                emit(Segment::LineEnd)?;
+            }
+            return Ok(());
+        };
+
+        // The vertical borders are emitted piece by piece, as in `Border::render_row_left`,
+        // `Border::render_row_separator`, and `Border::render_row_right`, to avoid allocating them
+        let vertical = border.vertical();
+        let padding = border.padding();
+
+        let mut cell_lines = self.row_cell_lines(row_index, layout);
+        for line_index in 0..layout.row_height(row_index) {
+            emit(Segment::Spaces(1))?;
+            emit(Segment::Text(vertical))?;
+            emit(Segment::Spaces(padding))?;
+            for (col_index, (cell, line)) in next_row_line(&mut cell_lines).enumerate() {
+                if col_index > 0 {
+                    emit(Segment::Spaces(padding))?;
+                    emit(Segment::Text(vertical))?;
+                    emit(Segment::Spaces(padding))?;
+                }
+                emit_cell_line(emit, line_index, col_index, cell, line)?;
+            }
+            emit(Segment::Spaces(padding))?;
+            emit(Segment::Text(vertical))?;
+            emit(Segment::Spaces(1))?;
+            emit(Segment::LineEnd)?;
+        }
+        Ok(())
+    }
+
+    /// Renders a single-line row of plain cells inside the layout window as a stream of segments.
+    ///
+    /// Plain cells need neither positioning nor styling: each one is emitted as the first line
+    /// of its text, followed by the spaces up to the next column.
+    /// The output is identical to the general loop of `write_row_segments`, for the most common tables.
+
+    fn write_plain_row_segments<E>(
+        &self,
+        row_index: usize,
+        layout: &Layout,
+        options: &RenderOptions,
+        emit: &mut impl FnMut(Segment<'_>) -> Result<(), E>,
+    ) -> Result<(), E>
+    {
+        let cells = || self.row_iter(layout.grid_row(row_index))
+            .skip(layout.cols.start)
+            .zip(layout.col_widths.iter().copied())
+            .map(|(cell, col_width)| (plain_line(cell, col_width), col_width));
+
+        let Some(border) = Border::from_style(options.border_style(), options.padding()) else {
+            // Skip the trailing whitespace
+            let last = cells().rposition(|(text, _)| !text.is_empty());
+            if let Some(last) = last {
+                for (col_index, (text, col_width)) in cells().enumerate().take(last + 1) {
+                    if col_index > 0 {
+                        emit(Segment::Spaces(options.gap()))?;
+                    }
+                    emit(Segment::Text(text))?;
+                    if col_index < last {
+                        emit(Segment::Spaces(col_width - text.len()))?;
+                    }
+                }
+            }
+            return emit(Segment::LineEnd);
+        };
+
+        let vertical = border.vertical();
+        let padding = border.padding();
+
+        emit(Segment::Spaces(1))?;
+        emit(Segment::Text(vertical))?;
+        for (col_index, (text, col_width)) in cells().enumerate() {
+            if col_index > 0 {
+                emit(Segment::Text(vertical))?;
+            }
+            emit(Segment::Spaces(padding))?;
+            emit(Segment::Text(text))?;
+            emit(Segment::Spaces(col_width - text.len() + padding))?;
+        }
+        emit(Segment::Text(vertical))?;
+        emit(Segment::Spaces(1))?;
+        emit(Segment::LineEnd)
+    }
+
+    /// Renders the layout window as a stream of segments.
+    ///
+    /// This is the single source of truth for the structure of a rendered table
+    /// (borders, separators, padding, and cell text), shared by all renderers.
+
+    pub(crate) fn write_segments<E>(
+        &self,
+        layout: &Layout,
+        options: &RenderOptions,
+        mut emit: impl FnMut(Segment<'_>) -> Result<(), E>,
+    ) -> Result<(), E>
+    {
+        write_frame_segments(layout, options, &mut emit, |row_index, emit| {
+            self.write_row_segments(row_index, layout, options, emit)
+        })
+    }
+
+    /// Renders the layout window one line at a time.
+    ///
+    /// Each line is passed to `write_line` without a trailing newline as soon as it is rendered,
+    /// so the whole table is never held in memory at once.
+
+    pub(crate) fn write_lines<E>(
+        &self,
+        layout: &Layout,
+        options: &RenderOptions,
+        mut write_line: impl FnMut(&str) -> Result<(), E>,
+    ) -> Result<(), E>
+    {
+        let mut line = String::new();
+        let mut prefixes = AnsiPrefixes::new();
+        self.write_segments(layout, options, |segment| {
+            match segment {
+                Segment::LineEnd => {
+                    write_line(&line)?;
+                    line.clear();
+                },
+                // Writing into a string never fails
+                segment => { let _ = write_segment(&mut line, segment, &mut prefixes); },
+            }
+            Ok(())
+        })
+    }
+
+    /// Renders a layout window into a string, with a newline after each line.
+
+    pub(crate) fn render_layout(
+        &self,
+        layout: &Layout,
+        options: &RenderOptions,
+    ) -> String
+    {
+        let mut output = String::new();
+        let mut prefixes = AnsiPrefixes::new();
+        // Writing into a string never fails
+        let _ = self.write_segments(layout, options, |segment| write_segment(&mut output, segment, &mut prefixes));
+        output
+    }
+
+    /// Renders the grid using the given render options.
+    ///
+    /// The `Display` implementation is a shortcut for rendering with the default options.
+
+    pub fn render_with(
+        &self,
+        options: &RenderOptions,
+    ) -> String
+    {
+        self.render_layout(&self.layout().constrain(options), options)
+    }
+
+    /// Renders the grid with the default layout but without any ANSI escape sequences.
+    ///
+    /// Useful for logs, snapshot tests, and text files.
+    /// This is a shortcut for rendering with `ColorMode::Never`.
+
+    pub fn render_plain(
+        &self
+    ) -> String
+    {
+        let mut options = self.default_options();
+        options.set_color_mode(ColorMode::Never);
+        self.render_with(&options)
+    }
+
+    /// Renders the grid directly into a writer.
+    ///
+    /// Borders and rows are written incrementally as they are rendered,
+    /// which avoids building the whole table as a `String` first.
+    /// The output is identical to the `Display` implementation.
+
+    #[cfg(feature = "std")]
+    pub fn render_to<W: io::Write>(
+        &self,
+        w: &mut W,
+    ) -> io::Result<()>
+    {
+        let options = self.default_options();
+        self.write_lines(&self.layout(), &options, |line| writeln!(w, "{}", line))
+    }
+
+    /// Renders the grid into a vector of lines.
+    ///
+    /// Each item is one line of the bordered table without a trailing newline,
+    /// which allows placing the table precisely in a larger layout.
+
+    pub fn render_lines(
+        &self
+    ) -> Vec<String>
+    {
+        self.render_lines_with(&self.default_options())
+    }
+
+    /// Renders the grid into a vector of lines using the given render options.
+
+    pub fn render_lines_with(
+        &self,
+        options: &RenderOptions,
+    ) -> Vec<String>
+    {
+        let mut lines = Vec::new();
+        let layout = self.layout().constrain(options);
+        let _ = self.write_lines(&layout, options, |line| -> Result<(), Infallible> {
+            lines.push(line.to_string());
+            Ok(())
+        });
+        lines
+    }
+
+    /// Renders the grid into lines prefixed with the given text, for logging each line separately.
+    ///
+    /// Loggers such as the `log` and `tracing` macros turn a multiline message into a single record,
+    /// which breaks the table in most log viewers. Logging each line instead keeps it readable:
+    /// `for line in grid.log_lines("[jobs] ") { log::info!("{}", line); }`.
+    /// Pass an empty prefix to log the lines as they are.
+    ///
+    /// The lines are rendered without ANSI escape sequences, since log files and collectors
+    /// rarely display them; use `log_lines_with` to render them with other options.
+
+    pub fn log_lines(
+        &self,
+        prefix: &str,
+    ) -> Vec<String>
+    {
+        let mut options = self.default_options();
+        options.set_color_mode(ColorMode::Never);
+        self.log_lines_with(prefix, &options)
+    }
+
+    /// Renders the grid into lines prefixed with the given text using the given render options.
+    ///
+    /// See `log_lines` for details.
+
+    pub fn log_lines_with(
+        &self,
+        prefix: &str,
+        options: &RenderOptions,
+    ) -> Vec<String>
+    {
+        let mut lines = Vec::new();
+        let layout = self.layout().constrain(options);
+        let _ = self.write_lines(&layout, options, |line| -> Result<(), Infallible> {
+            lines.push(format!("{}{}", prefix, line));
+            Ok(())
+        });
+        lines
+    }
+
+    /// Renders only a window of the grid, with complete borders around it.
+    ///
+    /// Both ranges accept any range syntax (`2..5`, `..10`, `3..`, `..`)
+    /// and are clamped to the size of the grid.
+    ///
+    /// Only the cells inside the window are measured and rendered,
+    /// so column widths are fitted to the visible rows rather than the whole grid.
+    /// This keeps rendering cost proportional to the window size.
+
+    pub fn render_viewport(
+        &self,
+        row_range: impl RangeBounds<usize>,
+        col_range: impl RangeBounds<usize>,
+    ) -> String
+    {
+        self.view(row_range, col_range).render_with(&self.default_options())
+    }
+
+    /// Renders only a range of rows, with indicator lines for the rows scrolled out of view.
+    ///
+    /// When rows are hidden above or below the range, a full-width `↑ 12 more` or `↓ 308 more`
+    /// line is added inside the frame, separated from the rows by a border.
+    /// The range accepts any range syntax and is clamped to the size of the grid.
+    /// Like `render_viewport`, column widths are fitted to the visible rows.
+    /// Borders follow the border style of the grid, as in the `Display` output;
+    /// without borders, the indicators are plain lines above and below the rows.
+
+    pub fn render_rows(
+        &self,
+        row_range: impl RangeBounds<usize>,
+    ) -> String
+    {
+        let rows = resolve_range(row_range, self.row_size());
+        let hidden_above = rows.start;
+        let hidden_below = self.row_size() - rows.end;
+        let mut layout = self.layout_window(rows, 0..self.col_size());
+
+        // The rows are always styled, whatever the environment selects
+        let mut options = self.default_options();
+        options.set_color_mode(ColorMode::Always);
+        let above_text = format!("↑ {} more", hidden_above);
+        let below_text = format!("↓ {} more", hidden_below);
+
+        let mut output = String::new();
+        let push_line = |output: &mut String, line: &str| {
+            output.push_str(line);
+            output.push('\n');
+        };
+        let mut prefixes = AnsiPrefixes::new();
+
+        let Some(border) = Border::from_style(options.border_style(), options.padding()) else {
+            // Without borders, the indicators are plain lines above and below the rows
+            if hidden_above > 0 {
+                push_line(&mut output, &above_text);
+            }
+            for row_index in layout.rows.clone() {
+                // Writing into a string never fails
+                let _ = self.write_row_segments(row_index, &layout, &options, &mut |segment| write_segment(&mut output, segment, &mut prefixes));
+            }
+            if hidden_below > 0 {
+                push_line(&mut output, &below_text);
+            }
+            return output;
+        };
+
+        // Widen the last column if the indicators don't fit in the table
+        let indicator_width = [(hidden_above, &above_text), (hidden_below, &below_text)].iter()
+            .filter(|(hidden, _)| *hidden > 0)
+            .map(|(_, text)| text.chars().count())
+            .max().unwrap_or(0);
+        let missing_width = indicator_width.saturating_sub(border.spanned_width(&layout.col_widths));
+        if let Some(last_width) = layout.col_widths.last_mut() {
+            *last_width += missing_width;
+        }
+
+        let spanned_width = border.spanned_width(&layout.col_widths);
+        let indicator = |text: &str| -> String {
+            let text: String = text.chars().take(spanned_width).collect();
+            let padding = " ".repeat(spanned_width - text.chars().count());
+            border.render_row_lines(vec![format!("{}{}", text, padding)])
+        };
+
+        if hidden_above > 0 {
+            push_line(&mut output, &border.render_top_border(&[spanned_width]));
+            push_line(&mut output, &indicator(&above_text));
+            push_line(&mut output, &border.render_split_border(&layout.col_widths));
+        } else {
+            push_line(&mut output, &border.render_top_border(&layout.col_widths));
+        }
+        for row_index in layout.rows.clone() {
+            // Writing into a string never fails
+            let _ = self.write_row_segments(row_index, &layout, &options, &mut |segment| write_segment(&mut output, segment, &mut prefixes));
+            if layout.footer_from == Some(row_index + 1) {
+                push_line(&mut output, &border.render_footer_border(&layout.col_widths));
+            } else if let Some(continued) = layout.continued_cols(row_index + 1) {
+                push_line(&mut output, &border.render_continued_border(&layout.col_widths, continued));
+            } else if row_index < layout.rows.end - 1 {
+                push_line(&mut output, &border.render_mid_border(&layout.col_widths));
+            }
+        }
+        if hidden_below > 0 {
+            push_line(&mut output, &border.render_merge_border(&layout.col_widths));
+            push_line(&mut output, &indicator(&below_text));
+            push_line(&mut output, &border.render_bot_border(&[spanned_width]));
+        } else {
+            push_line(&mut output, &border.render_bot_border(&layout.col_widths));
+        }
+        output
+    }
+
+    /// Renders the grid clipped to a horizontal window, for wide-table viewers.
+    ///
+    /// The window starts `x_offset` visible characters from the left edge of the table
+    /// and is at most `max_width` characters wide.
+    /// Clipping never splits box-drawing characters or escape sequences,
+    /// and cell styles crossing the edges of the window are reopened and closed at the cut.
+
+    pub fn render_scrolled(
+        &self,
+        x_offset: usize,
+        max_width: usize,
+    ) -> String
+    {
+        let mut output = String::new();
+        let _ = self.write_lines(&self.layout(), &self.default_options(), |line| -> Result<(), Infallible> {
+            output.push_str(&clip_visible(line, x_offset, max_width));
+            output.push('\n');
+            Ok(())
+        });
+        output
+    }
+
+    /// Renders the grid without any borders.
+    ///
+    /// Columns are separated by `gap` spaces, similar to the output of `ls -l` or `ps`.
+    /// Alignment and column widths are kept, and trailing whitespace is removed from each line.
+    ///
+    /// This is a shortcut for rendering with `BorderStyle::None`.
+
+    pub fn render_borderless(
+        &self,
+        gap: usize,
+    ) -> String
+    {
+        let mut options = RenderOptions::new();
+        options.set_border_style(BorderStyle::None);
+        options.set_gap(gap);
+        self.render_with(&options)
+    }
+
+    /// Renders the top of the table: the top border, the first row as a header, and the
+    /// separator below it.
+    ///
+    /// Together with `render_row` and `render_footer`, this renders a table in fragments over time,
+    /// e.g. for log-style output. Pass the same column widths to every call, such as the ones
+    /// returned by `Grid::col_widths`; with those, the fragments concatenate to the `Display` output,
+    /// including its border style, row separators, and footer border.
+    /// Extra column widths beyond the size of the grid are ignored.
+
+    pub fn render_header(
+        &self,
+        col_widths: &[usize],
+    ) -> String
+    {
+        self.render_header_with(col_widths, &self.default_options())
+    }
+
+    /// Renders the top of the table using the given render options.
+    ///
+    /// The fragments concatenate to the output of `render_with` with the same options.
+    /// See `render_header` for details.
+
+    pub fn render_header_with(
+        &self,
+        col_widths: &[usize],
+        options: &RenderOptions,
+    ) -> String
+    {
+        let col_widths = &col_widths[..col_widths.len().min(self.col_size())];
+        let border = Border::from_style(options.border_style(), options.padding());
+
+        let mut output = String::new();
+        if let Some(border) = &border {
+            output.push_str(&border.render_top_border(col_widths));
+            output.push('\n');
+        }
+        if self.row_size() > 0 {
+            output.push_str(&self.render_row_fragment(0, col_widths, options));
+        }
+        if let Some(border) = border.as_ref().filter(|_| self.row_size() > 1) {
+            output.push_str(&self.fragment_separator(1, col_widths, options, border));
+        }
+        output
+    }
+
+    /// Renders a single body row, preceded by the separator line above it unless it directly
+    /// follows the header: a heavier border for the first footer row, and a row separator
+    /// for the other rows if the options draw them.
+    ///
+    /// Rows out of bounds render as an empty string. See `render_header` for rendering in fragments.
+
+    pub fn render_row(
+        &self,
+        row_index: usize,
+        col_widths: &[usize],
+    ) -> String
+    {
+        self.render_row_with(row_index, col_widths, &self.default_options())
+    }
+
+    /// Renders a single body row using the given render options.
+    ///
+    /// See `render_row` and `render_header` for details.
+
+    pub fn render_row_with(
+        &self,
+        row_index: usize,
+        col_widths: &[usize],
+        options: &RenderOptions,
+    ) -> String
+    {
+        if row_index >= self.row_size() {
+            return String::new();
+        }
+        let col_widths = &col_widths[..col_widths.len().min(self.col_size())];
+
+        let mut output = String::new();
+        if row_index > 1 {
+            if let Some(border) = Border::from_style(options.border_style(), options.padding()) {
+                output.push_str(&self.fragment_separator(row_index, col_widths, options, &border));
+            }
+        }
+        output.push_str(&self.render_row_fragment(row_index, col_widths, options));
+        output
+    }
+
+    /// Renders the bottom of the table, closing the border.
+    ///
+    /// See `render_header` for rendering in fragments.
+
+    pub fn render_footer(
+        &self,
+        col_widths: &[usize],
+    ) -> String
+    {
+        self.render_footer_with(col_widths, &self.default_options())
+    }
+
+    /// Renders the bottom of the table using the given render options.
+    ///
+    /// See `render_header` for rendering in fragments.
+
+    pub fn render_footer_with(
+        &self,
+        col_widths: &[usize],
+        options: &RenderOptions,
+    ) -> String
+    {
+        let col_widths = &col_widths[..col_widths.len().min(self.col_size())];
+        let Some(border) = Border::from_style(options.border_style(), options.padding()) else {
+            return String::new();
+        };
+
+        let mut output = border.render_bot_border(col_widths);
+        output.push('\n');
+        output
+    }
+
+    /// Renders the line above a row as the full render draws it, with its newline:
+    /// the footer border above the first footer row, else a row separator if the options draw them,
+    /// else nothing.
+
+    fn fragment_separator(
+        &self,
+        row_index: usize,
+        col_widths: &[usize],
+        options: &RenderOptions,
+        border: &Border,
+    ) -> String
+    {
+        let line = if row_index == self.footer_start() {
+            border.render_footer_border(col_widths)
+        } else if options.row_separators() {
+            border.render_mid_border(col_widths)
+        } else {
+            return String::new();
+        };
+        line + "\n"
+    }
+
+    /// Renders the lines of a single row with the given column widths,
+    /// without any horizontal borders.
+
+    fn render_row_fragment(
+        &self,
+        row_index: usize,
+        col_widths: &[usize],
+        options: &RenderOptions,
+    ) -> String
+    {
+        let row_height = self.row_iter(row_index).map(Cell::height).max().unwrap_or(0);
+        let layout = Layout {
+            rows: row_index..row_index + 1,
+            cols: 0..col_widths.len(),
+            row_heights: vec![row_height],
+            col_widths: col_widths.to_vec(),
+            row_indices: None,
+            footer_from: None,
+            continued: BTreeMap::new(),
+            tree_prefixes: BTreeMap::new(),
+            plain: self.row_iter(row_index).all(Cell::is_plain),
+        };
+
+        let mut output = String::new();
+        let mut prefixes = AnsiPrefixes::new();
+        // Writing into a string never fails
+        let _ = self.write_row_segments(row_index, &layout, options, &mut |segment| write_segment(&mut output, segment, &mut prefixes));
+        output
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{BorderStyle, ColorMode, Grid, RenderOptions};
+
+    use alloc::string::{String, ToString};
+
+    fn fragments(
+        grid: &Grid,
+        col_widths: &[usize],
+        options: &RenderOptions,
+    ) -> String
+    {
+        let mut output = grid.render_header_with(col_widths, options);
+        for row_index in 1..grid.row_size() {
+            output += &grid.render_row_with(row_index, col_widths, options);
+        }
+        output + &grid.render_footer_with(col_widths, options)
+    }
+
+    fn plain_options() -> RenderOptions {
+        let mut options = RenderOptions::without_env();
+        options.set_color_mode(ColorMode::Never);
+        options
+    }
+
+    #[test]
+    fn max_col_width_cuts_multibyte_text_at_char_boundaries() {
+        let mut options = plain_options();
+        options.set_max_col_width(2);
+        // Plain fast path
+        let grid = Grid::from([["héllo wörld"]]);
+        assert_eq!(grid.render_with(&options).lines().nth(1).map(str::trim), Some("│ h  │"));
+        // Multi-line cells go through `CellLines`
+        let grid = Grid::from([["héllo\nwörld"]]);
+        let rendered = grid.render_with(&options);
+        assert_eq!(rendered.lines().nth(1).map(str::trim), Some("│ h  │"));
+        assert_eq!(rendered.lines().nth(2).map(str::trim), Some("│ w  │"));
+    }
+
+    #[test]
+    fn max_width_cuts_multibyte_text_at_char_boundaries() {
+        let mut options = plain_options();
+        options.set_max_width(12);
+        let grid = Grid::from([["héllo wörld", "ñandú"]]);
+        let rendered = grid.render_with(&options);
+        assert!(rendered.lines().all(|line| line.chars().count() <= 12), "{rendered}");
+    }
+
+    #[test]
+    fn fragments_use_the_border_style_of_the_grid() {
+        for border_style in [BorderStyle::Ascii, BorderStyle::None] {
+            let mut grid = Grid::from([["name", "qty"], ["apple", "3"], ["pear", "12"]]);
+            grid.set_border_style(border_style);
+            let col_widths = grid.col_widths();
+            let mut options = plain_options();
+            options.set_border_style(border_style);
+            assert_eq!(fragments(&grid, &col_widths, &options), grid.render_with(&options), "{border_style:?}");
+        }
+    }
+
+    #[test]
+    fn fragments_draw_the_footer_border_and_row_separators_like_a_full_render() {
+        let mut grid = Grid::from([["name", "qty"], ["apple", "3"], ["pear", "12"], ["total", "15"]]);
+        grid.set_footer_rows(1);
+        let col_widths = grid.col_widths();
+        for row_separators in [true, false] {
+            let mut options = plain_options();
+            options.set_row_separators(row_separators);
+            assert_eq!(fragments(&grid, &col_widths, &options), grid.render_with(&options), "{row_separators}");
+        }
+
+        // A footer right below the header
+        let mut grid = Grid::from([["name", "qty"], ["total", "15"]]);
+        grid.set_footer_rows(1);
+        let options = plain_options();
+        assert_eq!(fragments(&grid, &grid.col_widths(), &options), grid.render_with(&options));
+    }
+
+    #[test]
+    fn render_rows_uses_the_border_style_of_the_grid() {
+        let mut grid = Grid::from([["a"], ["b"], ["c"], ["d"]]);
+        grid.set_border_style(BorderStyle::Ascii);
+        let rendered = grid.render_rows(1..3);
+        assert!(rendered.lines().all(|line| line.is_ascii() || line.contains('↑') || line.contains('↓')), "{rendered}");
+        assert!(rendered.starts_with(" +"), "{rendered}");
+
+        grid.set_border_style(BorderStyle::None);
+        assert_eq!(grid.render_rows(1..3), "↑ 1 more\nb\nc\n↓ 1 more\n");
+    }
+
+    #[test]
+    fn shortcuts_use_the_border_style_of_the_grid() {
+        let grid = Grid::from([["a", "b"], ["c", "d"]]).with_border(BorderStyle::Ascii);
+        let rendered = [
+            grid.render_viewport(.., ..),
+            grid.render_scrolled(0, 80),
+            grid.log_lines("").join("\n"),
+            grid.view(.., ..).to_string(),
+        ];
+        for output in rendered {
+            assert!(output.contains("+---+---+") && !output.contains('─'), "{output}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn view_render_to_matches_its_display() {
+        let grid = Grid::from([["a", "b"], ["c", "d"]]).with_border(BorderStyle::Ascii);
+        let view = grid.view(1.., ..);
+        let mut output = Vec::new();
+        view.render_to(&mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), view.to_string());
+    }
+
+}
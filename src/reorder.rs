@@ -0,0 +1,115 @@
+use crate::error::ErrorMessage;
+use crate::grid::Grid;
+use crate::history::{Edit, inverse_order};
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+impl Grid {
+
+    /// Moves the columns into a new order, so the display order can differ from the order
+    /// in which the data was loaded, without rebuilding the grid.
+    ///
+    /// Panics if any column index is out of bounds.
+    ///
+    /// The listed columns come first, in the given order, followed by the columns
+    /// that are not listed, in their current order, so `grid.reorder_cols(&[2])` moves
+    /// the third column to the front. A column listed more than once keeps its first position.
+    /// Columns keep their type, validator, and whether their duplicates are merged at their new position.
+    /// The cells are swapped in place, without allocating a copy of the grid.
+
+    pub fn reorder_cols(
+        &mut self,
+        order: &[usize],
+    )
+    {
+        let col_size = self.col_size();
+        if order.iter().any(|&col_index| col_index >= col_size) {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        let order = complete_col_order(order, col_size);
+        // Leave the grid untouched, and its rows clean, if nothing moves
+        if order.iter().enumerate().all(|(col_index, &old_index)| col_index == old_index) {
+            return;
+        }
+
+        self.record_edit(|_| Edit::ReorderCols { order: inverse_order(&order) });
+        self.move_cols(&order);
+
+        let col_types = core::mem::take(self.col_types_mut());
+        *self.col_types_mut() = order.iter()
+            .enumerate()
+            .filter_map(|(col_index, old_index)| Some((col_index, *col_types.get(old_index)?)))
+            .collect();
+
+        let mut col_validators = core::mem::take(self.col_validators_mut());
+        *self.col_validators_mut() = order.iter()
+            .enumerate()
+            .filter_map(|(col_index, old_index)| Some((col_index, col_validators.remove(old_index)?)))
+            .collect();
+
+        let merged: Vec<usize> = self.merged_cols().iter().copied().collect();
+        for &col_index in &merged {
+            self.set_merged_col(col_index, false);
+        }
+        for (col_index, old_index) in order.into_iter().enumerate() {
+            if merged.contains(&old_index) {
+                self.set_merged_col(col_index, true);
+            }
+        }
+    }
+
+    /// Moves the columns into a new order given by their header names, as found by `col_index`,
+    /// e.g. `grid.reorder_cols_by_name(&["id", "name"])`.
+    ///
+    /// Panics if any name is not found in the header row.
+    ///
+    /// See `reorder_cols` for how the columns that are not listed are placed.
+
+    pub fn reorder_cols_by_name(
+        &mut self,
+        names: &[&str],
+    )
+    {
+        let order: Vec<usize> = names.iter()
+            .map(|name| self.col_index(name).unwrap_or_else(|| panic!("{}", ErrorMessage::COL_NAME_NOT_FOUND)))
+            .collect();
+        self.reorder_cols(&order);
+    }
+
+}
+
+/// Completes a column order with the columns that are not listed, in their current order,
+/// dropping the repeated ones, so it holds every column exactly once.
+
+pub(crate) fn complete_col_order(
+    order: &[usize],
+    col_size: usize,
+) -> Vec<usize>
+{
+    let mut listed = vec![false; col_size];
+    order.iter()
+        .copied()
+        .chain(0..col_size)
+        .filter(|&col_index| !core::mem::replace(&mut listed[col_index], true))
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::Grid;
+
+    #[test]
+    fn reorders_every_cycle_of_columns() {
+        let mut grid = Grid::from([["a", "b", "c", "d", "e"], ["1", "2", "3", "4", "5"]]);
+        // Two cycles: 0 -> 1 -> 2 and 3 <-> 4
+        grid.reorder_cols(&[1, 2, 0, 4, 3]);
+        assert_eq!(grid.to_records(), [["b", "c", "a", "e", "d"], ["2", "3", "1", "5", "4"]]);
+        grid.reorder_cols(&[4, 2]);
+        assert_eq!(grid.to_records(), [["d", "a", "b", "c", "e"], ["4", "1", "2", "3", "5"]]);
+    }
+
+}
@@ -0,0 +1,328 @@
+use crate::align::Align;
+use crate::cell::Cell;
+use crate::error::ErrorMessage;
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A row of a grid, as yielded by `Grid::rows_iter`, knowing its index.
+
+#[derive(Debug, Clone, Copy)]
+pub struct RowRef<'a> {
+    row_index: usize,
+    cells: &'a [Cell],
+}
+
+impl<'a> RowRef<'a> {
+
+    /// Gets the index of the row in the grid.
+
+    pub fn index(
+        &self
+    ) -> usize
+    {
+        self.row_index
+    }
+
+    /// Gets the cells of the row, from the first column to the last.
+
+    pub fn cells(
+        &self
+    ) -> &'a [Cell]
+    {
+        self.cells
+    }
+
+    /// Gets the cell in a column of the row, or None if the index is out of bounds.
+
+    pub fn get(
+        &self,
+        col_index: usize,
+    ) -> Option<&'a Cell>
+    {
+        self.cells.get(col_index)
+    }
+
+    /// Gets the data of every cell of the row.
+
+    pub fn data(
+        &self
+    ) -> impl DoubleEndedIterator<Item = &'a str> + ExactSizeIterator
+    {
+        self.cells.iter().map(Cell::get_data)
+    }
+
+    /// Gets the data of every cell of the row as owned strings, stripped of formatting,
+    /// like one row of `Grid::to_records`.
+
+    pub fn to_strings(
+        &self
+    ) -> Vec<String>
+    {
+        self.data().map(String::from).collect()
+    }
+
+    /// Returns true if every cell of the row is empty or only holds whitespace.
+
+    pub fn is_blank(
+        &self
+    ) -> bool
+    {
+        self.data().all(|data| data.trim().is_empty())
+    }
+
+}
+
+/// A row of a grid whose cells can be changed, as returned by `Grid::row_mut` and yielded by `Grid::rows_iter_mut`,
+/// with methods formatting the whole row at once.
+///
+/// As with `Grid::row_iter_mut`, changes made through it are not recorded in the history.
+
+#[derive(Debug)]
+pub struct RowMut<'a> {
+    row_index: usize,
+    cells: &'a mut [Cell],
+}
+
+impl RowMut<'_> {
+
+    /// Gets the index of the row in the grid.
+
+    pub fn index(
+        &self
+    ) -> usize
+    {
+        self.row_index
+    }
+
+    /// Gets the cells of the row, from the first column to the last.
+
+    pub fn cells(
+        &self
+    ) -> &[Cell]
+    {
+        self.cells
+    }
+
+    /// Gets the cells of the row to change them.
+
+    pub fn cells_mut(
+        &mut self
+    ) -> &mut [Cell]
+    {
+        self.cells
+    }
+
+    /// Gets the cell in a column of the row to change it, or None if the index is out of bounds.
+
+    pub fn get_mut(
+        &mut self,
+        col_index: usize,
+    ) -> Option<&mut Cell>
+    {
+        self.cells.get_mut(col_index)
+    }
+
+    /// Gets the row as a read-only `RowRef`, for its data helpers.
+
+    pub fn as_row_ref(
+        &self
+    ) -> RowRef<'_>
+    {
+        RowRef { row_index: self.row_index, cells: self.cells }
+    }
+
+    /// Sets the alignment of every cell of the row, as `Cell::set_align` does.
+
+    pub fn set_align(
+        &mut self,
+        new_align: Align,
+    )
+    {
+        self.cells.iter_mut().for_each(|cell| cell.set_align(new_align));
+    }
+
+    /// Sets the foreground color of every cell of the row, as `Cell::set_color` does.
+
+    pub fn set_color(
+        &mut self,
+        new_color: &str,
+    )
+    {
+        self.cells.iter_mut().for_each(|cell| cell.set_color(new_color));
+    }
+
+    /// Sets the background color of every cell of the row, as `Cell::set_highlight` does.
+
+    pub fn set_highlight(
+        &mut self,
+        new_color: &str,
+    )
+    {
+        self.cells.iter_mut().for_each(|cell| cell.set_highlight(new_color));
+    }
+
+    /// Sets the font style(s) of every cell of the row, as `Cell::set_style` does.
+
+    pub fn set_style(
+        &mut self,
+        style: FontStyle,
+    )
+    {
+        self.cells.iter_mut().for_each(|cell| cell.set_style(style));
+    }
+
+    /// Replaces every cell of the row with a copy of the given cell, data and formatting included.
+
+    pub fn fill(
+        &mut self,
+        cell: impl Into<Cell>,
+    )
+    {
+        self.cells.fill(cell.into());
+    }
+
+    /// Sets the data of the cells of the row from the first column on, keeping their formatting.
+    ///
+    /// Cells beyond the given data are left unchanged, and extra data is ignored.
+
+    pub fn set_data(
+        &mut self,
+        new_data: impl IntoIterator<Item = impl Into<String>>,
+    )
+    {
+        self.cells.iter_mut().zip(new_data).for_each(|(cell, data)| cell.set_data(data));
+    }
+
+    /// Replaces every cell of the row with an empty cell, dropping its data and formatting.
+
+    pub fn clear(
+        &mut self
+    )
+    {
+        self.cells.fill(Cell::default());
+    }
+
+}
+
+impl Grid {
+
+    /// Gets an iterator over every row, footer rows included, each knowing its index,
+    /// instead of looping over the indices and calling `row_iter`, e.g.
+    /// `grid.rows_iter().filter(RowRef::is_blank).map(|row| row.index())`.
+
+    pub fn rows_iter(
+        &self
+    ) -> impl DoubleEndedIterator<Item = RowRef<'_>> + ExactSizeIterator
+    {
+        (0..self.row_size()).map(|row_index| RowRef { row_index, cells: self.row_slice(row_index) })
+    }
+
+    /// Gets a row to change or format all of its cells at once,
+    /// e.g. `grid.row_mut(0).set_style(FontStyle::Bold)`.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// The row is marked as dirty. As with `row_iter_mut`, the changes are not recorded in the history.
+
+    pub fn row_mut(
+        &mut self,
+        row_index: usize,
+    ) -> RowMut<'_>
+    {
+        if row_index >= self.row_size() {
+            panic!("{}", ErrorMessage::ROW_INDEX_OUT_OF_BOUNDS);
+        }
+
+        RowMut { row_index, cells: self.row_slice_mut(row_index) }
+    }
+
+    /// Gets an iterator over every row whose cells can be changed.
+    ///
+    /// Every row is marked as dirty. As with `row_iter_mut`, the changes are not recorded in the history.
+
+    pub fn rows_iter_mut(
+        &mut self
+    ) -> impl Iterator<Item = RowMut<'_>>
+    {
+        self.row_slices_mut()
+            .enumerate()
+            .map(|(row_index, cells)| RowMut { row_index, cells })
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Align, Cell, Color, FontStyle, Grid, RowRef};
+
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn rows_iter_yields_every_row_with_its_index() {
+        let mut grid = Grid::from([["a", "1"], [" ", ""], ["c", "3"]]);
+        grid.set_footer_rows(1);
+        let blank: Vec<usize> = grid.rows_iter().filter(RowRef::is_blank).map(|row| row.index()).collect();
+        assert_eq!(blank, [1]);
+
+        let last = grid.rows_iter().next_back().unwrap();
+        assert_eq!((last.index(), last.cells().len()), (2, 2));
+        assert_eq!(last.get(1).map(|cell| cell.get_data()), Some("3"));
+        assert!(last.get(2).is_none());
+        assert_eq!(last.to_strings(), ["c", "3"]);
+        assert_eq!(grid.rows_iter().len(), 3);
+    }
+
+    #[test]
+    fn rows_iter_mut_changes_the_cells() {
+        let mut grid = Grid::from([["a", "1"], ["b", "2"]]);
+        for mut row in grid.rows_iter_mut() {
+            let index = row.index();
+            if let Some(cell) = row.get_mut(1) {
+                cell.set_data(format!("{}{}", cell.get_data(), index));
+            }
+            if index == 1 {
+                row.clear();
+            }
+        }
+        let rows: Vec<Vec<String>> = grid.rows_iter().map(|row| row.to_strings()).collect();
+        assert_eq!(rows, [vec!["a", "10"], vec!["", ""]]);
+    }
+
+    #[test]
+    fn row_mut_formats_the_whole_row() {
+        let mut grid = Grid::from([["name", "qty"], ["apple", "3"]]);
+        let mut header = grid.row_mut(0);
+        header.set_style(FontStyle::Bold);
+        header.set_align(Align::Center);
+        header.set_color(Color::BLUE);
+        header.set_highlight(Color::WHITE);
+        assert!(grid.row_iter(0).all(|cell| cell.get_style().contains(FontStyle::Bold)
+            && cell.get_align().contains(Align::Center)
+            && cell.get_color() == Some(Color::BLUE)
+            && cell.get_highlight() == Some(Color::WHITE)));
+        assert!(grid.row_iter(1).all(|cell| cell.get_color().is_none()));
+
+        grid.row_mut(0).set_data(["fruit"]);
+        assert_eq!(grid.rows_iter().next().unwrap().to_strings(), ["fruit", "qty"]);
+        assert!(grid.get_cell(0, 0).unwrap().get_style().contains(FontStyle::Bold));
+
+        grid.row_mut(1).fill(Cell::from("-"));
+        assert_eq!(grid.rows_iter().nth(1).unwrap().to_strings(), ["-", "-"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn row_mut_rejects_rows_out_of_bounds() {
+        let mut grid = Grid::from([["a"]]);
+        grid.row_mut(1);
+    }
+
+}
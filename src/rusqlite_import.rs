@@ -0,0 +1,67 @@
+use crate::align::Align;
+use crate::cell::Cell;
+use crate::grid::Grid;
+
+use rusqlite::types::ValueRef;
+use rusqlite::{Params, Statement};
+
+impl Grid {
+
+    /// Create a new grid by running a prepared statement and consuming all of its rows.
+    ///
+    /// The first row is a header holding the names from `Statement::column_names`,
+    /// formatted in bold and centered, so it is present even if the query returns no rows.
+    /// `NULL` values are shown as a dimmed `null`, numbers are right-aligned,
+    /// and BLOBs are shown as hexadecimal, like `0x1f8b`, or as their length if longer than 16 bytes.
+    /// Text that is not valid UTF-8 is shown with replacement characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The prepared statement to run
+    /// * `params` - The parameters bound to the statement, e.g. `[]` or `(1, "alice")`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameters cannot be bound or a row cannot be read
+
+    pub fn from_rusqlite(
+        statement: &mut Statement<'_>,
+        params: impl Params,
+    ) -> rusqlite::Result<Self>
+    {
+        let header: Vec<Cell> = statement.column_names().into_iter().map(Cell::new).collect();
+        let col_size = header.len();
+        let mut grid_rows = vec![header];
+
+        let mut rows = statement.query(params)?;
+        while let Some(row) = rows.next()? {
+            let mut cells = Vec::with_capacity(col_size);
+            for index in 0..col_size {
+                cells.push(Grid::rusqlite_cell(row.get_ref(index)?));
+            }
+            grid_rows.push(cells);
+        }
+
+        let mut grid = Grid::from(grid_rows);
+        grid.style_header_row();
+        Ok(grid)
+    }
+
+    /// Converts a value of a result row into a cell.
+
+    fn rusqlite_cell(
+        value: ValueRef<'_>,
+    ) -> Cell
+    {
+        let mut cell = match value {
+            ValueRef::Null => return Cell::null(),
+            ValueRef::Integer(number) => Cell::from(number),
+            ValueRef::Real(number) => Cell::from(number),
+            ValueRef::Text(text) => return Cell::new(String::from_utf8_lossy(text)),
+            ValueRef::Blob(bytes) => return Cell::blob(bytes),
+        };
+        cell.set_align(Align::Right);
+        cell
+    }
+
+}
@@ -0,0 +1,198 @@
+use crate::cell::Cell;
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+use crate::render::Segment;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// The pattern whose matches are highlighted when rendering a grid, set with `Grid::highlight_matches`.
+///
+/// Matches are found at render time in each line of text as it is rendered, so they always
+/// reflect the current cells, and a match split across wrapped lines is not highlighted.
+/// Highlights never affect equality, so two grids with the same cells are always equal.
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MatchHighlight {
+    pattern: Option<Pattern>,
+    style: FontStyle,
+}
+
+/// A pattern to search for in the text of cells.
+
+#[derive(Debug, Clone)]
+enum Pattern {
+    Literal(String),
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl Pattern {
+
+    /// Finds the first non-empty match in the text, starting from a byte offset.
+
+    fn find_at(
+        &self,
+        text: &str,
+        mut start: usize,
+    ) -> Option<Range<usize>>
+    {
+        loop {
+            let range = match self {
+                Pattern::Literal(literal) => text[start..].find(literal.as_str()).map(|index| start + index..start + index + literal.len()),
+                #[cfg(feature = "regex")]
+                Pattern::Regex(regex) => regex.find_at(text, start).map(|found| found.range()),
+            }?;
+            if !range.is_empty() {
+                return Some(range);
+            }
+            // Skip empty matches, which could not be seen anyway
+            start = range.start + text[range.start..].chars().next()?.len_utf8();
+        }
+    }
+
+}
+
+impl MatchHighlight {
+
+    /// Gets the number of heap bytes used by a literal pattern; a compiled regex is not counted.
+
+    pub(crate) fn heap_bytes(
+        &self
+    ) -> usize
+    {
+        match &self.pattern {
+            Some(Pattern::Literal(literal)) => literal.capacity(),
+            _ => 0,
+        }
+    }
+
+    /// Returns true if a pattern is set.
+
+    pub(crate) fn is_active(
+        &self
+    ) -> bool
+    {
+        self.pattern.is_some()
+    }
+
+    /// Emits a line of text of a cell as styled segments, with the matches in the highlight style.
+
+    pub(crate) fn write_styled_text<E>(
+        &self,
+        text: &str,
+        cell: &Cell,
+        emit: &mut dyn FnMut(Segment<'_>) -> Result<(), E>,
+    ) -> Result<(), E>
+    {
+        let Some(pattern) = &self.pattern else {
+            return emit(Segment::Styled(text, cell));
+        };
+
+        let mut highlighted = None;
+        let mut start = 0;
+        while let Some(range) = pattern.find_at(text, start) {
+            let highlighted = highlighted.get_or_insert_with(|| cell.with_added_style(self.style));
+            if range.start > start {
+                emit(Segment::Styled(&text[start..range.start], cell))?;
+            }
+            emit(Segment::Styled(&text[range.clone()], highlighted))?;
+            start = range.end;
+        }
+        if start < text.len() || start == 0 {
+            emit(Segment::Styled(&text[start..], cell))?;
+        }
+        Ok(())
+    }
+
+}
+
+impl PartialEq for MatchHighlight {
+
+    fn eq(
+        &self,
+        _other: &Self,
+    ) -> bool
+    {
+        true
+    }
+
+}
+
+impl Eq for MatchHighlight {}
+
+impl Grid {
+
+    /// Highlights every occurrence of a text in the cells with a font style, e.g. for `/`-search in a table viewer.
+    ///
+    /// The cells are not modified: the matches are styled when the grid is rendered with colors,
+    /// combined with the cell's own styles, until the highlight is replaced or cleared.
+    /// The search is case-sensitive, and an empty text clears the highlight.
+    /// Every row is marked as dirty, since the highlighted rows render differently.
+    ///
+    /// # Returns
+    ///
+    /// The row and column of every cell containing the text, in row-major order,
+    /// e.g. to jump from one match to the next
+
+    pub fn highlight_matches(
+        &mut self,
+        pattern: &str,
+        style: FontStyle,
+    ) -> Vec<(usize, usize)>
+    {
+        let pattern = (!pattern.is_empty()).then(|| Pattern::Literal(pattern.to_string()));
+        self.set_match_highlight(MatchHighlight { pattern, style })
+    }
+
+    /// Highlights every match of a regular expression in the cells with a font style.
+    ///
+    /// This works like `highlight_matches`, with a regular expression instead of a text,
+    /// e.g. `(?i)error` for a case-insensitive search. Empty matches are not highlighted.
+    ///
+    /// # Returns
+    ///
+    /// The row and column of every cell with a match, in row-major order
+
+    #[cfg(feature = "regex")]
+    pub fn highlight_regex(
+        &mut self,
+        regex: &regex::Regex,
+        style: FontStyle,
+    ) -> Vec<(usize, usize)>
+    {
+        self.set_match_highlight(MatchHighlight { pattern: Some(Pattern::Regex(regex.clone())), style })
+    }
+
+    /// Removes the highlight set by `highlight_matches`.
+
+    pub fn clear_highlight(
+        &mut self
+    )
+    {
+        self.set_match_highlight(MatchHighlight::default());
+    }
+
+    /// Replaces the highlight, marking every row as dirty, and finds the cells with a match.
+
+    fn set_match_highlight(
+        &mut self,
+        highlight: MatchHighlight,
+    ) -> Vec<(usize, usize)>
+    {
+        let col_size = self.col_size();
+        let matches = match &highlight.pattern {
+            Some(pattern) => self.flat_iter()
+                .enumerate()
+                .filter(|(_, cell)| pattern.find_at(cell.get_data(), 0).is_some())
+                .map(|(index, _)| (index / col_size, index % col_size))
+                .collect(),
+            None => Vec::new(),
+        };
+        self.dirty_rows_mut().mark_from(0);
+        *self.match_highlight_mut() = highlight;
+        matches
+    }
+
+}
@@ -0,0 +1,405 @@
+use crate::cell::Cell;
+use crate::error::ErrorMessage;
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+use crate::memory::btree_bytes;
+use crate::render::resolve_range;
+
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use core::mem::size_of;
+use core::ops::{Range, RangeBounds};
+
+/// The selected cells of a grid, shown in the selection style when rendering, set with `Grid::select_row`,
+/// `Grid::select_range`, and `Grid::toggle_cell`.
+///
+/// Selected rows, columns, and regions are kept as ranges rather than one entry per cell,
+/// with the cells toggled on top of them kept apart, so selecting a column of a large grid is cheap.
+/// Cells are selected by position, so the selection stays in place when rows are inserted or sorted.
+/// The selection never affects equality, so two grids with the same cells are always equal.
+
+#[derive(Debug, Clone)]
+pub(crate) struct Selection {
+    /// The selected regions, as row and column ranges.
+    regions: Vec<(Range<usize>, Range<usize>)>,
+    /// The cells toggled with `Grid::toggle_cell`, which are selected if and only if
+    /// they are outside every region.
+    toggled: BTreeSet<(usize, usize)>,
+    style: FontStyle,
+}
+
+impl Default for Selection {
+
+    fn default() -> Self {
+        Selection {
+            regions: Vec::new(),
+            toggled: BTreeSet::new(),
+            style: FontStyle::Reverse,
+        }
+    }
+
+}
+
+impl Selection {
+
+    /// Gets the number of heap bytes used by the selected regions and toggled cells, approximately.
+
+    pub(crate) fn heap_bytes(
+        &self
+    ) -> usize
+    {
+        self.regions.capacity() * size_of::<(Range<usize>, Range<usize>)>()
+            + btree_bytes::<(usize, usize)>(self.toggled.len())
+    }
+
+    /// Deselects every cell.
+
+    pub(crate) fn clear(
+        &mut self
+    )
+    {
+        self.regions.clear();
+        self.toggled.clear();
+    }
+
+    /// Deselects the cells outside a grid of the given size, after resizing it.
+
+    pub(crate) fn retain_in(
+        &mut self,
+        row_size: usize,
+        col_size: usize,
+    )
+    {
+        for (rows, cols) in &mut self.regions {
+            rows.end = rows.end.min(row_size);
+            cols.end = cols.end.min(col_size);
+        }
+        self.regions.retain(|(rows, cols)| !rows.is_empty() && !cols.is_empty());
+        self.toggled.retain(|&(row_index, col_index)| row_index < row_size && col_index < col_size);
+    }
+
+    /// Selects every cell of a region, including the toggled ones.
+
+    fn select(
+        &mut self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    )
+    {
+        self.toggled.retain(|(row_index, col_index)| !rows.contains(row_index) || !cols.contains(col_index));
+        let covered = self.regions.iter().any(|(selected_rows, selected_cols)|
+            selected_rows.start <= rows.start && rows.end <= selected_rows.end
+                && selected_cols.start <= cols.start && cols.end <= selected_cols.end
+        );
+        if !covered {
+            self.regions.push((rows, cols));
+        }
+    }
+
+    /// Returns true if the cell is in a selected region.
+
+    fn in_region(
+        &self,
+        row_index: usize,
+        col_index: usize,
+    ) -> bool
+    {
+        self.regions.iter().any(|(rows, cols)| rows.contains(&row_index) && cols.contains(&col_index))
+    }
+
+    /// Returns true if any cell of the row is selected.
+
+    pub(crate) fn contains_row(
+        &self,
+        row_index: usize,
+    ) -> bool
+    {
+        let toggled = || self.toggled.range((row_index, 0)..=(row_index, usize::MAX));
+        // A region is selected in the row unless all of its cells in the row were toggled off
+        let in_region = self.regions.iter()
+            .filter(|(rows, _)| rows.contains(&row_index))
+            .any(|(_, cols)| toggled().filter(|(_, col_index)| cols.contains(col_index)).count() < cols.len());
+        in_region || toggled().any(|&(_, col_index)| !self.in_region(row_index, col_index))
+    }
+
+    /// Gets the rows with at least one selected cell, in ascending order.
+
+    fn rows(
+        &self
+    ) -> Vec<usize>
+    {
+        let candidates: BTreeSet<usize> = self.regions.iter()
+            .flat_map(|(rows, _)| rows.clone())
+            .chain(self.toggled.iter().map(|&(row_index, _)| row_index))
+            .collect();
+        candidates.into_iter().filter(|&row_index| self.contains_row(row_index)).collect()
+    }
+
+    /// Gets the row and column of every selected cell, in row-major order.
+
+    fn cells(
+        &self
+    ) -> Vec<(usize, usize)>
+    {
+        let candidates: BTreeSet<(usize, usize)> = self.regions.iter()
+            .flat_map(|(rows, cols)| rows.clone().flat_map(move |row_index| cols.clone().map(move |col_index| (row_index, col_index))))
+            .chain(self.toggled.iter().copied())
+            .collect();
+        candidates.into_iter().filter(|&(row_index, col_index)| self.contains(row_index, col_index)).collect()
+    }
+
+    /// Creates the cell whose styles are used for the text of a selected cell.
+
+    pub(crate) fn selected_cell(
+        &self,
+        cell: &Cell,
+    ) -> Cell
+    {
+        cell.with_added_style(self.style)
+    }
+
+    /// Returns true if the cell is selected.
+
+    pub(crate) fn contains(
+        &self,
+        row_index: usize,
+        col_index: usize,
+    ) -> bool
+    {
+        self.in_region(row_index, col_index) != self.toggled.contains(&(row_index, col_index))
+    }
+
+}
+
+impl PartialEq for Selection {
+
+    fn eq(
+        &self,
+        _other: &Self,
+    ) -> bool
+    {
+        true
+    }
+
+}
+
+impl Eq for Selection {}
+
+impl Grid {
+
+    /// Selects every cell of a row, e.g. the current entry of a picker.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// The cells are not modified: selected cells are shown in the selection style
+    /// when the grid is rendered with colors, combined with the cell's own styles,
+    /// across the whole width of their column.
+
+    pub fn select_row(
+        &mut self,
+        row_index: usize,
+    )
+    {
+        if row_index >= self.row_size() {
+            panic!("{}", ErrorMessage::ROW_INDEX_OUT_OF_BOUNDS);
+        }
+
+        self.select_range(row_index..=row_index, ..);
+    }
+
+    /// Selects every cell of a column.
+    ///
+    /// Panics if the index is out of bounds.
+
+    pub fn select_col(
+        &mut self,
+        col_index: usize,
+    )
+    {
+        if col_index >= self.col_size() {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        self.select_range(.., col_index..=col_index);
+    }
+
+    /// Selects every cell of a rectangular region, keeping the cells already selected.
+    ///
+    /// Both ranges accept any range syntax (`2..5`, `..10`, `3..`, `..`)
+    /// and are clamped to the size of the grid, as in `view`.
+
+    pub fn select_range(
+        &mut self,
+        row_range: impl RangeBounds<usize>,
+        col_range: impl RangeBounds<usize>,
+    )
+    {
+        let rows = resolve_range(row_range, self.row_size());
+        let cols = resolve_range(col_range, self.col_size());
+        if rows.is_empty() || cols.is_empty() {
+            return;
+        }
+        for row_index in rows.clone() {
+            self.dirty_rows_mut().mark(row_index);
+        }
+        self.selection_mut().select(rows, cols);
+    }
+
+    /// Selects a cell if it is not selected, and deselects it otherwise, e.g. for a multi-select list.
+    ///
+    /// Panics if either index is out of bounds.
+    ///
+    /// # Returns
+    ///
+    /// True if the cell is now selected
+
+    pub fn toggle_cell(
+        &mut self,
+        row_index: usize,
+        col_index: usize,
+    ) -> bool
+    {
+        if row_index >= self.row_size() && col_index >= self.col_size() {
+            panic!("{}", ErrorMessage::ROW_AND_COL_INDEX_OUT_OF_BOUNDS);
+        }
+        if row_index >= self.row_size() {
+            panic!("{}", ErrorMessage::ROW_INDEX_OUT_OF_BOUNDS);
+        }
+        if col_index >= self.col_size() {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        self.dirty_rows_mut().mark(row_index);
+        let selection = self.selection_mut();
+        if !selection.toggled.remove(&(row_index, col_index)) {
+            selection.toggled.insert((row_index, col_index));
+        }
+        selection.contains(row_index, col_index)
+    }
+
+    /// Deselects every cell.
+
+    pub fn clear_selection(
+        &mut self
+    )
+    {
+        for row_index in self.selected_rows() {
+            self.dirty_rows_mut().mark(row_index);
+        }
+        self.selection_mut().clear();
+    }
+
+    /// Returns true if the cell is selected. Cells out of bounds are never selected.
+
+    pub fn is_selected(
+        &self,
+        row_index: usize,
+        col_index: usize,
+    ) -> bool
+    {
+        self.selection().contains(row_index, col_index)
+    }
+
+    /// Gets the row and column of every selected cell, in row-major order.
+
+    pub fn selected_cells(
+        &self
+    ) -> Vec<(usize, usize)>
+    {
+        self.selection().cells()
+    }
+
+    /// Gets the rows with at least one selected cell, in ascending order,
+    /// e.g. the entries chosen in a multi-select list.
+
+    pub fn selected_rows(
+        &self
+    ) -> Vec<usize>
+    {
+        self.selection().rows()
+    }
+
+    /// Gets the font style added to the selected cells when rendering, `FontStyle::Reverse` by default.
+
+    pub fn selection_style(
+        &self
+    ) -> FontStyle
+    {
+        self.selection().style
+    }
+
+    /// Sets the font style added to the selected cells when rendering, e.g. `FontStyle::Bold | FontStyle::Underline`.
+    ///
+    /// Every row with a selected cell is marked as dirty, since it renders differently.
+
+    pub fn set_selection_style(
+        &mut self,
+        style: FontStyle,
+    )
+    {
+        for row_index in self.selected_rows() {
+            self.dirty_rows_mut().mark(row_index);
+        }
+        self.selection_mut().style = style;
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::Grid;
+
+    fn grid() -> Grid {
+        Grid::new(4, 3)
+    }
+
+    #[test]
+    fn rows_and_columns_are_kept_as_ranges() {
+        let mut grid = grid();
+        grid.select_col(1);
+        grid.select_row(2);
+        grid.select_row(2);
+        assert_eq!(grid.selection().regions.len(), 2);
+        assert!(grid.selection().toggled.is_empty());
+        assert_eq!(grid.selected_cells(), [(0, 1), (1, 1), (2, 0), (2, 1), (2, 2), (3, 1)]);
+        assert_eq!(grid.selected_rows(), [0, 1, 2, 3]);
+        assert!(grid.is_selected(3, 1));
+        assert!(!grid.is_selected(3, 2));
+        assert!(!grid.is_selected(9, 1));
+    }
+
+    #[test]
+    fn toggling_flips_cells_inside_and_outside_regions() {
+        let mut grid = grid();
+        grid.select_range(0..2, 0..2);
+        assert!(!grid.toggle_cell(0, 0));
+        assert!(grid.toggle_cell(3, 2));
+        assert_eq!(grid.selected_cells(), [(0, 1), (1, 0), (1, 1), (3, 2)]);
+
+        // Deselecting every cell of a row in a region leaves the row unselected
+        assert!(!grid.toggle_cell(0, 1));
+        assert_eq!(grid.selected_rows(), [1, 3]);
+
+        // Selecting a region again selects its toggled cells
+        grid.select_range(..1, ..);
+        assert_eq!(grid.selected_rows(), [0, 1, 3]);
+        assert!(grid.is_selected(0, 0));
+        assert!(!grid.toggle_cell(0, 0));
+        assert!(grid.toggle_cell(0, 0));
+    }
+
+    #[test]
+    fn resizing_and_clearing_drop_the_selection() {
+        let mut grid = grid();
+        grid.select_range(1.., 1..);
+        grid.toggle_cell(0, 0);
+        grid.resize(2, 2);
+        assert_eq!(grid.selected_cells(), [(0, 0), (1, 1)]);
+        grid.take_dirty_rows();
+        grid.clear_selection();
+        assert!(grid.selected_cells().is_empty());
+        assert_eq!(grid.take_dirty_rows(), [0, 1]);
+    }
+
+}
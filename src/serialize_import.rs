@@ -0,0 +1,1329 @@
+use crate::cell::Cell;
+use crate::grid::Grid;
+
+use serde::de::value::Error;
+use serde::ser::{
+    Error as _, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+    SerializeTuple, SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+use std::collections::HashMap;
+
+/// The fields of a serialized item as named cells, in order of appearance.
+
+type Row = Vec<(String, Cell)>;
+
+impl Grid {
+
+    /// Create a new grid from any serializable value, such as a `Vec` of structs or maps.
+    ///
+    /// The value is laid out like `Grid::from_json`: the first row is a header holding the union
+    /// of all struct fields and map keys, in order of first appearance, formatted in bold and centered,
+    /// and every item of the sequence becomes a row. A single struct or map becomes a single row.
+    ///
+    /// Strings are shown without quotes, numbers and booleans as written,
+    /// and nested sequences, structs, and maps as compact JSON.
+    /// `None`, unit values, and missing fields are shown as a dimmed `null`.
+    /// Items that are not structs or maps are placed under a `value` column.
+    ///
+    /// The value is serialized straight into rows, without any intermediate JSON value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value fails to serialize, or is neither a sequence nor a struct or map
+
+    pub fn from_serialize(
+        value: &(impl Serialize + ?Sized),
+    ) -> Result<Self, Error>
+    {
+        let rows = value.serialize(RowsSerializer)?;
+
+        let mut col_indices: HashMap<String, usize> = HashMap::new();
+        let mut header: Vec<Cell> = Vec::new();
+        for (key, _) in rows.iter().flatten() {
+            if !col_indices.contains_key(key) {
+                col_indices.insert(key.clone(), header.len());
+                header.push(Cell::new(key.as_str()));
+            }
+        }
+
+        let col_size = header.len();
+        let data_rows = rows.into_iter().map(|row| {
+            let mut cells = vec![Cell::null(); col_size];
+            for (key, cell) in row {
+                cells[col_indices[&key]] = cell;
+            }
+            cells
+        });
+
+        let mut grid = Grid::from(std::iter::once(header).chain(data_rows));
+        grid.style_header_row();
+        Ok(grid)
+    }
+
+}
+
+/// The error returned when the serialized value cannot be laid out as rows.
+
+fn not_rows() -> Error {
+    Error::custom("expected a sequence of structs or maps")
+}
+
+/// Generates serializer methods for primitive values that forward to another serializer
+/// and convert its output.
+
+macro_rules! forward_primitives {
+    ($serializer:expr, $convert:expr; $($method:ident($type:ty)),* $(,)?) => {
+        $(
+            fn $method(
+                self,
+                value: $type,
+            ) -> Result<Self::Ok, Error>
+            {
+                $serializer.$method(value).map($convert)
+            }
+        )*
+    };
+}
+
+/// Generates implementations of the compound serializer traits taking unnamed elements,
+/// forwarding to the `element` and `finish` methods of the type.
+
+macro_rules! impl_elements {
+    ($type:ty => $ok:ty; $($trait:ident::$method:ident),* $(,)?) => {
+        $(
+            impl $trait for $type {
+                type Ok = $ok;
+                type Error = Error;
+
+                fn $method<T: Serialize + ?Sized>(
+                    &mut self,
+                    value: &T,
+                ) -> Result<(), Error>
+                {
+                    self.element(value)
+                }
+
+                fn end(
+                    self
+                ) -> Result<$ok, Error>
+                {
+                    Ok(self.finish())
+                }
+            }
+        )*
+    };
+}
+
+/// Generates implementations of the compound serializer traits taking named fields,
+/// forwarding to the `field` and `finish` methods of the type.
+
+macro_rules! impl_fields {
+    ($type:ty => $ok:ty; $($trait:ident),* $(,)?) => {
+        $(
+            impl $trait for $type {
+                type Ok = $ok;
+                type Error = Error;
+
+                fn serialize_field<T: Serialize + ?Sized>(
+                    &mut self,
+                    key: &'static str,
+                    value: &T,
+                ) -> Result<(), Error>
+                {
+                    self.field(key, value)
+                }
+
+                fn end(
+                    self
+                ) -> Result<$ok, Error>
+                {
+                    Ok(self.finish())
+                }
+            }
+        )*
+    };
+}
+
+/// Serializes the top-level value: a sequence of items, or a single struct or map.
+
+struct RowsSerializer;
+
+/// Generates serializer methods for values that cannot be laid out as rows.
+
+macro_rules! reject_primitives {
+    ($($method:ident($type:ty)),* $(,)?) => {
+        $(
+            fn $method(
+                self,
+                _value: $type,
+            ) -> Result<Self::Ok, Error>
+            {
+                Err(not_rows())
+            }
+        )*
+    };
+}
+
+impl Serializer for RowsSerializer {
+    type Ok = Vec<Row>;
+    type Error = Error;
+    type SerializeSeq = Rows;
+    type SerializeTuple = Rows;
+    type SerializeTupleStruct = Rows;
+    type SerializeTupleVariant = SingleRow<RowValue>;
+    type SerializeMap = SingleRow<RowFields>;
+    type SerializeStruct = SingleRow<RowFields>;
+    type SerializeStructVariant = SingleRow<RowValue>;
+
+    reject_primitives!(
+        serialize_bool(bool), serialize_i8(i8), serialize_i16(i16), serialize_i32(i32), serialize_i64(i64),
+        serialize_i128(i128), serialize_u8(u8), serialize_u16(u16), serialize_u32(u32), serialize_u64(u64),
+        serialize_u128(u128), serialize_f32(f32), serialize_f64(f64), serialize_char(char),
+        serialize_str(&str), serialize_bytes(&[u8]), serialize_unit_struct(&'static str),
+    );
+
+    fn serialize_none(
+        self
+    ) -> Result<Vec<Row>, Error>
+    {
+        Err(not_rows())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<Vec<Row>, Error>
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(
+        self
+    ) -> Result<Vec<Row>, Error>
+    {
+        Err(not_rows())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Vec<Row>, Error>
+    {
+        Err(not_rows())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Vec<Row>, Error>
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Vec<Row>, Error>
+    {
+        RowSerializer.serialize_newtype_variant(name, variant_index, variant, value).map(|row| vec![row])
+    }
+
+    fn serialize_seq(
+        self,
+        len: Option<usize>,
+    ) -> Result<Rows, Error>
+    {
+        Ok(Rows(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(
+        self,
+        len: usize,
+    ) -> Result<Rows, Error>
+    {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Rows, Error>
+    {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SingleRow<RowValue>, Error>
+    {
+        RowSerializer.serialize_tuple_variant(name, variant_index, variant, len).map(SingleRow)
+    }
+
+    fn serialize_map(
+        self,
+        len: Option<usize>,
+    ) -> Result<SingleRow<RowFields>, Error>
+    {
+        RowSerializer.serialize_map(len).map(SingleRow)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<SingleRow<RowFields>, Error>
+    {
+        RowSerializer.serialize_struct(name, len).map(SingleRow)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SingleRow<RowValue>, Error>
+    {
+        RowSerializer.serialize_struct_variant(name, variant_index, variant, len).map(SingleRow)
+    }
+}
+
+/// Collects the items of the top-level sequence as rows.
+
+struct Rows(Vec<Row>);
+
+impl Rows {
+
+    fn element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.0.push(value.serialize(RowSerializer)?);
+        Ok(())
+    }
+
+    fn finish(
+        self
+    ) -> Vec<Row>
+    {
+        self.0
+    }
+
+}
+
+impl_elements!(Rows => Vec<Row>; SerializeSeq::serialize_element, SerializeTuple::serialize_element, SerializeTupleStruct::serialize_field);
+
+/// Turns the single row of a top-level struct, map, or enum variant into the rows of the grid.
+
+struct SingleRow<S>(S);
+
+impl<S: SerializeMap<Ok = Row, Error = Error>> SerializeMap for SingleRow<S> {
+    type Ok = Vec<Row>;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(
+        &mut self,
+        key: &T,
+    ) -> Result<(), Error>
+    {
+        self.0.serialize_key(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.0.serialize_value(value)
+    }
+
+    fn end(
+        self
+    ) -> Result<Vec<Row>, Error>
+    {
+        self.0.end().map(|row| vec![row])
+    }
+}
+
+impl<S: SerializeStruct<Ok = Row, Error = Error>> SerializeStruct for SingleRow<S> {
+    type Ok = Vec<Row>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.0.serialize_field(key, value)
+    }
+
+    fn end(
+        self
+    ) -> Result<Vec<Row>, Error>
+    {
+        self.0.end().map(|row| vec![row])
+    }
+}
+
+impl<S: SerializeTupleVariant<Ok = Row, Error = Error>> SerializeTupleVariant for SingleRow<S> {
+    type Ok = Vec<Row>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.0.serialize_field(value)
+    }
+
+    fn end(
+        self
+    ) -> Result<Vec<Row>, Error>
+    {
+        self.0.end().map(|row| vec![row])
+    }
+}
+
+impl<S: SerializeStructVariant<Ok = Row, Error = Error>> SerializeStructVariant for SingleRow<S> {
+    type Ok = Vec<Row>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.0.serialize_field(key, value)
+    }
+
+    fn end(
+        self
+    ) -> Result<Vec<Row>, Error>
+    {
+        self.0.end().map(|row| vec![row])
+    }
+}
+
+/// Serializes an item of the sequence as a row: the fields of a struct or the entries of a map,
+/// the value of an enum variant under the variant name, or any other value under `value`.
+
+struct RowSerializer;
+
+/// Puts a cell under the `value` column, for items that are not structs or maps.
+
+fn value_row(
+    cell: Cell,
+) -> Row
+{
+    vec![(String::from("value"), cell)]
+}
+
+impl Serializer for RowSerializer {
+    type Ok = Row;
+    type Error = Error;
+    type SerializeSeq = RowValue;
+    type SerializeTuple = RowValue;
+    type SerializeTupleStruct = RowValue;
+    type SerializeTupleVariant = RowValue;
+    type SerializeMap = RowFields;
+    type SerializeStruct = RowFields;
+    type SerializeStructVariant = RowValue;
+
+    forward_primitives!(CellSerializer, value_row;
+        serialize_bool(bool), serialize_i8(i8), serialize_i16(i16), serialize_i32(i32), serialize_i64(i64),
+        serialize_i128(i128), serialize_u8(u8), serialize_u16(u16), serialize_u32(u32), serialize_u64(u64),
+        serialize_u128(u128), serialize_f32(f32), serialize_f64(f64), serialize_char(char),
+        serialize_str(&str), serialize_bytes(&[u8]), serialize_unit_struct(&'static str),
+    );
+
+    fn serialize_none(
+        self
+    ) -> Result<Row, Error>
+    {
+        Ok(value_row(Cell::null()))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<Row, Error>
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(
+        self
+    ) -> Result<Row, Error>
+    {
+        Ok(value_row(Cell::null()))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Row, Error>
+    {
+        Ok(value_row(Cell::new(variant)))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Row, Error>
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Row, Error>
+    {
+        Ok(vec![(String::from(variant), value.serialize(CellSerializer)?)])
+    }
+
+    fn serialize_seq(
+        self,
+        _len: Option<usize>,
+    ) -> Result<RowValue, Error>
+    {
+        Ok(RowValue { json: JsonCompound::new("[", "]"), key: "value" })
+    }
+
+    fn serialize_tuple(
+        self,
+        len: usize,
+    ) -> Result<RowValue, Error>
+    {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<RowValue, Error>
+    {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<RowValue, Error>
+    {
+        Ok(RowValue { json: JsonCompound::new("[", "]"), key: variant })
+    }
+
+    fn serialize_map(
+        self,
+        len: Option<usize>,
+    ) -> Result<RowFields, Error>
+    {
+        Ok(RowFields { row: Vec::with_capacity(len.unwrap_or(0)), key: None })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<RowFields, Error>
+    {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<RowValue, Error>
+    {
+        Ok(RowValue { json: JsonCompound::new("{", "}"), key: variant })
+    }
+}
+
+/// Collects the fields of a struct or the entries of a map as the cells of a row.
+
+struct RowFields {
+    row: Row,
+    key: Option<String>,
+}
+
+impl RowFields {
+
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.row.push((String::from(key), value.serialize(CellSerializer)?));
+        Ok(())
+    }
+
+    fn finish(
+        self
+    ) -> Row
+    {
+        self.row
+    }
+
+}
+
+impl SerializeMap for RowFields {
+    type Ok = Row;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(
+        &mut self,
+        key: &T,
+    ) -> Result<(), Error>
+    {
+        self.key = Some(key.serialize(CellSerializer)?.into_data());
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        let key = self.key.take().ok_or_else(|| Error::custom("map value without a key"))?;
+        self.field(&key, value)
+    }
+
+    fn end(
+        self
+    ) -> Result<Row, Error>
+    {
+        Ok(self.finish())
+    }
+}
+
+impl_fields!(RowFields => Row; SerializeStruct);
+
+/// Collects an item that is not a struct or map as compact JSON in a single cell,
+/// under `value` or the name of its enum variant.
+
+struct RowValue {
+    json: JsonCompound,
+    key: &'static str,
+}
+
+impl RowValue {
+
+    fn element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.json.element(value)
+    }
+
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.json.field(key, value)
+    }
+
+    fn finish(
+        self
+    ) -> Row
+    {
+        vec![(String::from(self.key), Cell::new(self.json.finish()))]
+    }
+
+}
+
+impl_elements!(RowValue => Row; SerializeSeq::serialize_element, SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field, SerializeTupleVariant::serialize_field);
+impl_fields!(RowValue => Row; SerializeStructVariant);
+
+/// Serializes a field value as a cell: strings as is, `None` and unit values as null cells,
+/// and anything else as compact JSON.
+
+struct CellSerializer;
+
+impl Serializer for CellSerializer {
+    type Ok = Cell;
+    type Error = Error;
+    type SerializeSeq = CellCompound;
+    type SerializeTuple = CellCompound;
+    type SerializeTupleStruct = CellCompound;
+    type SerializeTupleVariant = CellCompound;
+    type SerializeMap = CellCompound;
+    type SerializeStruct = CellCompound;
+    type SerializeStructVariant = CellCompound;
+
+    forward_primitives!(JsonSerializer, Cell::new;
+        serialize_bool(bool), serialize_i8(i8), serialize_i16(i16), serialize_i32(i32), serialize_i64(i64),
+        serialize_i128(i128), serialize_u8(u8), serialize_u16(u16), serialize_u32(u32), serialize_u64(u64),
+        serialize_u128(u128), serialize_bytes(&[u8]),
+    );
+
+    fn serialize_f32(
+        self,
+        value: f32,
+    ) -> Result<Cell, Error>
+    {
+        if !value.is_finite() {
+            return Ok(Cell::null());
+        }
+        JsonSerializer.serialize_f32(value).map(Cell::new)
+    }
+
+    fn serialize_f64(
+        self,
+        value: f64,
+    ) -> Result<Cell, Error>
+    {
+        if !value.is_finite() {
+            return Ok(Cell::null());
+        }
+        JsonSerializer.serialize_f64(value).map(Cell::new)
+    }
+
+    fn serialize_char(
+        self,
+        value: char,
+    ) -> Result<Cell, Error>
+    {
+        Ok(Cell::new(value))
+    }
+
+    fn serialize_str(
+        self,
+        value: &str,
+    ) -> Result<Cell, Error>
+    {
+        Ok(Cell::new(value))
+    }
+
+    fn serialize_none(
+        self
+    ) -> Result<Cell, Error>
+    {
+        Ok(Cell::null())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<Cell, Error>
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(
+        self
+    ) -> Result<Cell, Error>
+    {
+        Ok(Cell::null())
+    }
+
+    fn serialize_unit_struct(
+        self,
+        _name: &'static str,
+    ) -> Result<Cell, Error>
+    {
+        Ok(Cell::null())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Cell, Error>
+    {
+        Ok(Cell::new(variant))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Cell, Error>
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Cell, Error>
+    {
+        JsonSerializer.serialize_newtype_variant(name, variant_index, variant, value).map(Cell::new)
+    }
+
+    fn serialize_seq(
+        self,
+        len: Option<usize>,
+    ) -> Result<CellCompound, Error>
+    {
+        JsonSerializer.serialize_seq(len).map(CellCompound)
+    }
+
+    fn serialize_tuple(
+        self,
+        len: usize,
+    ) -> Result<CellCompound, Error>
+    {
+        JsonSerializer.serialize_tuple(len).map(CellCompound)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<CellCompound, Error>
+    {
+        JsonSerializer.serialize_tuple_struct(name, len).map(CellCompound)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<CellCompound, Error>
+    {
+        JsonSerializer.serialize_tuple_variant(name, variant_index, variant, len).map(CellCompound)
+    }
+
+    fn serialize_map(
+        self,
+        len: Option<usize>,
+    ) -> Result<CellCompound, Error>
+    {
+        JsonSerializer.serialize_map(len).map(CellCompound)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<CellCompound, Error>
+    {
+        JsonSerializer.serialize_struct(name, len).map(CellCompound)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<CellCompound, Error>
+    {
+        JsonSerializer.serialize_struct_variant(name, variant_index, variant, len).map(CellCompound)
+    }
+}
+
+/// Collects a nested sequence, struct, or map as compact JSON in a cell.
+
+struct CellCompound(JsonCompound);
+
+impl CellCompound {
+
+    fn element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.0.element(value)
+    }
+
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.0.field(key, value)
+    }
+
+    fn finish(
+        self
+    ) -> Cell
+    {
+        Cell::new(self.0.finish())
+    }
+
+}
+
+impl SerializeMap for CellCompound {
+    type Ok = Cell;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(
+        &mut self,
+        key: &T,
+    ) -> Result<(), Error>
+    {
+        self.0.serialize_key(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.0.serialize_value(value)
+    }
+
+    fn end(
+        self
+    ) -> Result<Cell, Error>
+    {
+        Ok(self.finish())
+    }
+}
+
+impl_elements!(CellCompound => Cell; SerializeSeq::serialize_element, SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field, SerializeTupleVariant::serialize_field);
+impl_fields!(CellCompound => Cell; SerializeStruct, SerializeStructVariant);
+
+/// Serializes a value as compact JSON text, as shown in cells holding nested values.
+
+struct JsonSerializer;
+
+/// Writes a string as a JSON string literal, with quotes and escapes.
+
+fn write_json_string(
+    output: &mut String,
+    text: &str,
+)
+{
+    output.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            '\u{8}' => output.push_str("\\b"),
+            '\u{c}' => output.push_str("\\f"),
+            c if c < ' ' => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
+/// Generates serializer methods writing integers and booleans as they are displayed.
+
+macro_rules! display_primitives {
+    ($($method:ident($type:ty)),* $(,)?) => {
+        $(
+            fn $method(
+                self,
+                value: $type,
+            ) -> Result<String, Error>
+            {
+                Ok(value.to_string())
+            }
+        )*
+    };
+}
+
+impl Serializer for JsonSerializer {
+    type Ok = String;
+    type Error = Error;
+    type SerializeSeq = JsonCompound;
+    type SerializeTuple = JsonCompound;
+    type SerializeTupleStruct = JsonCompound;
+    type SerializeTupleVariant = JsonCompound;
+    type SerializeMap = JsonCompound;
+    type SerializeStruct = JsonCompound;
+    type SerializeStructVariant = JsonCompound;
+
+    display_primitives!(
+        serialize_bool(bool), serialize_i8(i8), serialize_i16(i16), serialize_i32(i32), serialize_i64(i64),
+        serialize_i128(i128), serialize_u8(u8), serialize_u16(u16), serialize_u32(u32), serialize_u64(u64),
+        serialize_u128(u128),
+    );
+
+    fn serialize_f32(
+        self,
+        value: f32,
+    ) -> Result<String, Error>
+    {
+        // `Debug` keeps the fraction of whole numbers and uses exponents for very large or small numbers, like JSON writers
+        Ok(if value.is_finite() { format!("{:?}", value) } else { String::from("null") })
+    }
+
+    fn serialize_f64(
+        self,
+        value: f64,
+    ) -> Result<String, Error>
+    {
+        Ok(if value.is_finite() { format!("{:?}", value) } else { String::from("null") })
+    }
+
+    fn serialize_char(
+        self,
+        value: char,
+    ) -> Result<String, Error>
+    {
+        self.serialize_str(value.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(
+        self,
+        value: &str,
+    ) -> Result<String, Error>
+    {
+        let mut output = String::with_capacity(value.len() + 2);
+        write_json_string(&mut output, value);
+        Ok(output)
+    }
+
+    fn serialize_bytes(
+        self,
+        value: &[u8],
+    ) -> Result<String, Error>
+    {
+        let mut compound = JsonCompound::new("[", "]");
+        for byte in value {
+            compound.element(byte)?;
+        }
+        Ok(compound.finish())
+    }
+
+    fn serialize_none(
+        self
+    ) -> Result<String, Error>
+    {
+        Ok(String::from("null"))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<String, Error>
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(
+        self
+    ) -> Result<String, Error>
+    {
+        Ok(String::from("null"))
+    }
+
+    fn serialize_unit_struct(
+        self,
+        _name: &'static str,
+    ) -> Result<String, Error>
+    {
+        Ok(String::from("null"))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<String, Error>
+    {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<String, Error>
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<String, Error>
+    {
+        let mut compound = JsonCompound::new("{", "}");
+        compound.field(variant, value)?;
+        Ok(compound.finish())
+    }
+
+    fn serialize_seq(
+        self,
+        _len: Option<usize>,
+    ) -> Result<JsonCompound, Error>
+    {
+        Ok(JsonCompound::new("[", "]"))
+    }
+
+    fn serialize_tuple(
+        self,
+        len: usize,
+    ) -> Result<JsonCompound, Error>
+    {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<JsonCompound, Error>
+    {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<JsonCompound, Error>
+    {
+        Ok(JsonCompound::in_variant(variant, "[", "]"))
+    }
+
+    fn serialize_map(
+        self,
+        _len: Option<usize>,
+    ) -> Result<JsonCompound, Error>
+    {
+        Ok(JsonCompound::new("{", "}"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<JsonCompound, Error>
+    {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<JsonCompound, Error>
+    {
+        Ok(JsonCompound::in_variant(variant, "{", "}"))
+    }
+}
+
+/// Writes a JSON array or object, and the object holding it for enum variants.
+
+struct JsonCompound {
+    output: String,
+    first: bool,
+    close: &'static str,
+}
+
+impl JsonCompound {
+
+    /// Starts an array or object.
+
+    fn new(
+        open: &str,
+        close: &'static str,
+    ) -> Self
+    {
+        JsonCompound { output: String::from(open), first: true, close }
+    }
+
+    /// Starts an array or object as the value of an enum variant, `{"Variant":[...]}`.
+
+    fn in_variant(
+        variant: &str,
+        open: &str,
+        close: &'static str,
+    ) -> Self
+    {
+        let mut output = String::from("{");
+        write_json_string(&mut output, variant);
+        output.push(':');
+        output.push_str(open);
+        let close = if close == "]" { "]}" } else { "}}" };
+        JsonCompound { output, first: true, close }
+    }
+
+    /// Writes the separator before the next element or field.
+
+    fn separate(
+        &mut self
+    )
+    {
+        if !self.first {
+            self.output.push(',');
+        }
+        self.first = false;
+    }
+
+    fn element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.separate();
+        self.output.push_str(&value.serialize(JsonSerializer)?);
+        Ok(())
+    }
+
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.separate();
+        write_json_string(&mut self.output, key);
+        self.output.push(':');
+        self.output.push_str(&value.serialize(JsonSerializer)?);
+        Ok(())
+    }
+
+    fn finish(
+        mut self
+    ) -> String
+    {
+        self.output.push_str(self.close);
+        self.output
+    }
+
+}
+
+impl SerializeMap for JsonCompound {
+    type Ok = String;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(
+        &mut self,
+        key: &T,
+    ) -> Result<(), Error>
+    {
+        // JSON keys are strings, so other keys are written as their text
+        self.separate();
+        write_json_string(&mut self.output, key.serialize(CellSerializer)?.get_data());
+        self.output.push(':');
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Error>
+    {
+        self.output.push_str(&value.serialize(JsonSerializer)?);
+        Ok(())
+    }
+
+    fn end(
+        self
+    ) -> Result<String, Error>
+    {
+        Ok(self.finish())
+    }
+}
+
+impl_elements!(JsonCompound => String; SerializeSeq::serialize_element, SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field, SerializeTupleVariant::serialize_field);
+impl_fields!(JsonCompound => String; SerializeStruct, SerializeStructVariant);
+
+#[cfg(test)]
+mod tests {
+
+    use crate::Grid;
+
+    use serde::Serialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize)]
+    enum Status {
+        Active,
+        Paused { until: u32 },
+    }
+
+    #[derive(Serialize)]
+    struct Server {
+        name: &'static str,
+        load: f64,
+        tags: Vec<&'static str>,
+        owner: Option<&'static str>,
+        status: Status,
+    }
+
+    #[test]
+    fn lays_out_structs_as_rows() {
+        let servers = [
+            Server { name: "web", load: 0.5, tags: vec!["prod", "eu"], owner: Some("ops"), status: Status::Active },
+            Server { name: "db", load: 2.0, tags: vec![], owner: None, status: Status::Paused { until: 9 } },
+        ];
+        let grid = Grid::from_serialize(&servers).unwrap();
+        assert_eq!(grid.to_records(), [
+            ["name", "load", "tags", "owner", "status"],
+            ["web", "0.5", "[\"prod\",\"eu\"]", "ops", "Active"],
+            ["db", "2.0", "[]", "null", "{\"Paused\":{\"until\":9}}"],
+        ]);
+        assert!(grid.get_cell(2, 3).unwrap().is_null());
+    }
+
+    #[test]
+    fn unions_map_keys_and_puts_other_items_under_value() {
+        let first = BTreeMap::from([("a", 1)]);
+        let second = BTreeMap::from([("b", 2)]);
+        let grid = Grid::from_serialize(&(first, second, "text")).unwrap();
+        assert_eq!(grid.to_records(), [
+            ["a", "b", "value"],
+            ["1", "null", "null"],
+            ["null", "2", "null"],
+            ["null", "null", "text"],
+        ]);
+    }
+
+    #[test]
+    fn rejects_scalars() {
+        assert!(Grid::from_serialize(&42).is_err());
+    }
+
+}
@@ -0,0 +1,448 @@
+use crate::cell::Cell;
+use crate::col_type::parse_bool;
+use crate::error::ErrorMessage;
+use crate::grid::Grid;
+use crate::history::{Edit, inverse_order};
+use crate::locale::Locale;
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// How the cells of a column are compared when sorting rows by it.
+///
+/// Cells that cannot be parsed as the requested type, such as empty cells or `n/a`,
+/// are sorted after all the others, in lexicographic order.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortType {
+    /// Compares the text character by character, so `B` comes before `a` and `10` before `9`.
+    #[default]
+    Lexicographic,
+    /// Compares the text character by character, ignoring the case of letters.
+    CaseInsensitive,
+    /// Compares the cells as decimal numbers, such as `-12`, `3.5`, or `1e6`,
+    /// or numbers written in the locale of the grid (see `Grid::set_locale`).
+    Numeric,
+    /// Compares runs of digits by their value and everything else character by character,
+    /// so `file2` comes before `file10`.
+    Natural,
+    /// Compares the cells as ISO 8601 dates (`2024-03-01`), optionally followed by a time
+    /// (`2024-03-01 14:30` or `2024-03-01T14:30:59`). Slashes are accepted between date parts.
+    Date,
+    /// Compares the cells as booleans, with `false` before `true`, written as `true`/`false`,
+    /// `yes`/`no`, `y`/`n`, `on`/`off`, or `1`/`0` in any case.
+    Bool,
+}
+
+/// The direction of a sort.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    /// From the smallest to the largest value.
+    #[default]
+    Ascending,
+    /// From the largest to the smallest value.
+    Descending,
+}
+
+impl Order {
+
+    /// Applies the direction to an ascending comparison.
+
+    fn apply(
+        self,
+        ordering: Ordering,
+    ) -> Ordering
+    {
+        match self {
+            Order::Ascending  => ordering,
+            Order::Descending => ordering.reverse(),
+        }
+    }
+
+}
+
+impl Grid {
+
+    /// Sorts the rows by the cells of a column, compared as the given type.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// The sort is stable, so rows with equal cells keep their order, and all rows are sorted,
+    /// including a header row if the grid has one, except for the footer rows which stay last.
+
+    pub fn sort_by_col_typed(
+        &mut self,
+        col_index: usize,
+        sort_type: SortType,
+    )
+    {
+        self.sort_rows_by_keys(&[(col_index, sort_type, Order::Ascending)]);
+    }
+
+    /// Sorts the rows by several columns at once, each compared as its type and in its order.
+    ///
+    /// Panics if any column index is out of bounds.
+    ///
+    /// Rows are compared by the first key, then rows with equal cells for it by the second key,
+    /// and so on, e.g. by status, then by duration descending:
+    /// `grid.sort_rows_by_keys(&[(2, SortType::Lexicographic, Order::Ascending), (3, SortType::Numeric, Order::Descending)])`.
+    /// Cells that do not parse as their type come last in either order.
+    /// The sort is stable, so rows equal for all keys keep their order.
+    /// Footer rows are not sorted and stay last.
+
+    pub fn sort_rows_by_keys(
+        &mut self,
+        keys: &[(usize, SortType, Order)],
+    )
+    {
+        self.sort_rows_from_by_keys(0, keys);
+    }
+
+    /// Sorts the rows from the given row index up to the footer rows by several columns,
+    /// as in `sort_rows_by_keys`, leaving the rows above in place.
+
+    pub(crate) fn sort_rows_from_by_keys(
+        &mut self,
+        first_row: usize,
+        keys: &[(usize, SortType, Order)],
+    )
+    {
+        if keys.iter().any(|&(col_index, _, _)| col_index >= self.col_size()) {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        let columns: Vec<SortColumn> = keys.iter()
+            .map(|&(col_index, sort_type, order)| SortColumn::new(self, col_index, sort_type, order))
+            .collect();
+        let mut order: Vec<usize> = (0..self.row_size()).collect();
+        let footer_start = self.footer_start();
+        order[first_row.min(footer_start)..footer_start].sort_by(|&a, &b| {
+            columns.iter()
+                .map(|column| column.cmp_rows(a, b))
+                .find(|&ordering| ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+
+        self.reorder_rows(&order);
+    }
+
+    /// Moves the rows into the given order, where `order[i]` is the current index of the row to put at `i`.
+
+    pub(crate) fn reorder_rows(
+        &mut self,
+        order: &[usize],
+    )
+    {
+        // Leave the grid untouched, and its rows clean, if nothing moves
+        if order.iter().enumerate().all(|(row_index, &old_index)| row_index == old_index) {
+            return;
+        }
+
+        // Only the order is kept to undo this, rather than the cells
+        self.record_edit(|_| Edit::ReorderRows { order: inverse_order(order) });
+        self.move_rows(order);
+    }
+
+}
+
+/// The cells of a column used as a sort key, parsed once before sorting.
+
+struct SortColumn<'a> {
+    texts: Vec<&'a str>,
+    values: SortValues,
+    sort_type: SortType,
+    order: Order,
+}
+
+/// The values parsed from the cells of a sort key, for the types that need parsing.
+
+enum SortValues {
+    Text,
+    Numbers(Vec<Option<f64>>),
+    Dates(Vec<Option<[u32; 6]>>),
+    Bools(Vec<Option<bool>>),
+}
+
+impl<'a> SortColumn<'a> {
+
+    /// Reads and parses the cells of a column of the grid.
+
+    fn new(
+        grid: &'a Grid,
+        col_index: usize,
+        sort_type: SortType,
+        order: Order,
+    ) -> Self
+    {
+        let texts: Vec<&str> = grid.col_iter(col_index).map(Cell::get_data).collect();
+        let values = match sort_type {
+            SortType::Numeric => SortValues::Numbers(texts.iter().map(|text| parse_number(text, grid.locale())).collect()),
+            SortType::Date    => SortValues::Dates(texts.iter().map(|text| parse_date(text)).collect()),
+            SortType::Bool    => SortValues::Bools(texts.iter().map(|text| parse_bool(text.trim())).collect()),
+            _                 => SortValues::Text,
+        };
+        SortColumn { texts, values, sort_type, order }
+    }
+
+    /// Compares the cells of two rows in the column, in the order of the key.
+
+    fn cmp_rows(
+        &self,
+        a: usize,
+        b: usize,
+    ) -> Ordering
+    {
+        let (a_text, b_text) = (self.texts[a], self.texts[b]);
+        match &self.values {
+            SortValues::Numbers(numbers) => cmp_parsed(&numbers[a], &numbers[b], f64::total_cmp, a_text, b_text, self.order),
+            SortValues::Dates(dates) => cmp_parsed(&dates[a], &dates[b], Ord::cmp, a_text, b_text, self.order),
+            SortValues::Bools(bools) => cmp_parsed(&bools[a], &bools[b], Ord::cmp, a_text, b_text, self.order),
+            SortValues::Text => self.order.apply(match self.sort_type {
+                SortType::CaseInsensitive => cmp_case_insensitive(a_text, b_text),
+                SortType::Natural         => cmp_natural(a_text, b_text),
+                _                         => a_text.cmp(b_text),
+            }),
+        }
+    }
+
+}
+
+/// Compares two values parsed from cell text in the given order,
+/// with unparsed cells last and in lexicographic order in the given order.
+
+fn cmp_parsed<T>(
+    a: &Option<T>,
+    b: &Option<T>,
+    cmp: impl Fn(&T, &T) -> Ordering,
+    a_text: &str,
+    b_text: &str,
+    order: Order,
+) -> Ordering
+{
+    match (a, b) {
+        (Some(a), Some(b)) => order.apply(cmp(a, b)),
+        (Some(_), None)    => Ordering::Less,
+        (None, Some(_))    => Ordering::Greater,
+        (None, None)       => order.apply(a_text.cmp(b_text)),
+    }
+}
+
+/// Compares two texts character by character, ignoring the case of letters.
+
+fn cmp_case_insensitive(
+    a: &str,
+    b: &str,
+) -> Ordering
+{
+    a.chars().flat_map(char::to_lowercase)
+        .cmp(b.chars().flat_map(char::to_lowercase))
+}
+
+/// Compares two texts with runs of digits compared by their value.
+///
+/// Leading zeros do not count, so `file007` and `file7` are equal.
+
+fn cmp_natural(
+    a: &str,
+    b: &str,
+) -> Ordering
+{
+    // Digits are ASCII, and comparing the other UTF-8 bytes compares their characters
+    let (mut a, mut b) = (a.as_bytes(), b.as_bytes());
+    loop {
+        match (a.first(), b.first()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let (a_digits, a_rest) = split_digits(a);
+                let (b_digits, b_rest) = split_digits(b);
+                // Without leading zeros, a longer run of digits is a larger number
+                let ordering = a_digits.len().cmp(&b_digits.len())
+                    .then_with(|| a_digits.cmp(b_digits));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                (a, b) = (a_rest, b_rest);
+            },
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(y);
+                }
+                (a, b) = (&a[1..], &b[1..]);
+            },
+        }
+    }
+}
+
+/// Splits a leading run of digits, without its leading zeros, from the rest of the text.
+
+fn split_digits(
+    text: &[u8],
+) -> (&[u8], &[u8])
+{
+    let end = text.iter().position(|c| !c.is_ascii_digit()).unwrap_or(text.len());
+    let (digits, rest) = text.split_at(end);
+    let zeros = digits.iter().take_while(|&&c| c == b'0').count();
+    (&digits[zeros..], rest)
+}
+
+/// Parses a cell as a decimal number, ignoring surrounding whitespace, written either
+/// with the conventions of the locale, such as `1 234,5` with `Locale::FRENCH`, or like Rust does.
+
+pub(crate) fn parse_number(
+    text: &str,
+    locale: &Locale,
+) -> Option<f64>
+{
+    let plain = if locale.is_plain() { None } else { locale.unlocalize_number(text) };
+    let number: f64 = plain.as_deref().unwrap_or(text).trim().parse().ok()?;
+    // `NaN` and `inf` parse as numbers, but are not meant as ones in a table
+    number.is_finite().then_some(number)
+}
+
+/// Parses a cell as an ISO 8601 date with an optional time, into comparable parts.
+///
+/// Returns the year, month, day, hour, minute, and second, with missing time parts set to 0.
+
+pub(crate) fn parse_date(
+    text: &str,
+) -> Option<[u32; 6]>
+{
+    let text = text.trim();
+    let (date, time) = match text.find(['T', ' ']) {
+        Some(index) => (&text[..index], Some(text[index + 1..].trim_start())),
+        None => (text, None),
+    };
+
+    let mut date_parts = date.split(['-', '/']);
+    let year = parse_part(date_parts.next()?, 4..=4, 0..=9999)?;
+    let month = parse_part(date_parts.next()?, 1..=2, 1..=12)?;
+    let day = parse_part(date_parts.next()?, 1..=2, 1..=31)?;
+    if date_parts.next().is_some() {
+        return None;
+    }
+
+    let mut parsed = [year, month, day, 0, 0, 0];
+    if let Some(time) = time {
+        let mut time_parts = time.split(':');
+        let limits = [0..=23, 0..=59, 0..=60];
+        for (index, limit) in limits.into_iter().enumerate() {
+            match time_parts.next() {
+                Some(part) => parsed[3 + index] = parse_part(part, 2..=2, limit)?,
+                // Hours and minutes are required, seconds are optional
+                None if index == 2 => break,
+                None => return None,
+            }
+        }
+        if time_parts.next().is_some() {
+            return None;
+        }
+    }
+    Some(parsed)
+}
+
+/// Parses a part of a date or time, made of an allowed number of digits and within an allowed range.
+
+fn parse_part(
+    part: &str,
+    digits: core::ops::RangeInclusive<usize>,
+    range: core::ops::RangeInclusive<u32>,
+) -> Option<u32>
+{
+    if !digits.contains(&part.len()) || !part.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let value = part.parse().ok()?;
+    range.contains(&value).then_some(value)
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Grid, Order, SortType};
+
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    fn sorted(
+        cells: &[&str],
+        sort_type: SortType,
+    ) -> Vec<String>
+    {
+        let mut grid = Grid::from(cells.iter().map(|&cell| [cell]));
+        grid.sort_by_col_typed(0, sort_type);
+        grid.col_iter(0).map(|cell| cell.get_data().to_string()).collect()
+    }
+
+    #[test]
+    fn sorts_numbers_by_value() {
+        assert_eq!(sorted(&["10", "9", "-2.5", "1e3", "n/a", "0"], SortType::Numeric), ["-2.5", "0", "9", "10", "1e3", "n/a"]);
+        assert_eq!(sorted(&["10", "9"], SortType::Lexicographic), ["10", "9"]);
+    }
+
+    #[test]
+    fn sorts_runs_of_digits_naturally() {
+        assert_eq!(sorted(&["file10", "file2", "file1", "file007"], SortType::Natural), ["file1", "file2", "file007", "file10"]);
+    }
+
+    #[test]
+    fn sorts_ignoring_case() {
+        assert_eq!(sorted(&["beta", "Gamma", "alpha"], SortType::CaseInsensitive), ["alpha", "beta", "Gamma"]);
+        assert_eq!(sorted(&["beta", "Gamma", "alpha"], SortType::Lexicographic), ["Gamma", "alpha", "beta"]);
+    }
+
+    #[test]
+    fn sorts_dates_chronologically() {
+        let dates = ["2024-03-01 14:30", "2023/12/31", "2024-03-01T09:05:59", "yesterday", "2024-3-1"];
+        assert_eq!(sorted(&dates, SortType::Date), ["2023/12/31", "2024-3-1", "2024-03-01T09:05:59", "2024-03-01 14:30", "yesterday"]);
+    }
+
+    #[test]
+    fn footer_rows_stay_last() {
+        let mut grid = Grid::from([["3"], ["1"], ["2"], ["6"]]);
+        grid.set_footer_rows(1);
+        grid.sort_by_col_typed(0, SortType::Numeric);
+        let data: Vec<&str> = grid.col_iter(0).map(|cell| cell.get_data()).collect();
+        assert_eq!(data, ["1", "2", "3", "6"]);
+    }
+
+    #[test]
+    fn sorts_by_each_key_in_turn() {
+        let mut grid = Grid::from([
+            ["db", "OK", "12"],
+            ["api", "DOWN", "3"],
+            ["cache", "OK", "40"],
+            ["queue", "DOWN", "n/a"],
+            ["web", "OK", "12"],
+        ]);
+        grid.sort_rows_by_keys(&[(1, SortType::Lexicographic, Order::Ascending), (2, SortType::Numeric, Order::Descending)]);
+        assert_eq!(grid.to_records(), [
+            ["api", "DOWN", "3"],
+            // Unparsed cells come last in either order
+            ["queue", "DOWN", "n/a"],
+            ["cache", "OK", "40"],
+            // Rows equal for every key keep their order
+            ["db", "OK", "12"],
+            ["web", "OK", "12"],
+        ]);
+    }
+
+    #[test]
+    fn sorts_descending_above_the_footer() {
+        let mut grid = Grid::from([["3"], ["1"], ["2"], ["6"]]);
+        grid.set_footer_rows(1);
+        grid.sort_rows_by_keys(&[(0, SortType::Numeric, Order::Descending)]);
+        assert_eq!(grid.to_records(), [["3"], ["2"], ["1"], ["6"]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_keys_out_of_bounds() {
+        let mut grid = Grid::from([["a", "b"]]);
+        grid.sort_rows_by_keys(&[(0, SortType::Lexicographic, Order::Ascending), (2, SortType::Numeric, Order::Ascending)]);
+    }
+
+}
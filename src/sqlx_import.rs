@@ -0,0 +1,77 @@
+use crate::align::Align;
+use crate::cell::Cell;
+use crate::grid::Grid;
+
+use sqlx::any::{AnyRow, AnyTypeInfoKind};
+use sqlx::{Column, Row, ValueRef};
+
+impl Grid {
+
+    /// Create a new grid from the rows of a query result, such as the output of `fetch_all`.
+    ///
+    /// The first row is a header holding the column names, formatted in bold and centered.
+    /// `NULL` values are shown as a dimmed `null`, numbers are right-aligned,
+    /// and blobs are shown as hexadecimal, like `0x1f8b`, or as their length if longer than 16 bytes.
+    /// No rows result in an empty grid, since the column names are read from the rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a value cannot be decoded
+
+    pub fn from_sqlx_rows(
+        rows: &[AnyRow],
+    ) -> Result<Self, sqlx::Error>
+    {
+        let Some(first) = rows.first() else {
+            return Ok(Grid::default());
+        };
+
+        let header: Vec<Cell> = first.columns().iter().map(|column| Cell::new(column.name())).collect();
+        let mut grid_rows = vec![header];
+        for row in rows {
+            let mut cells = Vec::with_capacity(row.len());
+            for index in 0..row.len() {
+                cells.push(Grid::sqlx_cell(row, index)?);
+            }
+            grid_rows.push(cells);
+        }
+
+        let mut grid = Grid::from(grid_rows);
+        grid.style_header_row();
+        Ok(grid)
+    }
+
+    /// Decodes a value of a query result row into a cell.
+
+    fn sqlx_cell(
+        row: &AnyRow,
+        index: usize,
+    ) -> Result<Cell, sqlx::Error>
+    {
+        let value = row.try_get_raw(index)?;
+        if value.is_null() {
+            return Ok(Cell::null());
+        }
+
+        let kind = value.type_info().kind();
+        let mut cell = match kind {
+            AnyTypeInfoKind::Null => return Ok(Cell::null()),
+            AnyTypeInfoKind::Bool => Cell::from(row.try_get::<bool, _>(index)?),
+            AnyTypeInfoKind::SmallInt => Cell::from(row.try_get::<i16, _>(index)?),
+            AnyTypeInfoKind::Integer => Cell::from(row.try_get::<i32, _>(index)?),
+            AnyTypeInfoKind::BigInt => Cell::from(row.try_get::<i64, _>(index)?),
+            AnyTypeInfoKind::Real => Cell::from(row.try_get::<f32, _>(index)?),
+            AnyTypeInfoKind::Double => Cell::from(row.try_get::<f64, _>(index)?),
+            AnyTypeInfoKind::Text => Cell::from(row.try_get::<String, _>(index)?),
+            AnyTypeInfoKind::Blob => Cell::blob(&row.try_get::<Vec<u8>, _>(index)?),
+        };
+        let numeric = matches!(kind,
+            AnyTypeInfoKind::SmallInt | AnyTypeInfoKind::Integer | AnyTypeInfoKind::BigInt
+                | AnyTypeInfoKind::Real | AnyTypeInfoKind::Double);
+        if numeric {
+            cell.set_align(Align::Right);
+        }
+        Ok(cell)
+    }
+
+}
@@ -0,0 +1,146 @@
+use crate::error::ErrorMessage;
+use crate::grid::Grid;
+use crate::sort::parse_number;
+
+/// Statistics of the cells of a column, as reported by `Grid::col_stats`.
+///
+/// Widths are measured as when rendering, so they can guide width constraints,
+/// and the numeric statistics only consider the cells that parse as numbers.
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ColStats {
+    cell_count: usize,
+    empty_count: usize,
+    min_width: usize,
+    max_width: usize,
+    numeric_count: usize,
+    min: Option<f64>,
+    max: Option<f64>,
+    mean: Option<f64>,
+}
+
+impl ColStats {
+
+    /// Gets the number of cells in the column.
+
+    pub fn cell_count(
+        &self
+    ) -> usize
+    {
+        self.cell_count
+    }
+
+    /// Gets the number of cells without any data.
+
+    pub fn empty_count(
+        &self
+    ) -> usize
+    {
+        self.empty_count
+    }
+
+    /// Gets the width of the narrowest cell, or 0 if the column has no cells.
+
+    pub fn min_width(
+        &self
+    ) -> usize
+    {
+        self.min_width
+    }
+
+    /// Gets the width of the widest cell, which is the width of the column when rendered.
+
+    pub fn max_width(
+        &self
+    ) -> usize
+    {
+        self.max_width
+    }
+
+    /// Gets the number of cells that parse as numbers.
+
+    pub fn numeric_count(
+        &self
+    ) -> usize
+    {
+        self.numeric_count
+    }
+
+    /// Gets the smallest number in the column, or None if no cell parses as a number.
+
+    pub fn min(
+        &self
+    ) -> Option<f64>
+    {
+        self.min
+    }
+
+    /// Gets the largest number in the column, or None if no cell parses as a number.
+
+    pub fn max(
+        &self
+    ) -> Option<f64>
+    {
+        self.max
+    }
+
+    /// Gets the mean of the numbers in the column, or None if no cell parses as a number.
+
+    pub fn mean(
+        &self
+    ) -> Option<f64>
+    {
+        self.mean
+    }
+
+}
+
+impl Grid {
+
+    /// Computes statistics of the cells of a column, for layout decisions and quick data profiling.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// Every row is included, such as a header row, which usually does not parse as a number.
+    /// Numbers are read in the locale of the grid (see `set_locale`).
+
+    pub fn col_stats(
+        &self,
+        col_index: usize,
+    ) -> ColStats
+    {
+        if col_index >= self.col_size() {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        let mut stats = ColStats {
+            min_width: usize::MAX,
+            ..ColStats::default()
+        };
+        let mut sum = 0.0;
+        for cell in self.col_iter(col_index) {
+            stats.cell_count += 1;
+            if cell.get_data().is_empty() {
+                stats.empty_count += 1;
+            }
+            let width = cell.width();
+            stats.min_width = stats.min_width.min(width);
+            stats.max_width = stats.max_width.max(width);
+            if let Some(number) = parse_number(cell.get_data(), self.locale()) {
+                stats.numeric_count += 1;
+                stats.min = Some(stats.min.map_or(number, |min| min.min(number)));
+                stats.max = Some(stats.max.map_or(number, |max| max.max(number)));
+                sum += number;
+            }
+        }
+
+        if stats.cell_count == 0 {
+            stats.min_width = 0;
+        }
+        if stats.numeric_count > 0 {
+            stats.mean = Some(sum / stats.numeric_count as f64);
+        }
+        stats
+    }
+
+}
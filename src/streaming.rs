@@ -0,0 +1,309 @@
+use crate::cell::Cell;
+use crate::format::AnsiPrefixes;
+use crate::grid::Grid;
+use crate::options::RenderOptions;
+use crate::render::{Layout, write_segment};
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Error, Formatter};
+
+/// Grid keeping only the most recent rows of an unbounded stream, for tail-style displays.
+///
+/// Rows are appended with `push_row`. Once `max_rows` rows are held, every new row overwrites
+/// the oldest one in place, so memory stays bounded however long the stream runs.
+/// Row indices are relative to the oldest row still held.
+///
+/// Column widths are tracked as rows arrive and never shrink, even when the widest rows
+/// are dropped, so the table keeps a stable shape from one render to the next.
+
+#[derive(Debug, Clone)]
+pub struct StreamingGrid {
+    grid: Grid,
+    max_rows: usize,
+    oldest: usize,
+    total_rows: usize,
+    col_widths: Vec<usize>,
+    shifted: bool,
+}
+
+impl StreamingGrid {
+
+    /// Creates a streaming grid holding at most `max_rows` rows of `col_size` columns.
+    ///
+    /// At least one row is always kept, even if `max_rows` is 0.
+
+    pub fn new(
+        max_rows: usize,
+        col_size: usize,
+    ) -> Self
+    {
+        let max_rows = max_rows.max(1);
+        StreamingGrid {
+            grid: Grid::with_capacity(max_rows, col_size),
+            max_rows,
+            oldest: 0,
+            total_rows: 0,
+            col_widths: vec![0; col_size],
+            shifted: false,
+        }
+    }
+
+    /// Appends a new row, dropping the oldest row if the grid is full.
+    ///
+    /// If the new row has fewer cells than the number of columns in the grid,
+    /// the remaining cells will be filled with the default cell.
+    ///
+    /// If the new row has more cells than the number of columns in the grid,
+    /// the excess cells will be truncated.
+
+    pub fn push_row(
+        &mut self,
+        new_row: impl IntoIterator<Item = impl Into<Cell>>,
+    )
+    {
+        let row_index = if self.grid.row_size() < self.max_rows {
+            self.grid.push_row(new_row);
+            self.grid.row_size() - 1
+        } else {
+            let row_index = self.oldest;
+            self.grid.set_row(row_index, new_row);
+            self.oldest = (row_index + 1) % self.max_rows;
+            // Dropping the oldest row moves every other row up by one
+            self.shifted = true;
+            row_index
+        };
+        self.total_rows += 1;
+
+        for (col_width, cell) in self.col_widths.iter_mut().zip(self.grid.row_iter(row_index)) {
+            *col_width = (*col_width).max(cell.width());
+        }
+    }
+
+    /// Removes all rows, keeping the column widths.
+
+    pub fn clear(
+        &mut self
+    )
+    {
+        self.grid.resize(0, self.col_widths.len());
+        self.oldest = 0;
+    }
+
+    /// Takes the indices of the rows changed since the last call, counted from the oldest row,
+    /// in ascending order, like `Grid::take_dirty_rows`.
+    ///
+    /// Until the grid is full, only the pushed rows are reported. Once it is full, every push
+    /// drops the oldest row and moves the others up by one, so every row is reported.
+
+    pub fn take_dirty_rows(
+        &mut self
+    ) -> Vec<usize>
+    {
+        let storage_rows = self.grid.take_dirty_rows();
+        if core::mem::take(&mut self.shifted) {
+            return (0..self.row_size()).collect();
+        }
+        let mut row_indices: Vec<usize> = storage_rows.into_iter()
+            .map(|storage_row| (storage_row + self.max_rows - self.oldest) % self.max_rows)
+            .collect();
+        row_indices.sort_unstable();
+        row_indices
+    }
+
+    /// Get the number of rows currently held.
+
+    pub fn row_size(
+        &self
+    ) -> usize
+    {
+        self.grid.row_size()
+    }
+
+    /// Get the number of columns.
+
+    pub fn col_size(
+        &self
+    ) -> usize
+    {
+        self.grid.col_size()
+    }
+
+    /// Get the maximum number of rows held at once.
+
+    pub fn max_rows(
+        &self
+    ) -> usize
+    {
+        self.max_rows
+    }
+
+    /// Get the number of rows pushed since the grid was created, including the dropped ones.
+
+    pub fn total_rows(
+        &self
+    ) -> usize
+    {
+        self.total_rows
+    }
+
+    /// Get the number of rows that were pushed but are no longer held.
+
+    pub fn dropped_rows(
+        &self
+    ) -> usize
+    {
+        self.total_rows - self.row_size()
+    }
+
+    /// Get the width of every column: the widest cell ever pushed into it.
+
+    pub fn col_widths(
+        &self
+    ) -> &[usize]
+    {
+        &self.col_widths
+    }
+
+    /// Get an immutable reference to the cell at the specified row and column indices.
+    ///
+    /// Returns None if the indices are out of bounds.
+
+    pub fn get_cell(
+        &self,
+        row_index: usize,
+        col_index: usize,
+    ) -> Option<&Cell>
+    {
+        if row_index >= self.row_size() || col_index >= self.col_size() {
+            return None;
+        }
+        self.grid.get_cell(self.storage_row(row_index), col_index)
+    }
+
+    /// Get an immutable iterator over the cells in the specified row.
+    ///
+    /// If the row index is out of bounds, returns an empty iterator.
+
+    pub fn row_iter(
+        &self,
+        row_index: usize,
+    ) -> impl DoubleEndedIterator<Item = &Cell> + ExactSizeIterator
+    {
+        let row_index = if row_index < self.row_size() {
+            self.storage_row(row_index)
+        } else {
+            // This will return an empty iterator
+            self.row_size()
+        };
+        self.grid.row_iter(row_index)
+    }
+
+    /// Copies the rows currently held into a grid, from the oldest to the newest.
+
+    pub fn to_grid(
+        &self
+    ) -> Grid
+    {
+        let mut grid = Grid::with_capacity(self.row_size(), self.col_size());
+        for row_index in 0..self.row_size() {
+            grid.push_row(self.row_iter(row_index).cloned());
+        }
+        grid
+    }
+
+    /// Renders the rows currently held using the given render options.
+    ///
+    /// The rows are rendered straight from the ring buffer, from the oldest to the newest,
+    /// with the tracked column widths. The `Display` implementation is a shortcut for
+    /// rendering with the default options.
+
+    pub fn render_with(
+        &self,
+        options: &RenderOptions,
+    ) -> String
+    {
+        let mut output = String::new();
+        // Writing into a string never fails
+        let _ = self.write_to(&mut output, options);
+        output
+    }
+
+    /// Renders the rows currently held into a formatter or string.
+
+    fn write_to(
+        &self,
+        output: &mut impl core::fmt::Write,
+        options: &RenderOptions,
+    ) -> core::fmt::Result
+    {
+        let layout = self.layout().constrain(options);
+        let mut prefixes = AnsiPrefixes::new();
+        self.grid.write_segments(&layout, options, |segment| write_segment(output, segment, &mut prefixes))
+    }
+
+    /// Computes the layout of the rows currently held, from the oldest to the newest,
+    /// with the tracked column widths.
+
+    fn layout(
+        &self
+    ) -> Layout
+    {
+        let row_indices = (0..self.row_size()).map(|row_index| self.storage_row(row_index)).collect();
+        Layout {
+            col_widths: self.col_widths.clone(),
+            ..self.grid.layout_selected_rows(row_indices, 0..self.col_size())
+        }
+    }
+
+    /// Get the index in storage of the row at the specified index, counted from the oldest row.
+
+    fn storage_row(
+        &self,
+        row_index: usize,
+    ) -> usize
+    {
+        (self.oldest + row_index) % self.max_rows
+    }
+
+}
+
+impl Display for StreamingGrid {
+
+    fn fmt(
+        &self,
+        f: &mut Formatter,
+    ) -> Result<(), Error>
+    {
+        self.write_to(f, &RenderOptions::default())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::StreamingGrid;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn dirty_rows_are_logical_indices() {
+        let mut grid = StreamingGrid::new(3, 1);
+        grid.push_row(["a"]);
+        grid.push_row(["b"]);
+        assert_eq!(grid.take_dirty_rows(), vec![0, 1]);
+
+        grid.push_row(["c"]);
+        assert_eq!(grid.take_dirty_rows(), vec![2]);
+        assert_eq!(grid.take_dirty_rows(), Vec::<usize>::new());
+
+        // The ring wraps: "a" is dropped and every row moves up
+        grid.push_row(["d"]);
+        assert_eq!(grid.take_dirty_rows(), vec![0, 1, 2]);
+        assert_eq!(grid.get_cell(2, 0).unwrap().get_data(), "d");
+    }
+
+}
@@ -0,0 +1,252 @@
+use crate::cell::Cell;
+use crate::error::ErrorMessage;
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+use crate::locale::Locale;
+use crate::sort::parse_number;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+/// An aggregate computed over the numeric cells of a column, as used by `Grid::add_summary_row`.
+///
+/// Numbers are read and results written in the locale of the grid (see `Grid::set_locale`).
+/// Cells that cannot be parsed as numbers, such as a header, empty cells, or `n/a`, are skipped.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// The sum of the numbers, or `0` if there are none.
+    Sum,
+    /// The mean of the numbers, with at least two decimals, or empty if there are none.
+    Average,
+    /// The smallest number, or empty if there are none.
+    Min,
+    /// The largest number, or empty if there are none.
+    Max,
+    /// How many cells hold a number.
+    Count,
+}
+
+impl Aggregate {
+
+    /// Computes the aggregate of the given cell texts, formatted for a cell.
+    ///
+    /// Numbers are read and results written in the given locale. Results have as many decimals
+    /// as the most precise number, so `1.50` and `2` sum to `3.50`.
+
+    pub(crate) fn compute<'a>(
+        self,
+        texts: impl IntoIterator<Item = &'a str>,
+        locale: &Locale,
+    ) -> String
+    {
+        let mut numbers = Vec::new();
+        let mut decimals = 0;
+        for text in texts {
+            if let Some(number) = parse_number(text, locale) {
+                numbers.push(number);
+                decimals = decimals.max(count_decimals(text, locale));
+            }
+        }
+
+        // Summing an empty iterator of floats gives -0
+        let sum = numbers.iter().fold(0.0, |sum, number| sum + number);
+        let value = match self {
+            Aggregate::Count => return format!("{}", numbers.len()),
+            Aggregate::Sum => Some(sum),
+            Aggregate::Average => {
+                decimals = decimals.max(2);
+                (!numbers.is_empty()).then(|| sum / numbers.len() as f64)
+            },
+            Aggregate::Min => numbers.iter().copied().reduce(f64::min),
+            Aggregate::Max => numbers.iter().copied().reduce(f64::max),
+        };
+        value.map(|value| {
+            let plain = format!("{:.*}", decimals, value);
+            locale.format_number(&plain).unwrap_or(plain)
+        }).unwrap_or_default()
+    }
+
+}
+
+impl Grid {
+
+    /// Appends a footer row holding aggregates of the numeric cells in the chosen columns,
+    /// e.g. the total and the average of a column of amounts.
+    ///
+    /// Panics if any column index is out of bounds.
+    ///
+    /// Every row above the footer is aggregated. The new row is formatted in bold and added to the footer,
+    /// so it is drawn below a heavier border. Columns without an aggregate are left empty,
+    /// and a column listed more than once keeps its last aggregate.
+
+    pub fn add_summary_row(
+        &mut self,
+        aggregates: &[(usize, Aggregate)],
+    )
+    {
+        if aggregates.iter().any(|&(col_index, _)| col_index >= self.col_size()) {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        let body_rows = self.footer_start();
+        let mut row = vec![Cell::default(); self.col_size()];
+        for &(col_index, aggregate) in aggregates {
+            let texts = self.col_iter(col_index).take(body_rows).map(Cell::get_data);
+            row[col_index] = Cell::new(aggregate.compute(texts, self.locale()));
+        }
+        for cell in &mut row {
+            cell.set_style(FontStyle::Bold);
+        }
+
+        let footer_rows = self.footer_rows();
+        self.record_batch(|grid| {
+            grid.push_row(row);
+            grid.set_footer_rows(footer_rows + 1);
+        });
+    }
+
+    /// Appends a footer row labelled `Total` in the first column, holding the sum of every numeric column,
+    /// e.g. `println!("{}", grid.with_totals())`.
+    ///
+    /// A column is numeric if it has a number below its first row, and all of its non-empty cells
+    /// below the first row are numbers, so a header row does not prevent detection.
+    /// The first column is always used for the label. The row is added like `add_summary_row` adds it.
+
+    pub fn with_totals(
+        mut self
+    ) -> Grid
+    {
+        if self.col_size() == 0 {
+            return self;
+        }
+
+        let body_rows = self.footer_start();
+        let aggregates: Vec<(usize, Aggregate)> = (1..self.col_size())
+            .filter(|&col_index| {
+                let mut texts = self.col_iter(col_index)
+                    .take(body_rows)
+                    .skip(1)
+                    .map(Cell::get_data)
+                    .filter(|text| !text.trim().is_empty())
+                    .peekable();
+                texts.peek().is_some() && texts.all(|text| parse_number(text, self.locale()).is_some())
+            })
+            .map(|col_index| (col_index, Aggregate::Sum))
+            .collect();
+        self.add_summary_row(&aggregates);
+
+        let row_index = self.row_size() - 1;
+        if let Some(cell) = self.get_cell_mut(row_index, 0) {
+            cell.set_data("Total");
+        }
+        self
+    }
+
+    /// Groups the rows by the data of a column, under a header row for each group.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// This is a shortcut for `group_by_with` without aggregates.
+
+    pub fn group_by(
+        &mut self,
+        col_index: usize,
+    )
+    {
+        self.group_by_with(col_index, &[]);
+    }
+
+    /// Groups the rows by the data of a column, under a header row for each group
+    /// holding the aggregates of the group in the chosen columns, e.g. a subtotal per customer.
+    ///
+    /// Panics if any column index is out of bounds.
+    ///
+    /// Groups are kept in the order in which their first row appears, and rows keep their order
+    /// within their group. Each group header is formatted in bold and holds the grouped cell
+    /// and the aggregates, while the grouped cell of every row in the group is emptied.
+    /// All rows are grouped, including a header row if the grid has one,
+    /// except for the footer rows which stay last. Add summary rows before grouping,
+    /// since they aggregate every row above the footer, group headers included.
+
+    pub fn group_by_with(
+        &mut self,
+        col_index: usize,
+        aggregates: &[(usize, Aggregate)],
+    )
+    {
+        if col_index >= self.col_size() || aggregates.iter().any(|&(col_index, _)| col_index >= self.col_size()) {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        self.record_replace(|grid| grid.group_rows(col_index, aggregates));
+    }
+
+    /// Groups the rows as `group_by_with` does, with the indices already checked.
+
+    fn group_rows(
+        &mut self,
+        col_index: usize,
+        aggregates: &[(usize, Aggregate)],
+    )
+    {
+        let col_size = self.col_size();
+        let locale = *self.locale();
+        let mut cells: Vec<Cell> = self.flat_iter_mut().map(core::mem::take).collect();
+        let (body, footer) = cells.split_at_mut(self.footer_start() * col_size);
+
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut group_indices: BTreeMap<&str, usize> = BTreeMap::new();
+        for (row_index, row) in body.chunks(col_size).enumerate() {
+            let group_index = *group_indices.entry(row[col_index].get_data()).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group_index].push(row_index);
+        }
+
+        let mut grouped = Vec::with_capacity(body.len() + footer.len() + groups.len() * col_size);
+        for rows in &groups {
+            let cell = |row_index: usize, col_index: usize| &body[row_index * col_size + col_index];
+            let mut header = vec![Cell::default(); col_size];
+            header[col_index] = cell(rows[0], col_index).clone();
+            for &(col_index, aggregate) in aggregates {
+                let texts = rows.iter().map(|&row_index| cell(row_index, col_index).get_data());
+                header[col_index] = Cell::new(aggregate.compute(texts, &locale));
+            }
+            for cell in &mut header {
+                cell.set_style(FontStyle::Bold);
+            }
+            grouped.extend(header);
+
+            for &row_index in rows {
+                let row = &mut body[row_index * col_size..(row_index + 1) * col_size];
+                row[col_index].set_data("");
+                grouped.extend(row.iter_mut().map(core::mem::take));
+            }
+        }
+        grouped.extend(footer.iter_mut().map(core::mem::take));
+
+        self.resize(grouped.len() / col_size, col_size);
+        self.set_cells_vec(grouped);
+    }
+
+}
+
+/// Counts the decimals of a number as written in a cell, in the locale or like Rust does,
+/// or 0 if it uses an exponent.
+
+fn count_decimals(
+    text: &str,
+    locale: &Locale,
+) -> usize
+{
+    let plain = locale.unlocalize_number(text);
+    let text = plain.as_deref().unwrap_or(text).trim();
+    if text.contains(['e', 'E']) {
+        return 0;
+    }
+    text.split_once('.').map_or(0, |(_, fraction)| fraction.len())
+}
@@ -0,0 +1,404 @@
+use crate::cell::Cell;
+use crate::error::ErrorMessage;
+use crate::export::Exporter;
+use crate::grid::Grid;
+use crate::options::RenderOptions;
+use crate::reorder::complete_col_order;
+use crate::sort::{Order, SortType};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Error, Formatter};
+
+/// A table made of a header row, a grid of data rows, and a grid of footer rows.
+///
+/// Keeping the header apart from the data means operations on the rows, such as sorting
+/// and filtering, never move the header, and nothing has to treat the first row as special.
+/// The table is laid out as a single grid with `to_grid` for rendering and exports,
+/// with the header in bold and centered and the footer below a heavier border.
+///
+/// The header defines the columns: data and footer rows are padded with empty cells
+/// or truncated to its length when laid out.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    header: Vec<Cell>,
+    data: Grid,
+    footer: Grid,
+}
+
+impl Table {
+
+    /// Creates a table with the given header cells and no data or footer rows.
+
+    pub fn new(
+        header: impl IntoIterator<Item = impl Into<Cell>>,
+    ) -> Self
+    {
+        let header: Vec<Cell> = header.into_iter().map(Into::into).collect();
+        let col_size = header.len();
+        Table {
+            header,
+            data: Grid::with_capacity(0, col_size),
+            footer: Grid::with_capacity(0, col_size),
+        }
+    }
+
+    /// Creates a table from a grid whose first row is the header.
+    ///
+    /// The footer rows of the grid become the footer of the table.
+    /// A grid without rows gives a table with empty header cells.
+
+    pub fn from_grid(
+        mut grid: Grid,
+    ) -> Self
+    {
+        let (row_size, col_size) = (grid.row_size(), grid.col_size());
+        let footer_start = grid.footer_start().max(1);
+        let mut cells = grid.flat_iter_mut().map(core::mem::take);
+
+        let mut table = Table::new(cells.by_ref().chain(core::iter::repeat_with(Cell::default)).take(col_size));
+        table.data.reserve_rows(footer_start - 1);
+        for row_index in 1..row_size {
+            let row = cells.by_ref().take(col_size);
+            if row_index < footer_start {
+                table.data.push_row(row);
+            } else {
+                table.footer.push_row(row);
+            }
+        }
+        table
+    }
+
+    /// Gets the number of columns, as defined by the header.
+
+    pub fn col_size(
+        &self
+    ) -> usize
+    {
+        self.header.len()
+    }
+
+    /// Gets the header cells.
+
+    pub fn header(
+        &self
+    ) -> &[Cell]
+    {
+        &self.header
+    }
+
+    /// Gets mutable references to the header cells.
+
+    pub fn header_mut(
+        &mut self
+    ) -> &mut [Cell]
+    {
+        &mut self.header
+    }
+
+    /// Finds the index of the column whose header cell holds the given name.
+    ///
+    /// Names are compared exactly, and the first matching column is returned.
+    /// Returns None if no header cell holds the name.
+
+    pub fn col_index(
+        &self,
+        name: &str,
+    ) -> Option<usize>
+    {
+        self.header.iter().position(|cell| cell.get_data() == name)
+    }
+
+    /// Get an immutable reference to the cell at the specified data row in the column with the given header name.
+    ///
+    /// Returns None if the row index is out of bounds or no column has the name.
+
+    pub fn get_cell_by_name(
+        &self,
+        row_index: usize,
+        name: &str,
+    ) -> Option<&Cell>
+    {
+        self.data.get_cell(row_index, self.col_index(name)?)
+    }
+
+    /// Get an immutable iterator over the data cells in the column with the given header name.
+    ///
+    /// If no column has the name, returns an empty iterator.
+
+    pub fn col_iter_by_name(
+        &self,
+        name: &str,
+    ) -> impl DoubleEndedIterator<Item = &Cell> + ExactSizeIterator
+    {
+        let col_index = self.col_index(name).unwrap_or(self.data.col_size());
+        self.data.col_iter(col_index)
+    }
+
+    /// Gets the data rows.
+
+    pub fn data(
+        &self
+    ) -> &Grid
+    {
+        &self.data
+    }
+
+    /// Gets the data rows mutably, e.g. to edit, insert, or sort them with the methods of `Grid`.
+
+    pub fn data_mut(
+        &mut self
+    ) -> &mut Grid
+    {
+        &mut self.data
+    }
+
+    /// Gets the footer rows.
+
+    pub fn footer(
+        &self
+    ) -> &Grid
+    {
+        &self.footer
+    }
+
+    /// Gets the footer rows mutably.
+
+    pub fn footer_mut(
+        &mut self
+    ) -> &mut Grid
+    {
+        &mut self.footer
+    }
+
+    /// Appends a data row below the last one.
+    ///
+    /// Missing cells are filled with empty cells, and cells beyond the last column are dropped.
+
+    pub fn push_row(
+        &mut self,
+        new_row: impl IntoIterator<Item = impl Into<Cell>>,
+    )
+    {
+        self.data.push_row(new_row);
+    }
+
+    /// Appends a footer row below the last one, such as totals.
+
+    pub fn push_footer_row(
+        &mut self,
+        new_row: impl IntoIterator<Item = impl Into<Cell>>,
+    )
+    {
+        self.footer.push_row(new_row);
+    }
+
+    /// Sorts the data rows by the cells of a column, compared as the given type.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// See `Grid::sort_by_col_typed`; the header and footer are never sorted.
+
+    pub fn sort_by_col_typed(
+        &mut self,
+        col_index: usize,
+        sort_type: SortType,
+    )
+    {
+        self.data.sort_by_col_typed(col_index, sort_type);
+    }
+
+    /// Sorts the data rows by several columns at once.
+    ///
+    /// Panics if any column index is out of bounds.
+    ///
+    /// See `Grid::sort_rows_by_keys`; the header and footer are never sorted.
+
+    pub fn sort_rows_by_keys(
+        &mut self,
+        keys: &[(usize, SortType, Order)],
+    )
+    {
+        self.data.sort_rows_by_keys(keys);
+    }
+
+    /// Moves the columns into a new order, in the header, data, and footer alike.
+    ///
+    /// Panics if any column index is out of bounds of the header, the data, or the footer,
+    /// which can differ after columns are inserted through `data_mut` or `footer_mut`.
+    /// Nothing is moved in that case.
+    ///
+    /// See `Grid::reorder_cols` for how the columns that are not listed are placed.
+
+    pub fn reorder_cols(
+        &mut self,
+        order: &[usize],
+    )
+    {
+        let col_size = self.col_size().min(self.data.col_size()).min(self.footer.col_size());
+        if order.iter().any(|&col_index| col_index >= col_size) {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        let mut header = core::mem::take(&mut self.header);
+        self.header = complete_col_order(order, header.len()).into_iter()
+            .map(|old_index| core::mem::take(&mut header[old_index]))
+            .collect();
+        self.data.reorder_cols(order);
+        self.footer.reorder_cols(order);
+    }
+
+    /// Moves the columns into a new order given by their header names.
+    ///
+    /// Panics if any name is not found in the header.
+
+    pub fn reorder_cols_by_name(
+        &mut self,
+        names: &[&str],
+    )
+    {
+        let order: Vec<usize> = names.iter()
+            .map(|name| self.col_index(name).unwrap_or_else(|| panic!("{}", ErrorMessage::COL_NAME_NOT_FOUND)))
+            .collect();
+        self.reorder_cols(&order);
+    }
+
+    /// Creates a table with the same header and footer, and the data rows for which the predicate returns true.
+
+    pub fn filtered(
+        &self,
+        predicate: impl FnMut(&[Cell]) -> bool,
+    ) -> Table
+    {
+        Table {
+            header: self.header.clone(),
+            data: self.data.filtered(predicate).to_grid(),
+            footer: self.footer.clone(),
+        }
+    }
+
+    /// Lays out the table as a single grid: the header row in bold and centered,
+    /// the data rows, and the footer rows, marked as the footer of the grid.
+    ///
+    /// The grid keeps the column types, merged columns, and locale of the data rows,
+    /// so it sorts and renders their columns the same way.
+
+    pub fn to_grid(
+        &self
+    ) -> Grid
+    {
+        let row_size = 1 + self.data.row_size() + self.footer.row_size();
+        let mut grid = Grid::with_capacity(row_size, self.col_size());
+        grid.set_locale(*self.data.locale());
+        // The types are already applied to the data cells, and must not change the header
+        grid.col_types_mut().extend(self.data.col_types().range(..self.col_size()));
+        for &col_index in self.data.merged_cols().range(..self.col_size()) {
+            grid.set_merged_col(col_index, true);
+        }
+        grid.push_row(self.header.iter().cloned());
+        grid.style_header_row();
+        for row_index in 0..self.data.row_size() {
+            grid.push_row(self.data.row_iter(row_index).cloned());
+        }
+        for row_index in 0..self.footer.row_size() {
+            grid.push_row(self.footer.row_iter(row_index).cloned());
+        }
+        grid.set_footer_rows(self.footer.row_size());
+        grid
+    }
+
+    /// Exports the table using the given exporter, with the header as the first row.
+
+    pub fn export<E: Exporter + ?Sized>(
+        &self,
+        exporter: &E,
+    ) -> E::Output
+    {
+        exporter.export(&self.to_grid())
+    }
+
+    /// Renders the table using the given render options.
+    ///
+    /// The `Display` implementation is a shortcut for rendering with the default options.
+
+    pub fn render_with(
+        &self,
+        options: &RenderOptions,
+    ) -> String
+    {
+        self.to_grid().render_with(options)
+    }
+
+}
+
+impl From<Grid> for Table {
+
+    fn from(
+        grid: Grid,
+    ) -> Self
+    {
+        Table::from_grid(grid)
+    }
+
+}
+
+impl Display for Table {
+
+    fn fmt(
+        &self,
+        f: &mut Formatter,
+    ) -> Result<(), Error>
+    {
+        Display::fmt(&self.to_grid(), f)
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    extern crate std;
+
+    use crate::{ColType, Grid, Table};
+
+    use alloc::vec::Vec;
+
+    #[test]
+    #[should_panic]
+    fn reorder_cols_checks_the_data_columns() {
+        let mut table = Table::new(["a", "b", "c"]);
+        table.push_row(["1", "2", "3"]);
+        table.data_mut().remove_col(2);
+        table.reorder_cols(&[2]);
+    }
+
+    #[test]
+    fn reorder_cols_moves_nothing_when_out_of_bounds() {
+        let mut table = Table::new(["a", "b", "c"]);
+        table.push_row(["1", "2", "3"]);
+        table.data_mut().remove_col(2);
+        let before = table.clone();
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| table.reorder_cols(&[2])));
+        assert!(result.is_err());
+        assert_eq!(table, before);
+    }
+
+    #[test]
+    fn to_grid_keeps_column_types_and_merged_columns() {
+        let mut table = Table::from_grid(Grid::from([["name", "qty"], ["apple", "10"], ["apple", "9"]]));
+        table.data_mut().set_col_type(1, ColType::Numeric);
+        table.data_mut().merge_duplicates(0);
+
+        let mut grid = table.to_grid();
+        assert_eq!(grid.col_type(1), ColType::Numeric);
+        assert!(grid.merged_cols().contains(&0));
+
+        // Sorted as numbers, with the header, which is not one, last
+        grid.sort_by_col(1);
+        let qty: Vec<&str> = grid.col_iter(1).map(|cell| cell.get_data()).collect();
+        assert_eq!(qty, ["9", "10", "qty"]);
+    }
+
+}
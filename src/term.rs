@@ -0,0 +1,43 @@
+use crate::options::RenderOptions;
+
+/// Gets the size of the terminal attached to stdout, as `(width, height)` in characters.
+///
+/// Falls back to stderr and then stdin, so the size is still detected when stdout
+/// is redirected, e.g. in `cargo run | less`. The fallbacks are only available on unix and windows.
+///
+/// # Returns
+///
+/// The terminal size, or `None` if none of the streams is a terminal,
+/// which is always the case on other targets such as `wasm32-unknown-unknown`
+
+pub fn terminal_size() -> Option<(usize, usize)> {
+    let size = terminal_size::terminal_size();
+    #[cfg(any(unix, windows))]
+    let size = size
+        .or_else(|| terminal_size::terminal_size_of(std::io::stderr()))
+        .or_else(|| terminal_size::terminal_size_of(std::io::stdin()));
+    let (terminal_size::Width(width), terminal_size::Height(height)) = size?;
+    Some((usize::from(width), usize::from(height)))
+}
+
+impl RenderOptions {
+
+    /// Limits the width of the table to the width of the terminal, if one is detected.
+    ///
+    /// This is a shortcut for `RenderOptions::set_max_width` with the width returned by
+    /// `terminal_size`. If no terminal is detected, the options are left unchanged.
+    ///
+    /// # Returns
+    ///
+    /// The detected terminal width, or `None` if no terminal is detected
+
+    pub fn fit_terminal(
+        &mut self
+    ) -> Option<usize>
+    {
+        let (width, _) = terminal_size()?;
+        self.set_max_width(width);
+        Some(width)
+    }
+
+}
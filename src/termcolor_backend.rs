@@ -0,0 +1,152 @@
+use crate::cell::Cell;
+use crate::color::{Foreground, Background};
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+use crate::options::RenderOptions;
+use crate::render::Segment;
+
+use std::io;
+use termcolor::{ColorSpec, WriteColor};
+
+impl Grid {
+
+    /// Renders the grid into a `termcolor::WriteColor` sink.
+    ///
+    /// Colors and font styles are applied through `termcolor` instead of raw ANSI codes,
+    /// so they work on legacy Windows consoles and respect the sink's `ColorChoice`.
+    /// The layout is identical to the `Display` implementation.
+
+    pub fn render_to_color<W: WriteColor>(
+        &self,
+        w: &mut W,
+    ) -> io::Result<()>
+    {
+        self.render_to_color_with(w, &self.default_options())
+    }
+
+    /// Renders the grid into a `termcolor::WriteColor` sink using the given render options.
+
+    pub fn render_to_color_with<W: WriteColor>(
+        &self,
+        w: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()>
+    {
+        let layout = self.layout().constrain(options);
+        self.write_segments(&layout, options, |segment| {
+            match segment {
+                Segment::Text(text) => write!(w, "{}", text),
+                Segment::Spaces(count) => write!(w, "{:count$}", "", count = count),
+                Segment::Styled(text, cell) => {
+                    let spec = color_spec(cell);
+                    if spec.is_none() || text.is_empty() {
+                        return write!(w, "{}", text);
+                    }
+                    w.set_color(&spec)?;
+                    write!(w, "{}", text)?;
+                    w.reset()
+                },
+                Segment::LineEnd => writeln!(w),
+            }
+        })
+    }
+
+}
+
+/// Translates the cell's colors and font styles into a `termcolor` color spec.
+///
+/// Bright foreground colors use the intense flag, while bright background colors use
+/// the matching ANSI 256 color since `termcolor` cannot set intensity per layer.
+/// Blink, reverse, and hidden font styles have no `termcolor` equivalent and are ignored.
+
+fn color_spec(
+    cell: &Cell,
+) -> ColorSpec
+{
+    let mut spec = ColorSpec::new();
+    if let Some(fg_color) = cell.fg_color() {
+        let (color, intense) = foreground_color(fg_color);
+        spec.set_fg(Some(color));
+        spec.set_intense(intense);
+    }
+    if let Some(bg_color) = cell.bg_color() {
+        spec.set_bg(Some(background_color(bg_color)));
+    }
+    for style in cell.font_style() {
+        match style {
+            FontStyle::Bold      => { spec.set_bold(true); },
+            FontStyle::Dim       => { spec.set_dimmed(true); },
+            FontStyle::Italic    => { spec.set_italic(true); },
+            FontStyle::Underline => { spec.set_underline(true); },
+            FontStyle::Strike    => { spec.set_strikethrough(true); },
+            _ => {},
+        }
+    }
+    spec
+}
+
+fn foreground_color(
+    color: Foreground,
+) -> (termcolor::Color, bool)
+{
+    use termcolor::Color as C;
+    match color {
+        Foreground::Black         => (C::Black, false),
+        Foreground::Red           => (C::Red, false),
+        Foreground::Green         => (C::Green, false),
+        Foreground::Yellow        => (C::Yellow, false),
+        Foreground::Blue          => (C::Blue, false),
+        Foreground::Magenta       => (C::Magenta, false),
+        Foreground::Cyan          => (C::Cyan, false),
+        Foreground::White         => (C::White, false),
+        Foreground::BrightBlack   => (C::Black, true),
+        Foreground::BrightRed     => (C::Red, true),
+        Foreground::BrightGreen   => (C::Green, true),
+        Foreground::BrightYellow  => (C::Yellow, true),
+        Foreground::BrightBlue    => (C::Blue, true),
+        Foreground::BrightMagenta => (C::Magenta, true),
+        Foreground::BrightCyan    => (C::Cyan, true),
+        Foreground::BrightWhite   => (C::White, true),
+    }
+}
+
+fn background_color(
+    color: Background,
+) -> termcolor::Color
+{
+    use termcolor::Color as C;
+    match color {
+        Background::Black         => C::Black,
+        Background::Red           => C::Red,
+        Background::Green         => C::Green,
+        Background::Yellow        => C::Yellow,
+        Background::Blue          => C::Blue,
+        Background::Magenta       => C::Magenta,
+        Background::Cyan          => C::Cyan,
+        Background::White         => C::White,
+        Background::BrightBlack   => C::Ansi256(8),
+        Background::BrightRed     => C::Ansi256(9),
+        Background::BrightGreen   => C::Ansi256(10),
+        Background::BrightYellow  => C::Ansi256(11),
+        Background::BrightBlue    => C::Ansi256(12),
+        Background::BrightMagenta => C::Ansi256(13),
+        Background::BrightCyan    => C::Ansi256(14),
+        Background::BrightWhite   => C::Ansi256(15),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{BorderStyle, Grid};
+
+    #[test]
+    fn render_to_color_uses_the_border_style_of_the_grid() {
+        let grid = Grid::from([["a", "b"], ["c", "d"]]).with_border(BorderStyle::Ascii);
+        let mut sink = termcolor::NoColor::new(Vec::new());
+        grid.render_to_color(&mut sink).unwrap();
+        assert_eq!(String::from_utf8(sink.into_inner()).unwrap(), grid.render_plain());
+    }
+
+}
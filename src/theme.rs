@@ -0,0 +1,197 @@
+use crate::color::{Background, Foreground};
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+use crate::options::RenderOptions;
+
+use alloc::string::String;
+
+/// A reusable look for tables: render options, header formatting, and the text shown for null values.
+///
+/// Apply the cell formatting with `Grid::set_theme`, and render with the theme's options,
+/// e.g. `grid.render_with(theme.options())`.
+///
+/// With the `serde` feature, themes can be loaded from user-editable configuration files,
+/// such as TOML or JSON. All fields are optional; the render options are written at the top level
+/// (see `RenderOptions`), next to `header_color` and `header_highlight` (color names like
+/// `"bright blue"`), `header_style` (font style names like `["bold", "underline"]`), and `null_text`.
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default = "Theme::without_env"))]
+pub struct Theme {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    options: RenderOptions,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    header_color: Option<Foreground>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    header_highlight: Option<Background>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    header_style: Option<FontStyle>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    null_text: Option<String>,
+}
+
+impl Theme {
+
+    /// Creates a theme with the default render options that leaves the cell formatting unchanged.
+
+    pub fn new() -> Self {
+        Theme::default()
+    }
+
+    /// Creates a theme with the built-in default render options, ignoring the `FLATGRID_*`
+    /// environment variables, for the fields missing from a theme file.
+
+    #[cfg(feature = "serde")]
+    fn without_env() -> Self {
+        Theme {
+            options: RenderOptions::without_env(),
+            header_color: None,
+            header_highlight: None,
+            header_style: None,
+            null_text: None,
+        }
+    }
+
+    /// Gets the render options of the theme.
+
+    pub fn options(
+        &self
+    ) -> &RenderOptions
+    {
+        &self.options
+    }
+
+    /// Gets a mutable reference to the render options of the theme.
+
+    pub fn options_mut(
+        &mut self
+    ) -> &mut RenderOptions
+    {
+        &mut self.options
+    }
+
+    /// Sets the render options of the theme.
+
+    pub fn set_options(
+        &mut self,
+        new_options: RenderOptions,
+    )
+    {
+        self.options = new_options;
+    }
+
+    /// Gets the foreground color of the header row, as one of the `Color` constants, if set.
+
+    pub fn header_color(
+        &self
+    ) -> Option<&'static str>
+    {
+        self.header_color.map(|color| color.as_str())
+    }
+
+    /// Sets the foreground color of the header row.
+    ///
+    /// Unrecognized color strings leave the color of the header cells unchanged.
+
+    pub fn set_header_color(
+        &mut self,
+        new_color: &str,
+    )
+    {
+        self.header_color = Foreground::from_str(new_color);
+    }
+
+    /// Gets the background color of the header row, as one of the `Color` constants, if set.
+
+    pub fn header_highlight(
+        &self
+    ) -> Option<&'static str>
+    {
+        self.header_highlight.map(|color| color.as_str())
+    }
+
+    /// Sets the background color of the header row.
+    ///
+    /// Unrecognized color strings leave the background of the header cells unchanged.
+
+    pub fn set_header_highlight(
+        &mut self,
+        new_color: &str,
+    )
+    {
+        self.header_highlight = Background::from_str(new_color);
+    }
+
+    /// Gets the font style(s) of the header row, if set.
+
+    pub fn header_style(
+        &self
+    ) -> Option<FontStyle>
+    {
+        self.header_style
+    }
+
+    /// Sets the font style(s) of the header row, replacing the styles of the header cells.
+
+    pub fn set_header_style(
+        &mut self,
+        new_style: FontStyle,
+    )
+    {
+        self.header_style = Some(new_style);
+    }
+
+    /// Gets the text shown for null values and missing fields, if set.
+
+    pub fn null_text(
+        &self
+    ) -> Option<&str>
+    {
+        self.null_text.as_deref()
+    }
+
+    /// Sets the text shown for null values and missing fields instead of `null`, such as `-` or `N/A`.
+
+    pub fn set_null_text(
+        &mut self,
+        new_null_text: impl Into<String>,
+    )
+    {
+        self.null_text = Some(new_null_text.into());
+    }
+
+}
+
+impl Grid {
+
+    /// Applies the cell formatting of a theme to the grid.
+    ///
+    /// The header colors and style are applied to the first row, and the null text replaces the
+    /// text of null cells (see `Cell::is_null`). Settings missing from the theme leave the cells
+    /// unchanged. The render options of the theme are not stored in the grid;
+    /// pass them to `Grid::render_with`.
+
+    pub fn set_theme(
+        &mut self,
+        theme: &Theme,
+    )
+    {
+        for cell in self.row_iter_mut(0) {
+            if let Some(color) = theme.header_color() {
+                cell.set_color(color);
+            }
+            if let Some(color) = theme.header_highlight() {
+                cell.set_highlight(color);
+            }
+            if let Some(style) = theme.header_style() {
+                cell.set_style(style);
+            }
+        }
+        if let Some(null_text) = theme.null_text() {
+            for cell in self.flat_iter_mut().filter(|cell| cell.is_null()) {
+                cell.set_null_text(null_text);
+            }
+        }
+    }
+
+}
@@ -0,0 +1,228 @@
+use crate::border::BorderStyle;
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+use crate::options::ColorMode;
+use crate::theme::Theme;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+#[cfg(any(feature = "json", feature = "toml"))]
+use std::{fs, io, path::Path};
+
+/// The registry used by `Grid::set_theme_by_name`, created with the bundled themes on first use.
+#[cfg(feature = "std")]
+static GLOBAL_REGISTRY: RwLock<Option<ThemeRegistry>> = RwLock::new(None);
+
+/// A collection of named themes, so several tools can share a consistent look.
+///
+/// A new registry holds the bundled themes:
+///
+/// - `default`: the default render options with a bold header
+/// - `compact`: no separators between rows, with a bold and underlined header
+/// - `ascii`: ASCII borders for terminals and fonts without box-drawing characters
+/// - `plain`: no borders and no colors, for logs and piping into other programs
+/// - `solarized`: a bold yellow header and `-` for null values
+/// - `ocean`: a bold bright white header on blue
+///
+/// Register more themes with `insert` or `load_dir`, and make the registry available to
+/// `Grid::set_theme_by_name` with `ThemeRegistry::set_global`.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeRegistry {
+    themes: BTreeMap<String, Theme>,
+}
+
+impl Default for ThemeRegistry {
+
+    fn default() -> Self {
+        ThemeRegistry::new()
+    }
+
+}
+
+impl ThemeRegistry {
+
+    /// Creates a registry holding the bundled themes.
+
+    pub fn new() -> Self {
+        let mut registry = ThemeRegistry::empty();
+
+        let mut default = Theme::new();
+        default.set_header_style(FontStyle::Bold);
+        registry.insert("default", default);
+
+        let mut compact = Theme::new();
+        compact.options_mut().set_row_separators(false);
+        compact.set_header_style(FontStyle::Bold | FontStyle::Underline);
+        registry.insert("compact", compact);
+
+        let mut ascii = Theme::new();
+        ascii.options_mut().set_border_style(BorderStyle::Ascii);
+        ascii.set_header_style(FontStyle::Bold);
+        registry.insert("ascii", ascii);
+
+        let mut plain = Theme::new();
+        plain.options_mut().set_border_style(BorderStyle::None);
+        plain.options_mut().set_color_mode(ColorMode::Never);
+        registry.insert("plain", plain);
+
+        let mut solarized = Theme::new();
+        solarized.set_header_color("yellow");
+        solarized.set_header_style(FontStyle::Bold);
+        solarized.set_null_text("-");
+        registry.insert("solarized", solarized);
+
+        let mut ocean = Theme::new();
+        ocean.set_header_color("bright white");
+        ocean.set_header_highlight("blue");
+        ocean.set_header_style(FontStyle::Bold);
+        registry.insert("ocean", ocean);
+
+        registry
+    }
+
+    /// Creates a registry without any themes.
+
+    pub fn empty() -> Self {
+        ThemeRegistry { themes: BTreeMap::new() }
+    }
+
+    /// Adds a theme under the given name, replacing any theme with the same name.
+
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        theme: Theme,
+    )
+    {
+        self.themes.insert(name.into(), theme);
+    }
+
+    /// Gets the theme with the given name, if any.
+
+    pub fn get(
+        &self,
+        name: &str,
+    ) -> Option<&Theme>
+    {
+        self.themes.get(name)
+    }
+
+    /// Gets the names of all themes, in alphabetical order.
+
+    pub fn names(
+        &self
+    ) -> Vec<&str>
+    {
+        self.themes.keys().map(String::as_str).collect()
+    }
+
+    /// Loads every theme file of a directory, such as `~/.config/mytool/themes`.
+    ///
+    /// With the `json` feature, each `.json` file is a theme named after the file, without the extension,
+    /// so `solarized.json` replaces the bundled `solarized` theme.
+    /// With the `toml` feature, `.toml` files are loaded the same way.
+    /// Other files and subdirectories are ignored. See `Theme` for the file format.
+    ///
+    /// # Returns
+    ///
+    /// The number of loaded themes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or a theme file cannot be read, or if a theme file
+    /// is invalid, in which case the error message names the file. Themes loaded before the
+    /// error are kept.
+
+    #[cfg(any(feature = "json", feature = "toml"))]
+    pub fn load_dir(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> io::Result<usize>
+    {
+        let mut paths: Vec<_> = fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<_>>()?;
+        // Load in a stable order, so the themes loaded before an error are predictable
+        paths.sort();
+
+        let mut count = 0;
+        for path in paths {
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let invalid = |error: &dyn core::fmt::Display| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), error))
+            };
+            let theme: Theme = match path.extension().and_then(|extension| extension.to_str()) {
+                #[cfg(feature = "json")]
+                Some("json") if path.is_file() => {
+                    serde_json::from_str(&fs::read_to_string(&path)?).map_err(|error| invalid(&error))?
+                },
+                #[cfg(feature = "toml")]
+                Some("toml") if path.is_file() => {
+                    toml::from_str(&fs::read_to_string(&path)?).map_err(|error| invalid(&error))?
+                },
+                _ => continue,
+            };
+            self.insert(name, theme);
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Replaces the registry used by `Grid::set_theme_by_name` for the whole process.
+    ///
+    /// Call this once at startup, e.g. after loading the user's theme files with `load_dir`.
+
+    #[cfg(feature = "std")]
+    pub fn set_global(
+        registry: ThemeRegistry,
+    )
+    {
+        *GLOBAL_REGISTRY.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(registry);
+    }
+
+    /// Gets a copy of the theme with the given name from the registry used by `Grid::set_theme_by_name`.
+
+    #[cfg(feature = "std")]
+    pub fn global_theme(
+        name: &str,
+    ) -> Option<Theme>
+    {
+        let registry = GLOBAL_REGISTRY.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        match registry.as_ref() {
+            Some(registry) => registry.get(name).cloned(),
+            None => ThemeRegistry::new().get(name).cloned(),
+        }
+    }
+
+}
+
+impl Grid {
+
+    /// Applies the cell formatting of a named theme from the global registry to the grid.
+    ///
+    /// The registry holds the bundled themes (see `ThemeRegistry`) unless replaced with
+    /// `ThemeRegistry::set_global`. Render with the returned theme's options to get its
+    /// borders and padding: `grid.render_with(theme.options())`.
+    ///
+    /// # Returns
+    ///
+    /// The applied theme, or `None` if no theme has that name, in which case the grid is unchanged
+
+    #[cfg(feature = "std")]
+    pub fn set_theme_by_name(
+        &mut self,
+        name: &str,
+    ) -> Option<Theme>
+    {
+        let theme = ThemeRegistry::global_theme(name)?;
+        self.set_theme(&theme);
+        Some(theme)
+    }
+
+}
@@ -0,0 +1,84 @@
+use crate::cell::Cell;
+use crate::grid::Grid;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::collections::{BTreeMap, BTreeSet};
+
+/// A type whose values can be laid out as the rows of a grid, one value per row.
+///
+/// With the `derive` feature, `#[derive(ToGrid)]` implements this trait for structs
+/// with named fields, using the field names as the header.
+/// The following field attributes are supported:
+///
+/// * `#[grid(rename = "Name")]` - Uses a different header for the column
+/// * `#[grid(skip)]` - Leaves the field out of the grid
+/// * `#[grid(order = 0)]` - Sorts the columns by this value instead of the field position
+/// * `#[grid(align = "right")]` - Aligns the field's cells `left`, `center`, or `right`
+/// * `#[grid(format = "{:.2}")]` - Formats the field with a format string instead of `Display`
+
+pub trait ToGrid {
+
+    /// Gets the header cells, one per column.
+
+    fn header() -> Vec<Cell>;
+
+    /// Gets the cells of the row representing this value, one per column.
+
+    fn row(
+        &self
+    ) -> Vec<Cell>;
+
+}
+
+impl Grid {
+
+    /// Create a new grid from values implementing `ToGrid`, such as a slice of derived structs.
+    ///
+    /// The first row is the header, formatted in bold and centered,
+    /// followed by one row per value.
+
+    pub fn from_rows<'a, T: ToGrid + 'a>(
+        rows: impl IntoIterator<Item = &'a T>,
+    ) -> Self
+    {
+        let rows = rows.into_iter().map(ToGrid::row);
+        let mut grid = Grid::from(core::iter::once(T::header()).chain(rows));
+        grid.style_header_row();
+        grid
+    }
+
+    /// Create a new grid from a list of maps, such as `Vec<HashMap<String, String>>`.
+    ///
+    /// The first row is a header holding the union of all keys in sorted order,
+    /// formatted in bold and centered, followed by one row per map with each value under its key.
+    /// Keys missing from a map are shown as a dimmed `null`.
+
+    pub fn from_maps<K, V>(
+        maps: impl IntoIterator<Item = impl IntoIterator<Item = (K, V)>>,
+    ) -> Self
+    where
+        K: Into<String>,
+        V: Into<Cell>,
+    {
+        let maps: Vec<BTreeMap<String, Cell>> = maps.into_iter()
+            .map(|map| map.into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect()
+            )
+            .collect();
+        let keys: BTreeSet<&String> = maps.iter().flat_map(BTreeMap::keys).collect();
+
+        let header: Vec<Cell> = keys.iter().map(|&key| Cell::new(key.as_str())).collect();
+        let rows = maps.iter().map(|map|
+            keys.iter()
+                .map(|&key| map.get(key).cloned().unwrap_or_else(Cell::null))
+                .collect::<Vec<Cell>>()
+        );
+
+        let mut grid = Grid::from(core::iter::once(header).chain(rows));
+        grid.style_header_row();
+        grid
+    }
+
+}
@@ -0,0 +1,369 @@
+use crate::cell::Cell;
+use crate::error::ErrorMessage;
+use crate::grid::Grid;
+use crate::memory::btree_bytes;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::vec;
+
+/// The parent of every child row of a grid and the collapsed rows, set with `Grid::set_parent`
+/// and `Grid::set_collapsed`.
+///
+/// Parents are always above their children: when rows are moved, a row whose parent ends up
+/// below it becomes a top-level row. When rendering, children are drawn indented
+/// under their parent with tree glyphs in the first column, and the descendants of
+/// collapsed rows are hidden.
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Tree {
+    parents: BTreeMap<usize, usize>,
+    collapsed: BTreeSet<usize>,
+}
+
+impl Tree {
+
+    /// Gets the number of heap bytes used by the parents and collapsed rows, approximately.
+
+    pub(crate) fn heap_bytes(
+        &self
+    ) -> usize
+    {
+        btree_bytes::<(usize, usize)>(self.parents.len()) + btree_bytes::<usize>(self.collapsed.len())
+    }
+
+    /// Returns true if no row has a parent, so the grid renders as a flat table.
+
+    pub(crate) fn is_empty(
+        &self
+    ) -> bool
+    {
+        self.parents.is_empty()
+    }
+
+    /// Makes room for a row inserted at the given index, moving the rows below it down.
+
+    pub(crate) fn insert_row(
+        &mut self,
+        row_index: usize,
+    )
+    {
+        let shifted = |row: usize| if row >= row_index { row + 1 } else { row };
+        self.parents = self.parents.iter().map(|(&child, &parent)| (shifted(child), shifted(parent))).collect();
+        self.collapsed = self.collapsed.iter().map(|&row| shifted(row)).collect();
+    }
+
+    /// Forgets a removed row, moving the rows below it up. Its children become top-level rows.
+
+    pub(crate) fn remove_row(
+        &mut self,
+        row_index: usize,
+    )
+    {
+        let shifted = |row: usize| if row > row_index { Some(row - 1) } else { (row < row_index).then_some(row) };
+        self.parents = self.parents.iter()
+            .filter_map(|(&child, &parent)| Some((shifted(child)?, shifted(parent)?)))
+            .collect();
+        self.collapsed = self.collapsed.iter().filter_map(|&row| shifted(row)).collect();
+    }
+
+    /// Moves the rows into the given order, where `order[i]` is the current index of the row to put at `i`.
+    /// Rows whose parent ends up below them become top-level rows.
+
+    pub(crate) fn move_rows(
+        &mut self,
+        order: &[usize],
+    )
+    {
+        let mut new_indices = vec![0; order.len()];
+        for (row_index, &old_index) in order.iter().enumerate() {
+            new_indices[old_index] = row_index;
+        }
+        self.parents = self.parents.iter()
+            .map(|(&child, &parent)| (new_indices[child], new_indices[parent]))
+            .filter(|&(child, parent)| parent < child)
+            .collect();
+        self.collapsed = self.collapsed.iter().map(|&row| new_indices[row]).collect();
+    }
+
+    /// Forgets the rows at or below the given index, after the grid is shrunk to it.
+
+    pub(crate) fn truncate(
+        &mut self,
+        row_size: usize,
+    )
+    {
+        self.parents.retain(|&child, _| child < row_size);
+        self.collapsed.retain(|&row| row < row_size);
+    }
+
+    /// Forgets every parent and collapsed row.
+
+    pub(crate) fn clear(
+        &mut self
+    )
+    {
+        self.parents.clear();
+        self.collapsed.clear();
+    }
+
+    /// Gets the rows of a grid of the given number of rows that are not hidden under a collapsed row,
+    /// or None if every row is shown.
+
+    pub(crate) fn visible_rows(
+        &self,
+        row_size: usize,
+    ) -> Option<Vec<usize>>
+    {
+        // Parents are above their children, so a row's parent is known to be hidden or not before it
+        let mut hidden = BTreeSet::new();
+        for (&child, parent) in &self.parents {
+            if self.collapsed.contains(parent) || hidden.contains(parent) {
+                hidden.insert(child);
+            }
+        }
+        (!hidden.is_empty()).then(|| (0..row_size).filter(|row_index| !hidden.contains(row_index)).collect())
+    }
+
+    /// Gets the glyphs drawn before the first cell of every child row, by row index,
+    /// such as `│  └─ ` for the last child of a row that has later siblings.
+
+    pub(crate) fn prefixes(
+        &self
+    ) -> BTreeMap<usize, TreePrefix>
+    {
+        // The last child of every parent, the only one drawn with `└─`
+        let mut last_children = BTreeMap::new();
+        for (&child, &parent) in &self.parents {
+            last_children.insert(parent, child);
+        }
+        let has_next_sibling = |row_index: usize| self.parents.get(&row_index)
+            .is_some_and(|parent| last_children[parent] != row_index);
+
+        let mut prefixes: BTreeMap<usize, TreePrefix> = BTreeMap::new();
+        for (&child, &parent) in &self.parents {
+            // A child continues the lines drawn below its parent
+            let mut first = prefixes.get(&parent).map_or_else(String::new, |prefix| prefix.rest.clone());
+            let mut rest = first.clone();
+            if has_next_sibling(child) {
+                first.push_str("├─ ");
+                rest.push_str("│  ");
+            } else {
+                first.push_str("└─ ");
+                rest.push_str("   ");
+            }
+            prefixes.insert(child, TreePrefix { first, rest });
+        }
+        prefixes
+    }
+
+}
+
+/// The tree glyphs drawn before the first cell of a child row.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TreePrefix {
+    /// The glyphs on the first line of the row, ending with `├─` or `└─`.
+    pub(crate) first: String,
+    /// The glyphs on the following lines of the row, continuing the vertical lines.
+    pub(crate) rest: String,
+}
+
+impl TreePrefix {
+
+    /// Gets the number of characters taken by the glyphs, the same on every line.
+
+    pub(crate) fn width(
+        &self
+    ) -> usize
+    {
+        self.first.chars().count()
+    }
+
+}
+
+impl Grid {
+
+    /// Makes a row the child of another row, e.g. for dependency trees and directory listings.
+    ///
+    /// Panics if either index is out of bounds, or if the parent is not above the child.
+    ///
+    /// The cells are not modified: when rendering, children are drawn under their parent,
+    /// indented with tree glyphs (`├─`, `└─`) before their first cell, and hidden while
+    /// an ancestor is collapsed. The rows are drawn in their order in the grid, so every row
+    /// should be followed by its descendants, as in a depth-first listing; `push_child_row`
+    /// keeps that order. Inserting, removing, sorting, and reordering rows move the tree with them;
+    /// a row whose parent is moved below it becomes a top-level row.
+
+    pub fn set_parent(
+        &mut self,
+        row_index: usize,
+        parent_index: usize,
+    )
+    {
+        if row_index >= self.row_size() {
+            panic!("{}", ErrorMessage::ROW_INDEX_OUT_OF_BOUNDS);
+        }
+        if parent_index >= row_index {
+            panic!("{}", ErrorMessage::PARENT_ROW_NOT_ABOVE);
+        }
+
+        self.tree_mut().parents.insert(row_index, parent_index);
+    }
+
+    /// Makes a row a top-level row again. Its own children stay under it.
+
+    pub fn clear_parent(
+        &mut self,
+        row_index: usize,
+    )
+    {
+        if self.tree().parents.contains_key(&row_index) {
+            self.tree_mut().parents.remove(&row_index);
+        }
+    }
+
+    /// Gets the parent of a row, or None if it is a top-level row.
+
+    pub fn parent(
+        &self,
+        row_index: usize,
+    ) -> Option<usize>
+    {
+        self.tree().parents.get(&row_index).copied()
+    }
+
+    /// Inserts a row as the last child of a row, below the descendants the row already has.
+    ///
+    /// Panics if the parent index is out of bounds.
+    ///
+    /// Like `insert_row`, missing cells are filled with empty cells and extra cells are dropped.
+    ///
+    /// # Returns
+    ///
+    /// The index of the inserted row
+
+    pub fn push_child_row(
+        &mut self,
+        parent_index: usize,
+        new_row: impl IntoIterator<Item = impl Into<Cell>>,
+    ) -> usize
+    {
+        if parent_index >= self.row_size() {
+            panic!("{}", ErrorMessage::ROW_INDEX_OUT_OF_BOUNDS);
+        }
+
+        // The descendants of the parent are the rows right below it whose ancestors include it
+        let mut row_index = parent_index + 1;
+        while row_index < self.row_size() && self.is_descendant(row_index, parent_index) {
+            row_index += 1;
+        }
+        self.insert_row(row_index, new_row);
+        self.set_parent(row_index, parent_index);
+        row_index
+    }
+
+    /// Collapses or expands a row, hiding or showing its descendants when rendering.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// Hidden rows are skipped by the rendered grid and by `row_heights`,
+    /// but are still part of the grid and of its views.
+
+    pub fn set_collapsed(
+        &mut self,
+        row_index: usize,
+        collapsed: bool,
+    )
+    {
+        if row_index >= self.row_size() {
+            panic!("{}", ErrorMessage::ROW_INDEX_OUT_OF_BOUNDS);
+        }
+
+        if collapsed != self.is_collapsed(row_index) {
+            let tree = self.tree_mut();
+            if collapsed {
+                tree.collapsed.insert(row_index);
+            } else {
+                tree.collapsed.remove(&row_index);
+            }
+        }
+    }
+
+    /// Collapses a row if it is expanded, and expands it otherwise.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// # Returns
+    ///
+    /// True if the row is now collapsed
+
+    pub fn toggle_collapsed(
+        &mut self,
+        row_index: usize,
+    ) -> bool
+    {
+        let collapsed = !self.is_collapsed(row_index);
+        self.set_collapsed(row_index, collapsed);
+        collapsed
+    }
+
+    /// Returns true if the row is collapsed, hiding its descendants.
+
+    pub fn is_collapsed(
+        &self,
+        row_index: usize,
+    ) -> bool
+    {
+        self.tree().collapsed.contains(&row_index)
+    }
+
+    /// Returns true if a row is under the given ancestor row.
+
+    fn is_descendant(
+        &self,
+        row_index: usize,
+        ancestor_index: usize,
+    ) -> bool
+    {
+        let mut row_index = row_index;
+        while let Some(parent_index) = self.parent(row_index) {
+            if parent_index == ancestor_index {
+                return true;
+            }
+            row_index = parent_index;
+        }
+        false
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::Grid;
+
+    use alloc::vec::Vec;
+
+    #[test]
+    fn sorting_moves_the_tree_with_the_rows() {
+        let mut grid = Grid::from([["b"], ["b1"], ["a"], ["a1"]]);
+        grid.set_parent(1, 0);
+        grid.set_parent(3, 2);
+        grid.set_collapsed(2, true);
+
+        grid.sort_by_col(0);
+        assert_eq!(grid.to_records(), [["a"], ["a1"], ["b"], ["b1"]]);
+        assert_eq!((0..4).map(|row_index| grid.parent(row_index)).collect::<Vec<_>>(), [None, Some(0), None, Some(2)]);
+        assert!(grid.is_collapsed(0) && !grid.is_collapsed(2));
+
+        // A child sorted above its parent becomes a top-level row
+        grid.set_cell(1, 0, "0");
+        grid.sort_by_col(0);
+        assert_eq!(grid.to_records(), [["0"], ["a"], ["b"], ["b1"]]);
+        assert_eq!(grid.parent(0), None);
+        assert_eq!(grid.parent(3), Some(2));
+    }
+
+}
@@ -0,0 +1,64 @@
+use crate::cell::Cell;
+use crate::grid::Grid;
+
+use alloc::vec::Vec;
+use alloc::vec;
+
+/// A value that can be converted into a single row of cells, such as a tuple.
+///
+/// Implemented for tuples of up to 12 values convertible into `Cell`,
+/// so heterogeneous rows like `("id", 1, true)` don't have to be mapped to strings first.
+
+pub trait IntoRow {
+
+    /// Converts the value into the cells of a row.
+
+    fn into_row(
+        self
+    ) -> Vec<Cell>;
+
+}
+
+macro_rules! impl_into_row_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: Into<Cell>),+> IntoRow for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn into_row(
+                self
+            ) -> Vec<Cell>
+            {
+                let ($($name,)+) = self;
+                vec![$($name.into()),+]
+            }
+        }
+    };
+}
+
+impl_into_row_for_tuple!(A);
+impl_into_row_for_tuple!(A, B);
+impl_into_row_for_tuple!(A, B, C);
+impl_into_row_for_tuple!(A, B, C, D);
+impl_into_row_for_tuple!(A, B, C, D, E);
+impl_into_row_for_tuple!(A, B, C, D, E, F);
+impl_into_row_for_tuple!(A, B, C, D, E, F, G);
+impl_into_row_for_tuple!(A, B, C, D, E, F, G, H);
+impl_into_row_for_tuple!(A, B, C, D, E, F, G, H, I);
+impl_into_row_for_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_into_row_for_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_into_row_for_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+impl Grid {
+
+    /// Create a new grid from rows of tuples, such as `vec![("id", 1, true), ("name", 2, false)]`.
+    ///
+    /// Each tuple becomes a row and each of its values a cell.
+    /// Tuples of different lengths are allowed: missing cells are filled with defaults.
+
+    pub fn from_tuples(
+        rows: impl IntoIterator<Item = impl IntoRow>,
+    ) -> Self
+    {
+        Grid::from(rows.into_iter().map(IntoRow::into_row))
+    }
+
+}
@@ -0,0 +1,231 @@
+use crate::color::Color;
+use crate::error::ErrorMessage;
+use crate::grid::Grid;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::mem::{size_of, size_of_val};
+use core::panic::RefUnwindSafe;
+
+/// A function checking the data of a cell, returning an error message if the data is rejected.
+///
+/// Validators are `RefUnwindSafe`, so a grid holding them can still be used across `catch_unwind`.
+
+type ValidatorFn = dyn Fn(&str) -> Result<(), String> + Send + Sync + RefUnwindSafe;
+
+/// A validator checking the data of every cell of a column, set with `Grid::set_col_validator`.
+
+#[derive(Clone)]
+pub(crate) struct ColValidator(Arc<ValidatorFn>);
+
+impl ColValidator {
+
+    /// Gets the number of heap bytes used by the function and its reference counts.
+
+    pub(crate) fn heap_bytes(
+        &self
+    ) -> usize
+    {
+        size_of_val(&*self.0) + 2 * size_of::<usize>()
+    }
+
+}
+
+/// The validators set on the columns of a grid, by column index.
+///
+/// Functions cannot be compared, so the validators never affect equality,
+/// and two grids with the same cells are always equal.
+
+#[derive(Clone, Default)]
+pub(crate) struct ColValidators(pub(crate) BTreeMap<usize, ColValidator>);
+
+impl PartialEq for ColValidators {
+
+    fn eq(
+        &self,
+        _other: &Self,
+    ) -> bool
+    {
+        true
+    }
+
+}
+
+impl Eq for ColValidators {}
+
+/// A cell rejected by the validator of its column, as reported by `Grid::validate_data`.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    row_index: usize,
+    col_index: usize,
+    message: String,
+}
+
+impl Violation {
+
+    /// Gets the row of the rejected cell.
+
+    pub fn row_index(
+        &self
+    ) -> usize
+    {
+        self.row_index
+    }
+
+    /// Gets the column of the rejected cell.
+
+    pub fn col_index(
+        &self
+    ) -> usize
+    {
+        self.col_index
+    }
+
+    /// Gets the message returned by the validator.
+
+    pub fn message(
+        &self
+    ) -> &str
+    {
+        &self.message
+    }
+
+}
+
+impl Display for Violation {
+
+    /// Formats the violation as `row 2, column 1: message`, with indices starting at 0.
+
+    fn fmt(
+        &self,
+        f: &mut Formatter,
+    ) -> fmt::Result
+    {
+        write!(f, "row {}, column {}: {}", self.row_index, self.col_index, self.message)
+    }
+
+}
+
+impl Grid {
+
+    /// Sets a validator for the data of the cells of a column, replacing any previous one,
+    /// e.g. to check user-editable tabular config before using it.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// The validator returns an error message for data it rejects. It is only run by `validate_data`
+    /// and `validate_and_mark`, so setting cells never fails. The validator moves with the column
+    /// when columns are inserted or reordered. Validators are not compared by `==`,
+    /// so a grid equals a copy of itself with other or no validators.
+
+    pub fn set_col_validator(
+        &mut self,
+        col_index: usize,
+        validator: impl Fn(&str) -> Result<(), String> + Send + Sync + RefUnwindSafe + 'static,
+    )
+    {
+        if col_index >= self.col_size() {
+            panic!("{}", ErrorMessage::COL_INDEX_OUT_OF_BOUNDS);
+        }
+
+        self.col_validators_mut().insert(col_index, ColValidator(Arc::new(validator)));
+    }
+
+    /// Removes the validator of a column, if any.
+
+    pub fn clear_col_validator(
+        &mut self,
+        col_index: usize,
+    )
+    {
+        self.col_validators_mut().remove(&col_index);
+    }
+
+    /// Runs the validator of every column on its cells.
+    ///
+    /// Every row is validated, including a header row if the grid has one, so validators should
+    /// accept the header, or keep it apart in a `Table` and validate its data rows.
+    ///
+    /// # Returns
+    ///
+    /// The cells rejected by their validator, in row-major order, or an empty vector if all cells are valid
+
+    pub fn validate_data(
+        &self
+    ) -> Vec<Violation>
+    {
+        let mut violations = Vec::new();
+        for row_index in 0..self.row_size() {
+            for (&col_index, validator) in self.col_validators() {
+                let data = self.get_cell(row_index, col_index).map_or("", |cell| cell.get_data());
+                if let Err(message) = (validator.0)(data) {
+                    violations.push(Violation { row_index, col_index, message });
+                }
+            }
+        }
+        violations
+    }
+
+    /// Runs the validators like `validate_data` and shows the rejected cells in red.
+    ///
+    /// Valid cells are left unchanged, so fixing a cell does not reset its color;
+    /// set it again with `Cell::set_color` when updating its data.
+
+    pub fn validate_and_mark(
+        &mut self
+    ) -> Vec<Violation>
+    {
+        let violations = self.validate_data();
+        for violation in &violations {
+            if let Some(cell) = self.get_cell_mut(violation.row_index, violation.col_index) {
+                cell.set_color(Color::RED);
+            }
+        }
+        violations
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    extern crate std;
+
+    use crate::Grid;
+
+    use alloc::string::{String, ToString};
+    use core::panic::{RefUnwindSafe, UnwindSafe};
+
+    fn not_empty(
+        text: &str,
+    ) -> Result<(), String>
+    {
+        if text.is_empty() { Err("empty".to_string()) } else { Ok(()) }
+    }
+
+    #[test]
+    fn grid_with_validators_is_unwind_safe() {
+        fn assert_unwind_safe<T: UnwindSafe + RefUnwindSafe>() {}
+        assert_unwind_safe::<Grid>();
+
+        let mut grid = Grid::from([["a", ""]]);
+        grid.set_col_validator(1, not_empty);
+        let violations = std::panic::catch_unwind(|| grid.validate_data()).unwrap();
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn validators_do_not_affect_equality() {
+        let mut first = Grid::from([["a", "b"]]);
+        let mut second = first.clone();
+        first.set_col_validator(0, not_empty);
+        assert_eq!(first, second);
+        second.set_col_validator(1, not_empty);
+        assert_eq!(first, second);
+    }
+
+}
@@ -0,0 +1,386 @@
+use crate::cell::Cell;
+use crate::grid::Grid;
+use crate::options::RenderOptions;
+use crate::render::{Layout, resolve_range};
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Display, Error, Formatter};
+use core::ops::{Range, RangeBounds};
+#[cfg(feature = "std")]
+use std::io;
+
+/// A borrowed region of a grid: a range of its columns, within either a range of its rows
+/// or the rows selected by a filter.
+///
+/// A view reads and renders the cells of its region directly from the grid, without copying them,
+/// e.g. to render the visible part of a large table or to export a part of it.
+/// Row and column indices are relative to the top-left corner of the view,
+/// so the rows of a filtered view are numbered without gaps.
+/// Rendering measures the cells inside the view only, so column widths are fitted to its content.
+///
+/// Views are created with `Grid::view` and `Grid::filtered`,
+/// and can be narrowed further with `GridView::view` and `GridView::filtered`.
+
+#[derive(Debug, Clone)]
+pub struct GridView<'a> {
+    grid: &'a Grid,
+    rows: ViewRows,
+    cols: Range<usize>,
+}
+
+/// The rows of the grid covered by a view.
+
+#[derive(Debug, Clone)]
+enum ViewRows {
+    Range(Range<usize>),
+    Selected(Vec<usize>),
+}
+
+impl ViewRows {
+
+    /// Get the number of rows covered.
+
+    fn len(
+        &self
+    ) -> usize
+    {
+        match self {
+            ViewRows::Range(rows) => rows.len(),
+            ViewRows::Selected(rows) => rows.len(),
+        }
+    }
+
+    /// Get the index in the grid of the row at the specified index of the view.
+    ///
+    /// Returns None if the index is out of bounds.
+
+    fn get(
+        &self,
+        row_index: usize,
+    ) -> Option<usize>
+    {
+        match self {
+            ViewRows::Range(rows) => (row_index < rows.len()).then(|| rows.start + row_index),
+            ViewRows::Selected(rows) => rows.get(row_index).copied(),
+        }
+    }
+
+    /// Get the rows covered within a range of indices of the view, which must be in bounds.
+
+    fn slice(
+        &self,
+        range: Range<usize>,
+    ) -> ViewRows
+    {
+        match self {
+            ViewRows::Range(rows) => ViewRows::Range(rows.start + range.start..rows.start + range.end),
+            ViewRows::Selected(rows) => ViewRows::Selected(rows[range].to_vec()),
+        }
+    }
+
+}
+
+impl Grid {
+
+    /// Borrows a rectangular region of the grid, without copying its cells.
+    ///
+    /// Both ranges accept any range syntax (`2..5`, `..10`, `3..`, `..`)
+    /// and are clamped to the size of the grid.
+
+    pub fn view(
+        &self,
+        row_range: impl RangeBounds<usize>,
+        col_range: impl RangeBounds<usize>,
+    ) -> GridView<'_>
+    {
+        GridView {
+            grid: self,
+            rows: ViewRows::Range(resolve_range(row_range, self.row_size())),
+            cols: resolve_range(col_range, self.col_size()),
+        }
+    }
+
+    /// Borrows a page of rows of the grid, e.g. to show one page at a time in an interactive pager.
+    ///
+    /// Page 0 holds the first `rows_per_page` rows, page 1 the next ones, and so on,
+    /// and the last page may be shorter. A page past the last one is empty.
+    /// A page holds at least one row, even if `rows_per_page` is 0.
+
+    pub fn page(
+        &self,
+        page_index: usize,
+        rows_per_page: usize,
+    ) -> GridView<'_>
+    {
+        let rows_per_page = rows_per_page.max(1);
+        let start = page_index.saturating_mul(rows_per_page);
+        self.view(start..start.saturating_add(rows_per_page), ..)
+    }
+
+    /// Get the number of pages of `rows_per_page` rows needed to show every row, as used by `page`.
+    ///
+    /// An empty grid has no pages.
+
+    pub fn page_count(
+        &self,
+        rows_per_page: usize,
+    ) -> usize
+    {
+        self.row_size().div_ceil(rows_per_page.max(1))
+    }
+
+    /// Borrows the rows of the grid for which the predicate returns true, without copying
+    /// or removing any of them, e.g. to narrow the display to the rows matching a filter box.
+    ///
+    /// The predicate is called once per row, in order, with the cells of the row.
+    /// The view is computed when this is called, so it must be filtered again
+    /// to reflect later changes to the grid.
+
+    pub fn filtered(
+        &self,
+        predicate: impl FnMut(&[Cell]) -> bool,
+    ) -> GridView<'_>
+    {
+        self.view(.., ..).filtered(predicate)
+    }
+
+}
+
+impl<'a> GridView<'a> {
+
+    /// Get the number of rows in the view.
+
+    pub fn row_size(
+        &self
+    ) -> usize
+    {
+        self.rows.len()
+    }
+
+    /// Get the number of columns in the view.
+
+    pub fn col_size(
+        &self
+    ) -> usize
+    {
+        self.cols.len()
+    }
+
+    /// Get the index in the grid of the row at the specified index of the view,
+    /// e.g. to edit the row selected in a filtered view.
+    ///
+    /// Returns None if the index is out of bounds.
+
+    pub fn grid_row_index(
+        &self,
+        row_index: usize,
+    ) -> Option<usize>
+    {
+        self.rows.get(row_index)
+    }
+
+    /// Get the range of the grid's columns covered by the view.
+
+    pub fn cols(
+        &self
+    ) -> Range<usize>
+    {
+        self.cols.clone()
+    }
+
+    /// Get a reference to the cell at the specified row and column indices of the view.
+    ///
+    /// Returns None if the indices are out of bounds.
+
+    pub fn get_cell(
+        &self,
+        row_index: usize,
+        col_index: usize,
+    ) -> Option<&'a Cell>
+    {
+        if col_index >= self.col_size() {
+            return None;
+        }
+        self.grid.get_cell(self.rows.get(row_index)?, self.cols.start + col_index)
+    }
+
+    /// Get an iterator over the cells in the specified row of the view.
+    ///
+    /// If the row index is out of bounds, returns an empty iterator.
+
+    pub fn row_iter(
+        &self,
+        row_index: usize,
+    ) -> impl DoubleEndedIterator<Item = &'a Cell> + ExactSizeIterator
+    {
+        self.row_slice(row_index).iter()
+    }
+
+    /// Get an iterator over the cells in the specified column of the view.
+    ///
+    /// If the column index is out of bounds, returns an empty iterator.
+
+    pub fn col_iter(
+        &self,
+        col_index: usize,
+    ) -> impl DoubleEndedIterator<Item = &'a Cell> + ExactSizeIterator + '_
+    {
+        let row_count = if col_index < self.col_size() { self.row_size() } else { 0 };
+        (0..row_count).map(move |row_index| &self.row_slice(row_index)[col_index])
+    }
+
+    /// Get an iterator over all cells in the view.
+    ///
+    /// The cells are returned in row-major order.
+
+    pub fn flat_iter(
+        &self
+    ) -> impl Iterator<Item = &'a Cell> + '_
+    {
+        (0..self.row_size()).flat_map(move |row_index| self.row_iter(row_index))
+    }
+
+    /// Borrows a rectangular region of the view, relative to its top-left corner.
+    ///
+    /// Both ranges are clamped to the size of the view.
+
+    pub fn view(
+        &self,
+        row_range: impl RangeBounds<usize>,
+        col_range: impl RangeBounds<usize>,
+    ) -> GridView<'a>
+    {
+        let rows = resolve_range(row_range, self.row_size());
+        let cols = resolve_range(col_range, self.col_size());
+        GridView {
+            grid: self.grid,
+            rows: self.rows.slice(rows),
+            cols: self.cols.start + cols.start..self.cols.start + cols.end,
+        }
+    }
+
+    /// Borrows the rows of the view for which the predicate returns true.
+    ///
+    /// The predicate is called once per row, in order, with the cells of the row inside the view.
+
+    pub fn filtered(
+        &self,
+        mut predicate: impl FnMut(&[Cell]) -> bool,
+    ) -> GridView<'a>
+    {
+        let rows = (0..self.row_size())
+            .filter(|&row_index| predicate(self.row_slice(row_index)))
+            .filter_map(|row_index| self.rows.get(row_index))
+            .collect();
+        GridView {
+            grid: self.grid,
+            rows: ViewRows::Selected(rows),
+            cols: self.cols.clone(),
+        }
+    }
+
+    /// Get the width of every column's content in the view, as used when rendering it.
+
+    pub fn col_widths(
+        &self
+    ) -> Vec<usize>
+    {
+        self.layout().col_widths
+    }
+
+    /// Get the height of every row's content in the view, as used when rendering it.
+
+    pub fn row_heights(
+        &self
+    ) -> Vec<usize>
+    {
+        self.layout().row_heights
+    }
+
+    /// Renders the view using the given render options.
+    ///
+    /// The `Display` implementation is a shortcut for rendering with the default options.
+
+    pub fn render_with(
+        &self,
+        options: &RenderOptions,
+    ) -> String
+    {
+        self.grid.render_layout(&self.layout().constrain(options), options)
+    }
+
+    /// Renders the view directly into a writer.
+    ///
+    /// The output is identical to the `Display` implementation.
+
+    #[cfg(feature = "std")]
+    pub fn render_to<W: io::Write>(
+        &self,
+        w: &mut W,
+    ) -> io::Result<()>
+    {
+        let options = self.grid.default_options();
+        self.grid.write_lines(&self.layout(), &options, |line| writeln!(w, "{}", line))
+    }
+
+    /// Get the data of every cell in the view as text, stripped of all formatting, one `Vec` per row.
+
+    pub fn to_records(
+        &self
+    ) -> Vec<Vec<String>>
+    {
+        (0..self.row_size())
+            .map(|row_index| self.row_iter(row_index).map(|cell| cell.get_data().to_string()).collect())
+            .collect()
+    }
+
+    /// Copies the cells of the view into a new grid.
+
+    pub fn to_grid(
+        &self
+    ) -> Grid
+    {
+        let mut grid = Grid::new(self.row_size(), self.col_size());
+        grid.set_cells_vec(self.flat_iter().cloned().collect());
+        grid
+    }
+
+    /// Get the cells of the specified row inside the view, which is empty if the index is out of bounds.
+
+    fn row_slice(
+        &self,
+        row_index: usize,
+    ) -> &'a [Cell]
+    {
+        match self.rows.get(row_index) {
+            Some(grid_row_index) => &self.grid.row_slice(grid_row_index)[self.cols.clone()],
+            None => &[],
+        }
+    }
+
+    /// Computes the row heights and column widths of the cells inside the view.
+
+    fn layout(
+        &self
+    ) -> Layout
+    {
+        match &self.rows {
+            ViewRows::Range(rows) => self.grid.layout_window(rows.clone(), self.cols.clone()),
+            ViewRows::Selected(rows) => self.grid.layout_selected_rows(rows.clone(), self.cols.clone()),
+        }
+    }
+
+}
+
+impl Display for GridView<'_> {
+
+    fn fmt(
+        &self,
+        f: &mut Formatter,
+    ) -> Result<(), Error>
+    {
+        self.grid.fmt_layout(f, self.layout())
+    }
+
+}
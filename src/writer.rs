@@ -0,0 +1,184 @@
+use crate::border::Border;
+use crate::cell::Cell;
+use crate::format::AnsiPrefixes;
+use crate::grid::Grid;
+use crate::options::RenderOptions;
+use crate::render::{Layout, Segment};
+
+use alloc::collections::BTreeMap;
+use std::io;
+
+/// Streaming table writer that renders rows as soon as they are appended.
+///
+/// The column widths are fixed up front, so every row can be written straight to the
+/// underlying writer without keeping the table in memory, e.g. for tailing live data.
+/// The top border is written on creation and the bottom border on `finish`.
+///
+/// Cells wider than their column are truncated, and rows with fewer cells than columns
+/// are filled with empty cells while extra cells are ignored.
+
+#[derive(Debug)]
+pub struct GridWriter<W: io::Write> {
+    writer: W,
+    options: RenderOptions,
+    col_widths: Vec<usize>,
+    prefixes: AnsiPrefixes,
+    needs_separator: bool,
+}
+
+impl<W: io::Write> GridWriter<W> {
+
+    /// Creates a writer with the given column widths using the default render options,
+    /// and writes the top border.
+
+    pub fn new(
+        writer: W,
+        col_widths: impl IntoIterator<Item = usize>,
+    ) -> io::Result<Self>
+    {
+        GridWriter::with_options(writer, col_widths, RenderOptions::default())
+    }
+
+    /// Creates a writer with the given column widths and render options,
+    /// and writes the top border.
+
+    pub fn with_options(
+        writer: W,
+        col_widths: impl IntoIterator<Item = usize>,
+        options: RenderOptions,
+    ) -> io::Result<Self>
+    {
+        let col_widths = Layout { col_widths: col_widths.into_iter().collect(), ..Layout::default() }
+            .constrain(&options)
+            .col_widths;
+        let mut grid_writer = GridWriter {
+            writer,
+            options,
+            col_widths,
+            prefixes: AnsiPrefixes::new(),
+            needs_separator: false,
+        };
+        if let Some(border) = grid_writer.border() {
+            let top_border = border.render_top_border(&grid_writer.col_widths);
+            writeln!(grid_writer.writer, "{}", top_border)?;
+        }
+        Ok(grid_writer)
+    }
+
+    /// Creates a writer whose columns are defined by the header cells, and writes the
+    /// top border and the header.
+    ///
+    /// Each column is as wide as its header cell, so use `Cell::set_width`
+    /// on the header cells to make room for wider data.
+
+    pub fn with_header(
+        writer: W,
+        header: impl IntoIterator<Item = impl Into<Cell>>,
+        options: RenderOptions,
+    ) -> io::Result<Self>
+    {
+        let header: Vec<Cell> = header.into_iter().map(Into::into).collect();
+        let col_widths = header.iter().map(Cell::width);
+        let mut grid_writer = GridWriter::with_options(writer, col_widths, options)?;
+        grid_writer.write_header(header)?;
+        Ok(grid_writer)
+    }
+
+    /// Gets the column widths of the table.
+
+    pub fn col_widths(
+        &self
+    ) -> &[usize]
+    {
+        &self.col_widths
+    }
+
+    /// Writes a header row, which is always followed by a separator line if the table has borders.
+
+    pub fn write_header(
+        &mut self,
+        header: impl IntoIterator<Item = impl Into<Cell>>,
+    ) -> io::Result<()>
+    {
+        self.write_row(header)?;
+        self.needs_separator = true;
+        Ok(())
+    }
+
+    /// Writes a data row, preceded by a separator line if row separators are enabled.
+
+    pub fn write_row(
+        &mut self,
+        row: impl IntoIterator<Item = impl Into<Cell>>,
+    ) -> io::Result<()>
+    {
+        if self.needs_separator {
+            if let Some(border) = self.border() {
+                let mid_border = border.render_mid_border(&self.col_widths);
+                writeln!(self.writer, "{}", mid_border)?;
+            }
+        }
+
+        let mut grid = Grid::new(1, self.col_widths.len());
+        grid.set_row(0, row);
+        let row_height = grid.row_iter(0).map(Cell::height).max().unwrap_or(0);
+        let layout = Layout {
+            rows: 0..1,
+            cols: 0..self.col_widths.len(),
+            row_heights: vec![row_height],
+            col_widths: self.col_widths.clone(),
+            row_indices: None,
+            footer_from: None,
+            continued: BTreeMap::new(),
+            tree_prefixes: BTreeMap::new(),
+            plain: grid.row_iter(0).all(Cell::is_plain),
+        };
+
+        let writer = &mut self.writer;
+        let prefixes = &mut self.prefixes;
+        grid.write_row_segments(0, &layout, &self.options, &mut |segment| {
+            match segment {
+                Segment::Text(text) => write!(writer, "{}", text),
+                Segment::Spaces(count) => write!(writer, "{:count$}", "", count = count),
+                Segment::Styled(text, cell) => write!(writer, "{}", cell.styled(text, prefixes)),
+                Segment::LineEnd => writeln!(writer),
+            }
+        })?;
+
+        self.needs_separator = self.options.row_separators();
+        Ok(())
+    }
+
+    /// Flushes the underlying writer.
+
+    pub fn flush(
+        &mut self
+    ) -> io::Result<()>
+    {
+        self.writer.flush()
+    }
+
+    /// Writes the bottom border and returns the underlying writer.
+
+    pub fn finish(
+        mut self
+    ) -> io::Result<W>
+    {
+        if let Some(border) = self.border() {
+            let bot_border = border.render_bot_border(&self.col_widths);
+            writeln!(self.writer, "{}", bot_border)?;
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    /// Gets the border symbols for the render options, if the table has borders.
+
+    fn border(
+        &self
+    ) -> Option<Border>
+    {
+        Border::from_style(self.options.border_style(), self.options.padding())
+    }
+
+}
@@ -0,0 +1,169 @@
+use crate::align::{AlignH, AlignV};
+use crate::cell::Cell;
+use crate::export::Exporter;
+use crate::fontstyle::FontStyle;
+use crate::grid::Grid;
+use crate::sort::parse_number;
+
+use rust_xlsxwriter::{Format, FormatAlign, FormatUnderline, Workbook, XlsxError};
+use std::path::Path;
+
+impl Grid {
+
+    /// Export the grid into an Excel spreadsheet (`.xlsx`) at the given path.
+    ///
+    /// Every cell is written to the matching spreadsheet cell of the first worksheet,
+    /// with its colors, bold, italic, underline, and strike font styles,
+    /// and alignment carried over to the cell format.
+    /// Cells whose data parses as a number, also in the locale of the grid, are written as numbers,
+    /// so they can be used in formulas.
+    /// Multiline cells wrap their text, and every column is as wide as in the rendered table.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the spreadsheet file to create
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the grid is too large for a worksheet or the file cannot be written
+
+    pub fn to_xlsx(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<(), XlsxError>
+    {
+        Xlsx::workbook(self)?.save(path)
+    }
+
+}
+
+/// Exporter for Excel spreadsheets, as written by `Grid::to_xlsx`.
+///
+/// The exported output is the content of the `.xlsx` file.
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Xlsx;
+
+impl Exporter for Xlsx {
+
+    type Output = Result<Vec<u8>, XlsxError>;
+
+    fn export(
+        &self,
+        grid: &Grid,
+    ) -> Result<Vec<u8>, XlsxError>
+    {
+        Xlsx::workbook(grid)?.save_to_buffer()
+    }
+
+}
+
+impl Xlsx {
+
+    /// Creates a workbook holding the grid in its first worksheet.
+
+    fn workbook(
+        grid: &Grid,
+    ) -> Result<Workbook, XlsxError>
+    {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        for (col_index, col_width) in grid.layout().col_widths.into_iter().enumerate() {
+            let col_index = Xlsx::col_num(col_index)?;
+            worksheet.set_column_width(col_index, col_width as f64 + 1.0)?;
+        }
+
+        for row_index in 0..grid.row_size() {
+            let row_num = Xlsx::row_num(row_index)?;
+            for (col_index, cell) in grid.row_iter(row_index).enumerate() {
+                let col_num = Xlsx::col_num(col_index)?;
+                let format = Xlsx::cell_format(cell);
+                let data = cell.get_data();
+                match parse_number(data, grid.locale()) {
+                    Some(number) => {
+                        worksheet.write_number_with_format(row_num, col_num, number, &format)?;
+                    },
+                    _ => {
+                        worksheet.write_string_with_format(row_num, col_num, data, &format)?;
+                    },
+                }
+            }
+        }
+
+        Ok(workbook)
+    }
+
+    /// Converts a row index into a worksheet row number.
+
+    fn row_num(
+        row_index: usize,
+    ) -> Result<u32, XlsxError>
+    {
+        u32::try_from(row_index).map_err(|_| XlsxError::RowColumnLimitError)
+    }
+
+    /// Converts a column index into a worksheet column number.
+
+    fn col_num(
+        col_index: usize,
+    ) -> Result<u16, XlsxError>
+    {
+        u16::try_from(col_index).map_err(|_| XlsxError::RowColumnLimitError)
+    }
+
+    /// Creates the spreadsheet cell format matching the cell's colors, font styles, and alignment.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The cell to create the format for
+    ///
+    /// # Returns
+    ///
+    /// The cell format
+
+    fn cell_format(
+        cell: &Cell,
+    ) -> Format
+    {
+        let mut format = Format::new();
+
+        if let Some(color) = cell.fg_color() {
+            let (r, g, b) = color.as_rgb();
+            format = format.set_font_color(u32::from_be_bytes([0, r, g, b]));
+        }
+        if let Some(color) = cell.bg_color() {
+            let (r, g, b) = color.as_rgb();
+            format = format.set_background_color(u32::from_be_bytes([0, r, g, b]));
+        }
+        for style in cell.font_style() {
+            format = match style {
+                FontStyle::Bold      => format.set_bold(),
+                FontStyle::Italic    => format.set_italic(),
+                FontStyle::Underline => format.set_underline(FormatUnderline::Single),
+                FontStyle::Strike    => format.set_font_strikethrough(),
+                _ => format,
+            };
+        }
+
+        match cell.h_align() {
+            Some(AlignH::Left)   => format = format.set_align(FormatAlign::Left),
+            Some(AlignH::Center) => format = format.set_align(FormatAlign::Center),
+            Some(AlignH::Right)  => format = format.set_align(FormatAlign::Right),
+            None => {},
+        }
+        // The rendered table aligns to the top by default, unlike spreadsheets
+        match cell.v_align() {
+            Some(AlignV::Top) | None => format = format.set_align(FormatAlign::Top),
+            Some(AlignV::Middle)     => format = format.set_align(FormatAlign::VerticalCenter),
+            Some(AlignV::Bottom)     => format = format.set_align(FormatAlign::Bottom),
+        }
+
+        if cell.get_data().contains('\n') {
+            format = format.set_text_wrap();
+        }
+
+        format
+    }
+
+}